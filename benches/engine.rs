@@ -1,4 +1,7 @@
-use bls12_381_relic::{ff::Field, G1Projective, G2Projective, RelicEngine};
+use bls12_381_relic::{
+    engine::MillerLoopOutput, ff::Field, subtle::ConstantTimeEq, G1Projective, G2Prepared,
+    G2Projective, Gt, RelicEngine, Scalar,
+};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use pairing::{
     group::{prime::PrimeCurve, Curve, Group},
@@ -45,6 +48,114 @@ fn bench_pairings(c: &mut Criterion) {
             ))
         })
     });
+
+    let g1s: Vec<_> = (0..8).map(|_| G1Projective::random(&mut rng)).collect();
+    let g2s: Vec<_> = (0..8).map(|_| G2Projective::random(&mut rng)).collect();
+    let (affine_g1s, affine_g2s) = (g1s.clone(), g2s.clone());
+    c.bench_function(
+        "RelicEngine: multi miller loop (8, projective via affine and prepared)",
+        move |b| {
+            b.iter(|| {
+                let g1s: Vec<_> = black_box(&affine_g1s)
+                    .iter()
+                    .map(|g| g.to_affine())
+                    .collect();
+                let g2s: Vec<_> = black_box(&affine_g2s)
+                    .iter()
+                    .map(G2Prepared::from)
+                    .collect();
+                let terms: Vec<_> = g1s.iter().zip(g2s.iter()).collect();
+                black_box(RelicEngine::multi_miller_loop(&terms).final_exponentiation())
+            })
+        },
+    );
+    c.bench_function("RelicEngine: multi miller loop (8, projective)", move |b| {
+        b.iter(|| {
+            black_box(
+                RelicEngine::multi_miller_loop_slices(black_box(&g1s), black_box(&g2s))
+                    .final_exponentiation(),
+            )
+        })
+    });
+
+    // pairings with a fixed point in G2, e.g., verification of signatures
+    // under the same public key
+    let g2 = G2Projective::random(&mut rng);
+    let prepared = G2Prepared::from(g2);
+    let g1s: Vec<_> = (0..8)
+        .map(|_| G1Projective::random(&mut rng).to_affine())
+        .collect();
+    let projective_g1s: Vec<_> = g1s.iter().map(G1Projective::from).collect();
+    let (multi_g1s, multi_g2s) = (projective_g1s.clone(), vec![g2; 8]);
+    c.bench_function("RelicEngine: miller loops with fixed G2 (8)", move |b| {
+        b.iter(|| {
+            black_box(&projective_g1s)
+                .iter()
+                .map(|g1| RelicEngine::projective_miller_loop(g1, black_box(&g2)))
+                .sum::<MillerLoopOutput>()
+        })
+    });
+    c.bench_function(
+        "RelicEngine: multi miller loop with fixed G2 (8)",
+        move |b| {
+            b.iter(|| {
+                RelicEngine::multi_miller_loop_slices(black_box(&multi_g1s), black_box(&multi_g2s))
+            })
+        },
+    );
+    let terms: Vec<_> = g1s.iter().map(|g1| (g1, &prepared)).collect();
+    c.bench_function(
+        "RelicEngine: miller loops with fixed prepared G2 (8)",
+        |b| {
+            b.iter(|| {
+                black_box(&terms)
+                    .iter()
+                    .map(|term| RelicEngine::multi_miller_loop(core::slice::from_ref(term)))
+                    .sum::<MillerLoopOutput>()
+            })
+        },
+    );
+    c.bench_function(
+        "RelicEngine: multi miller loop with fixed prepared G2 (8)",
+        |b| b.iter(|| RelicEngine::multi_miller_loop(black_box(&terms))),
+    );
+    c.bench_function("RelicEngine: prepare G2", |b| {
+        b.iter(|| G2Prepared::from(black_box(&g2)))
+    });
+
+    let terms: Vec<_> = (0..8)
+        .map(|_| {
+            (
+                <RelicEngine as Engine>::G1::random(&mut rng),
+                <RelicEngine as Engine>::G2::random(&mut rng),
+            )
+        })
+        .collect();
+    let pairing_terms = terms.clone();
+    c.bench_function("RelicEngine: sum of pairings (8)", move |b| {
+        b.iter(|| {
+            black_box(
+                black_box(&pairing_terms)
+                    .iter()
+                    .map(|(g1, g2)| RelicEngine::projective_pairing(g1, g2))
+                    .sum::<Gt>(),
+            )
+        })
+    });
+    c.bench_function(
+        "RelicEngine: sum of miller loops with one final exponentiation (8)",
+        move |b| {
+            b.iter(|| {
+                black_box(
+                    black_box(&terms)
+                        .iter()
+                        .map(|(g1, g2)| RelicEngine::projective_miller_loop(g1, g2))
+                        .sum::<MillerLoopOutput>()
+                        .final_exponentiation(),
+                )
+            })
+        },
+    );
 }
 
 fn bench_bls12_381_pairings(c: &mut Criterion) {
@@ -70,14 +181,105 @@ where
     c.bench_function(&format!("{}: scalar multiplication", name), move |b| {
         b.iter(|| black_box(black_box(a) * black_box(s)))
     });
+    c.bench_function(&format!("{}: multiply and add", name), move |b| {
+        b.iter(|| black_box(black_box(a) * black_box(s) + black_box(a)))
+    });
 }
 
 fn bench_g1_projective(c: &mut Criterion) {
     bench_group::<G1Projective>(c, "G1Projective");
+
+    let mut rng = rand::thread_rng();
+    let a = G1Projective::random(&mut rng);
+    let s = Scalar::random(&mut rng);
+    c.bench_function("G1Projective: mul_add", move |b| {
+        b.iter(|| black_box(black_box(&a).mul_add(black_box(&s), black_box(&a))))
+    });
+    c.bench_function("G1Projective: generator multiplication", move |b| {
+        b.iter(|| black_box(G1Projective::generator() * black_box(s)))
+    });
+    c.bench_function("G1Projective: mul_generator", move |b| {
+        b.iter(|| black_box(G1Projective::mul_generator(black_box(&s))))
+    });
 }
 
 fn bench_g2_projective(c: &mut Criterion) {
     bench_group::<G2Projective>(c, "G2Projective");
+
+    let mut rng = rand::thread_rng();
+    let a = G2Projective::random(&mut rng);
+    let s = Scalar::random(&mut rng);
+    c.bench_function("G2Projective: mul_add", move |b| {
+        b.iter(|| black_box(black_box(&a).mul_add(black_box(&s), black_box(&a))))
+    });
+    c.bench_function("G2Projective: generator multiplication", move |b| {
+        b.iter(|| black_box(G2Projective::generator() * black_box(s)))
+    });
+    c.bench_function("G2Projective: mul_generator", move |b| {
+        b.iter(|| black_box(G2Projective::mul_generator(black_box(&s))))
+    });
+}
+
+fn bench_scalar(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let a = Scalar::random(&mut rng);
+    let b = a * Scalar::ONE;
+
+    c.bench_function("Scalar: eq", move |bench| {
+        bench.iter(|| black_box(black_box(a) == black_box(b)))
+    });
+    c.bench_function("Scalar: ct_eq", move |bench| {
+        bench.iter(|| black_box(black_box(a).ct_eq(&black_box(b))))
+    });
+}
+
+fn bench_gt(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let a = Gt::random(&mut rng);
+    let s = Scalar::random(&mut rng);
+
+    c.bench_function("Gt: multiply and add", move |b| {
+        b.iter(|| black_box(black_box(a) * black_box(s) + black_box(a)))
+    });
+    c.bench_function("Gt: mul_add", move |b| {
+        b.iter(|| black_box(black_box(&a).mul_add(black_box(&s), black_box(&a))))
+    });
+}
+
+fn bench_gt_constants(c: &mut Criterion) {
+    c.bench_function("Gt: generator", |b| b.iter(|| black_box(Gt::generator())));
+    c.bench_function("Gt: identity", |b| b.iter(|| black_box(Gt::identity())));
+    c.bench_function("Gt: pairing of generators", |b| {
+        b.iter(|| {
+            black_box(RelicEngine::projective_pairing(
+                black_box(&G1Projective::generator()),
+                black_box(&G2Projective::generator()),
+            ))
+        })
+    });
+}
+
+fn bench_g1_msm(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    for size in [16, 256, 4096, 1 << 16] {
+        let points: Vec<_> = (0..size)
+            .map(|_| G1Projective::random(&mut rng).to_affine())
+            .collect();
+        let scalars: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+
+        c.bench_function(&format!("G1Projective: msm ({})", size), |b| {
+            b.iter(|| black_box(G1Projective::msm(black_box(&points), black_box(&scalars))))
+        });
+        c.bench_function(&format!("G1Projective: msm_pippenger ({})", size), |b| {
+            b.iter(|| {
+                black_box(G1Projective::msm_pippenger(
+                    black_box(&points),
+                    black_box(&scalars),
+                    None,
+                ))
+            })
+        });
+    }
 }
 
 fn bench_bls12_381_g1_projective(c: &mut Criterion) {
@@ -92,6 +294,10 @@ criterion_group!(
     benches,
     bench_g1_projective,
     bench_g2_projective,
+    bench_g1_msm,
+    bench_scalar,
+    bench_gt,
+    bench_gt_constants,
     bench_pairings,
     bench_bls12_381_g1_projective,
     bench_bls12_381_g2_projective,
@@ -10,6 +10,7 @@ fn build() -> PathBuf {
         .define("STBIN", "OFF")
         .define("STLIB", "ON")
         .define("TIMER", "")
+        .define("MULTI", "PTHREAD")
         .define(
             "CHECK",
             if env::var("PROFILE").unwrap() == "debug"
@@ -32,6 +33,10 @@ fn build() -> PathBuf {
         .define("FPX_METHD", "INTEG;INTEG;LAZYR")
         .define("EP_PLAIN", "off")
         .define("EP_SUPER", "off")
+        // relic's defaults, set explicitly since scalar multiplication in G1
+        // relies on w-NAF with the GLV endomorphism
+        .define("EP_ENDOM", "on")
+        .define("EP_METHD", "PROJC;LWNAF;COMBS;INTER")
         .define("PP_METHD", "LAZYR;OATEP");
     if env::var("CARGO_CFG_TARGET_ARCH").unwrap() == "x86_64" {
         cmake.define("ARCH", "X64").define("ARITH", "x64-asm-382");
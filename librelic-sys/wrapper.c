@@ -1,21 +1,71 @@
 #include "wrapper.h"
 
 #include <assert.h>
+#include <stdlib.h>
+#include <string.h>
+#if MULTI == PTHREAD
+#include <pthread.h>
+#endif
 
 #if ALLOC != AUTO
 #error "Only relic with automatic allocation is supported."
 #endif
 
+#if FP_PRIME != 381
+#error "Only relic configured for BLS12-381 is supported."
+#endif
+
 static bool core_init_run = false;
 static bn_t order;
 
+/* Select the parameters of BLS12-381. With FP_PRIME == 381, ep_param_set_any_pairf selects B12_P381 and also
+ * configures the twist for G2, which ep_param_set alone does not. Check that the expected curve was selected. */
+static void set_curve_params(void) {
+  ep_param_set_any_pairf();
+  assert(ep_param_get() == B12_P381 && "relic is not configured for BLS12-381");
+}
+
+#if MULTI
+#if MULTI == PTHREAD
+static pthread_key_t thread_key;
+static bool thread_key_created = false;
+
+static void clean_relic_thread(void* unused) {
+  (void)unused;
+
+  core_clean();
+}
+#endif
+
+/* With multithreading support, relic keeps its context in thread-local storage. Set up the context of every other
+ * thread on its first use and clean it up when the thread exits. */
+static void init_relic_thread(void* unused) {
+  (void)unused;
+
+  core_init();
+  set_curve_params();
+#if MULTI == PTHREAD
+  if (thread_key_created) {
+    /* The destructor is only called for non-NULL values. */
+    pthread_setspecific(thread_key, &thread_key);
+  }
+#endif
+}
+#endif
+
 __attribute__((constructor)) static void init_relic(void) {
   if (!core_get()) {
     core_init();
     core_init_run = true;
   }
 
-  ep_param_set_any_pairf();
+  set_curve_params();
+#if MULTI
+#if MULTI == PTHREAD
+  thread_key_created = pthread_key_create(&thread_key, clean_relic_thread) == 0;
+#endif
+  core_set_thread_initializer(init_relic_thread, NULL);
+#endif
 
   bn_null(order);
   bn_new(order);
@@ -31,6 +81,62 @@ __attribute__((destructor)) static void clean_relic(void) {
   }
 }
 
+void wrapper_init_thread(void) {
+  /* With a registered thread initializer, core_get sets up the context if required. */
+  if (!core_get()) {
+    core_init();
+    set_curve_params();
+  }
+}
+
+size_t wrapper_fp_prime_bits(void) {
+  return FP_PRIME;
+}
+
+size_t wrapper_order_bits(void) {
+  return bn_bits(order);
+}
+
+int wrapper_curve_param(void) {
+  return ep_param_get();
+}
+
+bool wrapper_curve_is_bls12_381(void) {
+  return ep_param_get() == B12_P381 && ep_curve_is_pairf() == EP_B12;
+}
+
+const char* wrapper_pairing_type(void) {
+  switch (ep_curve_is_pairf()) {
+    case EP_BN:
+      return "BN";
+    case EP_B12:
+      return "BLS12";
+    default:
+      return "unknown";
+  }
+}
+
+const char* wrapper_relic_version(void) {
+  return RLC_VERSION;
+}
+
+bool wrapper_is_multithreaded(void) {
+#if MULTI
+  return true;
+#else
+  return false;
+#endif
+}
+
+void wrapper_bn_order(wrapper_bn_t* bn) {
+  RLC_TRY {
+    bn_copy(*bn, order);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
 void wrapper_bn_copy(wrapper_bn_t* dst, const wrapper_bn_t* src) {
   RLC_TRY {
     bn_copy(*dst, *src);
@@ -98,6 +204,15 @@ void wrapper_bn_neg(wrapper_bn_t* bn) {
   }
 }
 
+void wrapper_bn_reduce(wrapper_bn_t* bn) {
+  RLC_TRY {
+    bn_mod(*bn, *bn, order);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
 void wrapper_bn_sub_assign(wrapper_bn_t* dst, const wrapper_bn_t* rhs) {
   RLC_TRY {
     bn_sub(*dst, *dst, *rhs);
@@ -214,6 +329,42 @@ void wrapper_bn_rand(wrapper_bn_t* dst, const uint8_t* src, size_t len) {
   }
 }
 
+/* Relic's md_xmd does not implement the rule for domain separation tags longer than 255 bytes from
+ * RFC 9380, Section 5.3.3. Such tags are replaced by H("H2C-OVERSIZE-DST-" || DST). */
+static const uint8_t* shorten_dst(uint8_t hashed_dst[RLC_MD_LEN_SH256], const uint8_t* dst, size_t* dst_len) {
+  static const uint8_t prefix[] = "H2C-OVERSIZE-DST-";
+  const size_t prefix_len = sizeof(prefix) - 1;
+
+  if (*dst_len <= 255) {
+    return dst;
+  }
+
+  uint8_t* buf = malloc(prefix_len + *dst_len);
+  assert(buf != NULL);
+  memcpy(buf, prefix, prefix_len);
+  memcpy(buf + prefix_len, dst, *dst_len);
+  md_map_sh256(hashed_dst, buf, prefix_len + *dst_len);
+  free(buf);
+
+  *dst_len = RLC_MD_LEN_SH256;
+  return hashed_dst;
+}
+
+void wrapper_bn_hash_to_field(wrapper_bn_t* bn, const uint8_t* msg, size_t len, const uint8_t* dst, size_t dst_len) {
+  /* L = ceil((ceil(log2(r)) + k) / 8) = 48 for k = 128 as in RFC 9380 */
+  uint8_t buf[48];
+  uint8_t hashed_dst[RLC_MD_LEN_SH256];
+  dst = shorten_dst(hashed_dst, dst, &dst_len);
+  RLC_TRY {
+    md_xmd(buf, sizeof(buf), msg, len, dst, dst_len);
+    bn_read_bin(*bn, buf, sizeof(buf));
+    bn_mod(*bn, *bn, order);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
 bool wrapper_bn_is_zero(const wrapper_bn_t* value) {
   return bn_is_zero(*value) == 1;
 }
@@ -250,7 +401,18 @@ void wrapper_g1_generator(wrapper_g1_t* g1) {
   }
 }
 
+void wrapper_g1_map_from_field(wrapper_g1_t* g1, const uint8_t* bytes, size_t len) {
+  RLC_TRY {
+    ep_map_from_field(*g1, bytes, len);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
 void wrapper_g1_hash_to_curve(wrapper_g1_t* g1, const uint8_t* msg, size_t len, const uint8_t* dst, size_t dst_len) {
+  uint8_t hashed_dst[RLC_MD_LEN_SH256];
+  dst = shorten_dst(hashed_dst, dst, &dst_len);
   RLC_TRY {
     ep_map_dst(*g1, msg, len, dst, dst_len);
   }
@@ -286,6 +448,18 @@ void wrapper_g1_double(wrapper_g1_t* dst, const wrapper_g1_t* src) {
   }
 }
 
+void wrapper_g1_double_n(wrapper_g1_t* dst, const wrapper_g1_t* src, size_t k) {
+  RLC_TRY {
+    g1_copy(*dst, *src);
+    for (size_t i = 0; i < k; ++i) {
+      g1_dbl(*dst, *dst);
+    }
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
 void wrapper_g1_neg(wrapper_g1_t* g1) {
   RLC_TRY {
     g1_neg(*g1, *g1);
@@ -331,9 +505,43 @@ void wrapper_g1_mul(wrapper_g1_t* dst, const wrapper_g1_t* lhs, const wrapper_bn
   }
 }
 
+void wrapper_g1_mul_cof(wrapper_g1_t* dst, const wrapper_g1_t* src) {
+  RLC_TRY {
+    ep_mul_cof(*dst, *src);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+void wrapper_g1_mul_pre(wrapper_g1_table_t* table, const wrapper_g1_t* base) {
+  RLC_TRY {
+    g1_mul_pre(table->table, *base);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+void wrapper_g1_mul_fix(wrapper_g1_t* dst, const wrapper_g1_table_t* table, const wrapper_bn_t* rhs) {
+  RLC_TRY {
+    g1_mul_fix(*dst, table->table, *rhs);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
 void wrapper_g1_simmul(wrapper_g1_t* dst, const wrapper_g1_t* g1s, const wrapper_bn_t* scalars, size_t len) {
+  /* do not rely on relic to handle empty and single-element inputs */
   RLC_TRY {
-    g1_mul_sim_lot(*dst, g1s, scalars, len);
+    if (len == 0) {
+      g1_set_infty(*dst);
+    } else if (len == 1) {
+      g1_mul(*dst, g1s[0], scalars[0]);
+    } else {
+      g1_mul_sim_lot(*dst, g1s, scalars, len);
+    }
   }
   RLC_CATCH_ANY {
     assert(false);
@@ -377,10 +585,23 @@ bool wrapper_g1_is_valid(const wrapper_g1_t* value) {
   return g1_is_valid(*value) == 1;
 }
 
+bool wrapper_g1_on_curve(const wrapper_g1_t* value) {
+  return g1_on_curve(*value) == 1;
+}
+
 bool wrapper_g1_is_equal(const wrapper_g1_t* lhs, const wrapper_g1_t* rhs) {
   return g1_cmp(*lhs, *rhs) == RLC_EQ;
 }
 
+void wrapper_g1_conditional_select(wrapper_g1_t* dst, const wrapper_g1_t* lhs, const wrapper_g1_t* rhs, bool choice) {
+  const dig_t cond = choice;
+  g1_copy(*dst, *lhs);
+  dv_copy_cond((dig_t*)(*dst)->x, (const dig_t*)(*rhs)->x, sizeof((*dst)->x) / sizeof(dig_t), cond);
+  dv_copy_cond((dig_t*)(*dst)->y, (const dig_t*)(*rhs)->y, sizeof((*dst)->y) / sizeof(dig_t), cond);
+  dv_copy_cond((dig_t*)(*dst)->z, (const dig_t*)(*rhs)->z, sizeof((*dst)->z) / sizeof(dig_t), cond);
+  (*dst)->coord ^= ((*dst)->coord ^ (*rhs)->coord) & -(int)choice;
+}
+
 /* --- G2 --- */
 
 void wrapper_g2_init(wrapper_g2_t* g2) {
@@ -405,7 +626,18 @@ void wrapper_g2_generator(wrapper_g2_t* g2) {
   }
 }
 
+void wrapper_g2_map_from_field(wrapper_g2_t* g2, const uint8_t* bytes, size_t len) {
+  RLC_TRY {
+    ep2_map_from_field(*g2, bytes, len);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
 void wrapper_g2_hash_to_curve(wrapper_g2_t* g2, const uint8_t* msg, size_t len, const uint8_t* dst, size_t dst_len) {
+  uint8_t hashed_dst[RLC_MD_LEN_SH256];
+  dst = shorten_dst(hashed_dst, dst, &dst_len);
   RLC_TRY {
     ep2_map_dst(*g2, msg, len, dst, dst_len);
   }
@@ -441,6 +673,18 @@ void wrapper_g2_double(wrapper_g2_t* dst, const wrapper_g2_t* src) {
   }
 }
 
+void wrapper_g2_double_n(wrapper_g2_t* dst, const wrapper_g2_t* src, size_t k) {
+  RLC_TRY {
+    g2_copy(*dst, *src);
+    for (size_t i = 0; i < k; ++i) {
+      g2_dbl(*dst, *dst);
+    }
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
 void wrapper_g2_neg(wrapper_g2_t* g2) {
   RLC_TRY {
     g2_neg(*g2, *g2);
@@ -486,9 +730,43 @@ void wrapper_g2_mul(wrapper_g2_t* dst, const wrapper_g2_t* lhs, const wrapper_bn
   }
 }
 
+void wrapper_g2_mul_cof(wrapper_g2_t* dst, const wrapper_g2_t* src) {
+  RLC_TRY {
+    ep2_mul_cof(*dst, *src);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+void wrapper_g2_mul_pre(wrapper_g2_table_t* table, const wrapper_g2_t* base) {
+  RLC_TRY {
+    g2_mul_pre(table->table, *base);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+void wrapper_g2_mul_fix(wrapper_g2_t* dst, const wrapper_g2_table_t* table, const wrapper_bn_t* rhs) {
+  RLC_TRY {
+    g2_mul_fix(*dst, table->table, *rhs);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
 void wrapper_g2_simmul(wrapper_g2_t* dst, const wrapper_g2_t* g2s, const wrapper_bn_t* scalars, size_t len) {
+  /* do not rely on relic to handle empty and single-element inputs */
   RLC_TRY {
-    g2_mul_sim_lot(*dst, g2s, scalars, len);
+    if (len == 0) {
+      g2_set_infty(*dst);
+    } else if (len == 1) {
+      g2_mul(*dst, g2s[0], scalars[0]);
+    } else {
+      g2_mul_sim_lot(*dst, g2s, scalars, len);
+    }
   }
   RLC_CATCH_ANY {
     assert(false);
@@ -532,10 +810,23 @@ bool wrapper_g2_is_valid(const wrapper_g2_t* value) {
   return g2_is_valid(*value) == 1;
 }
 
+bool wrapper_g2_on_curve(const wrapper_g2_t* value) {
+  return g2_on_curve(*value) == 1;
+}
+
 bool wrapper_g2_is_equal(const wrapper_g2_t* lhs, const wrapper_g2_t* rhs) {
   return g2_cmp(*lhs, *rhs) == RLC_EQ;
 }
 
+void wrapper_g2_conditional_select(wrapper_g2_t* dst, const wrapper_g2_t* lhs, const wrapper_g2_t* rhs, bool choice) {
+  const dig_t cond = choice;
+  g2_copy(*dst, *lhs);
+  dv_copy_cond((dig_t*)(*dst)->x, (const dig_t*)(*rhs)->x, sizeof((*dst)->x) / sizeof(dig_t), cond);
+  dv_copy_cond((dig_t*)(*dst)->y, (const dig_t*)(*rhs)->y, sizeof((*dst)->y) / sizeof(dig_t), cond);
+  dv_copy_cond((dig_t*)(*dst)->z, (const dig_t*)(*rhs)->z, sizeof((*dst)->z) / sizeof(dig_t), cond);
+  (*dst)->coord ^= ((*dst)->coord ^ (*rhs)->coord) & -(int)choice;
+}
+
 /* --- gt --- */
 
 void wrapper_gt_init(wrapper_gt_t* gt) {
@@ -640,6 +931,24 @@ void wrapper_gt_mul(wrapper_gt_t* dst, const wrapper_gt_t* lhs, const wrapper_bn
   }
 }
 
+void wrapper_gt_sqr_cyc(wrapper_gt_t* dst, const wrapper_gt_t* src) {
+  RLC_TRY {
+    fp12_sqr_cyc(*dst, *src);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+void wrapper_gt_exp_cyc(wrapper_gt_t* dst, const wrapper_gt_t* lhs, const wrapper_bn_t* rhs) {
+  RLC_TRY {
+    fp12_exp_cyc(*dst, *lhs, *rhs);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
 void wrapper_gt_write_bin(uint8_t* dst, size_t len, const wrapper_gt_t* src, bool compressed) {
   RLC_TRY {
     gt_write_bin(dst, len, *src, compressed);
@@ -660,6 +969,74 @@ int wrapper_gt_read_bin(wrapper_gt_t* dst, const uint8_t* src, size_t len) {
   return RLC_OK;
 }
 
+/* Compression in the algebraic torus T2: elements g = g0 + g1 w of the cyclotomic subgroup of Fp12 = Fp6[w] / (w^2 - v)
+ * satisfy g0^2 - v g1^2 = 1 and are represented by b = (1 + g0) / g1 in Fp6. The unity is the only element of Gt with
+ * g1 = 0 and is encoded as b = 0. */
+void wrapper_gt_write_torus(uint8_t* dst, size_t len, const wrapper_gt_t* src) {
+  fp6_t b, t;
+  fp6_null(b);
+  fp6_null(t);
+  RLC_TRY {
+    fp6_new(b);
+    fp6_new(t);
+    if (gt_is_unity(*src)) {
+      fp6_zero(b);
+    } else {
+      fp6_set_dig(t, 1);
+      fp6_add(t, t, (*src)[0]);
+      fp6_inv(b, (*src)[1]);
+      fp6_mul(b, b, t);
+    }
+    fp6_write_bin(dst, len, b);
+  }
+  RLC_FINALLY {
+    fp6_free(b);
+    fp6_free(t);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+/* Decompression from the algebraic torus T2: g = (b + w) / (b - w) = (b^2 + v + 2 b w) / (b^2 - v). */
+int wrapper_gt_read_torus(wrapper_gt_t* dst, const uint8_t* src, size_t len) {
+  fp6_t b, t, v;
+  int ret = RLC_OK;
+  fp6_null(b);
+  fp6_null(t);
+  fp6_null(v);
+  RLC_TRY {
+    fp6_new(b);
+    fp6_new(t);
+    fp6_new(v);
+    fp6_read_bin(b, src, len);
+    if (fp6_is_zero(b)) {
+      gt_set_unity(*dst);
+    } else {
+      fp6_zero(v);
+      fp_set_dig(v[1][0], 1);
+      fp6_sqr(t, b);
+      fp6_sub(t, t, v);
+      fp6_inv(t, t);
+      fp6_dbl((*dst)[1], b);
+      fp6_mul((*dst)[1], (*dst)[1], t);
+      fp6_sqr(b, b);
+      fp6_add(b, b, v);
+      fp6_mul((*dst)[0], b, t);
+    }
+  }
+  RLC_FINALLY {
+    fp6_free(b);
+    fp6_free(t);
+    fp6_free(v);
+  }
+  RLC_CATCH_ANY {
+    ret = RLC_ERR;
+  }
+
+  return ret;
+}
+
 bool wrapper_gt_is_neutral(const wrapper_gt_t* value) {
   return gt_is_unity(*value) == 1;
 }
@@ -672,9 +1049,20 @@ bool wrapper_gt_is_equal(const wrapper_gt_t* lhs, const wrapper_gt_t* rhs) {
   return gt_cmp(*lhs, *rhs) == RLC_EQ;
 }
 
+void wrapper_gt_conditional_select(wrapper_gt_t* dst, const wrapper_gt_t* lhs, const wrapper_gt_t* rhs, bool choice) {
+  gt_copy(*dst, *lhs);
+  dv_copy_cond((dig_t*)*dst, (const dig_t*)*rhs, sizeof(*dst) / sizeof(dig_t), choice);
+}
+
 /* --- pairing --- */
 
 void wrapper_pc_map(wrapper_gt_t* gt, const wrapper_g1_t* g1, const wrapper_g2_t* g2) {
+  /* e(O, Q) = e(P, O) = 1; do not rely on the pairing implementation to handle the point at infinity */
+  if (g1_is_infty(*g1) || g2_is_infty(*g2)) {
+    gt_set_unity(*gt);
+    return;
+  }
+
   RLC_TRY {
     pc_map(*gt, *g1, *g2);
   }
@@ -684,8 +1072,356 @@ void wrapper_pc_map(wrapper_gt_t* gt, const wrapper_g1_t* g1, const wrapper_g2_t
 }
 
 void wrapper_pc_map_sim(wrapper_gt_t* gt, const wrapper_g1_t* g1, const wrapper_g2_t* g2, size_t len) {
+  /* the empty sum of pairings is the unity; do not rely on relic to handle empty inputs */
+  if (len == 0) {
+    gt_set_unity(*gt);
+    return;
+  }
+
+  RLC_TRY {
+    if (len <= WRAPPER_PC_CHUNK_SIZE) {
+      pc_map_sim(*gt, g1, g2, len);
+    } else {
+      /* accumulate the Miller loops of all chunks and perform a single final exponentiation */
+      wrapper_pc_miller_loop_sim(gt, g1, g2, len);
+      pp_exp_k12(*gt, *gt);
+    }
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+/* Miller loop for BLS12 curves without final exponentiation. This follows pp_mil_k12 in relic, which
+ * is not exported. */
+static void miller_loop_sim_chunk(wrapper_gt_t* gt, const wrapper_g1_t* g1, const wrapper_g2_t* g2, size_t len) {
+  g1_t* p  = RLC_ALLOCA(g1_t, len);
+  g1_t* _p = RLC_ALLOCA(g1_t, len);
+  g2_t* q  = RLC_ALLOCA(g2_t, len);
+  g2_t* t  = RLC_ALLOCA(g2_t, len);
+  size_t m = 0;
+  fp12_t l;
+  bn_t a;
+
+  fp12_null(l);
+  bn_null(a);
+
+  RLC_TRY {
+    fp12_new(l);
+    bn_new(a);
+    if (len && (p == NULL || _p == NULL || q == NULL || t == NULL)) {
+      RLC_THROW(ERR_NO_MEMORY);
+    }
+
+    /* pairs involving the point at infinity do not contribute to the result */
+    for (size_t i = 0; i < len; ++i) {
+      if (!g1_is_infty(g1[i]) && !g2_is_infty(g2[i])) {
+        g1_norm(p[m], g1[i]);
+        g2_norm(q[m], g2[i]);
+        ++m;
+      }
+    }
+
+    fp12_set_dig(*gt, 1);
+    if (m > 0) {
+      for (size_t j = 0; j < m; ++j) {
+        g2_copy(t[j], q[j]);
+#if EP_ADD == BASIC
+        g1_neg(_p[j], p[j]);
+#else
+        fp_add(_p[j]->x, p[j]->x, p[j]->x);
+        fp_add(_p[j]->x, _p[j]->x, p[j]->x);
+        fp_neg(_p[j]->y, p[j]->y);
+#endif
+      }
+
+      fp_prime_get_par(a);
+      fp12_zero(l);
+      for (int i = bn_bits(a) - 2; i >= 0; --i) {
+        fp12_sqr(*gt, *gt);
+        for (size_t j = 0; j < m; ++j) {
+          pp_dbl_k12(l, t[j], t[j], _p[j]);
+          fp12_mul_dxs(*gt, *gt, l);
+          if (bn_get_bit(a, i)) {
+            pp_add_k12(l, t[j], q[j], p[j]);
+            fp12_mul_dxs(*gt, *gt, l);
+          }
+        }
+      }
+      if (bn_sign(a) == RLC_NEG) {
+        /* f_{-a,Q}(P) = 1/f_{a,Q}(P) */
+        fp12_inv_cyc(*gt, *gt);
+      }
+    }
+  }
+  RLC_FINALLY {
+    fp12_free(l);
+    bn_free(a);
+    RLC_FREE(p);
+    RLC_FREE(_p);
+    RLC_FREE(q);
+    RLC_FREE(t);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+void wrapper_pc_miller_loop_sim(wrapper_gt_t* gt, const wrapper_g1_t* g1, const wrapper_g2_t* g2, size_t len) {
+  gt_t ml;
+
+  gt_null(ml);
+
   RLC_TRY {
-    pc_map_sim(*gt, g1, g2, len);
+    gt_new(ml);
+    miller_loop_sim_chunk(gt, g1, g2, RLC_MIN(len, WRAPPER_PC_CHUNK_SIZE));
+    for (size_t i = WRAPPER_PC_CHUNK_SIZE; i < len; i += WRAPPER_PC_CHUNK_SIZE) {
+      miller_loop_sim_chunk(&ml, g1 + i, g2 + i, RLC_MIN(len - i, WRAPPER_PC_CHUNK_SIZE));
+      fp12_mul(*gt, *gt, ml);
+    }
+  }
+  RLC_FINALLY {
+    gt_free(ml);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+void wrapper_pc_final_exp(wrapper_gt_t* dst, const wrapper_gt_t* src) {
+  RLC_TRY {
+    pp_exp_k12(*dst, *src);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+/* --- prepared points in G2 --- */
+
+#define LINE_COEFF(l, slot) ((l)[(slot) / 3][(slot) % 3])
+
+/* Split the lines obtained by evaluating the same step of the Miller loop at (0, 0), (1, 0) and (0, 1) into their
+ * constant part and the parts scaled by x and y. The lines are affine in the coordinates of the point in G1 and each
+ * part occupies a single coefficient in Fp2. Determining the coefficients instead of hardcoding them keeps this
+ * independent of relic's layout of lines, which depends on the type of the twist. */
+static bool split_line(wrapper_g2_line_t* line, fp12_t l[3]) {
+  fp12_sub(l[1], l[1], l[0]);
+  fp12_sub(l[2], l[2], l[0]);
+  for (int k = 0; k < 3; ++k) {
+    bool found = false;
+
+    line->slots[k] = 0;
+    fp2_zero(line->coeffs[k]);
+    for (uint8_t slot = 0; slot < 6; ++slot) {
+      if (!fp2_is_zero(LINE_COEFF(l[k], slot))) {
+        if (found) {
+          return false;
+        }
+        found = true;
+        line->slots[k] = slot;
+        fp2_copy(line->coeffs[k], LINE_COEFF(l[k], slot));
+      }
+    }
+  }
+  return true;
+}
+
+/* Perform a doubling or addition step of the Miller loop on t and store the line of the step. */
+static bool prepare_step(wrapper_g2_line_t* line, g2_t t, g2_t q, bool add, g2_t r[3], fp12_t l[3], g1_t probe[3]) {
+  for (int k = 0; k < 3; ++k) {
+    g2_copy(r[k], t);
+    fp12_zero(l[k]);
+    if (add) {
+      pp_add_k12(l[k], r[k], q, probe[k]);
+    } else {
+      pp_dbl_k12(l[k], r[k], r[k], probe[k]);
+    }
+  }
+  g2_copy(t, r[0]);
+  return split_line(line, l);
+}
+
+void wrapper_g2_prepare(wrapper_g2_prepared_t* prepared, const wrapper_g2_t* g2) {
+  g1_t probe[3];
+  g2_t q, t, r[3];
+  fp12_t l[3];
+  bn_t a;
+  size_t len = 0;
+
+  memset(prepared, 0, sizeof(*prepared));
+  for (int k = 0; k < 3; ++k) {
+    g1_null(probe[k]);
+    g2_null(r[k]);
+    fp12_null(l[k]);
+  }
+  g2_null(q);
+  g2_null(t);
+  bn_null(a);
+
+  RLC_TRY {
+    for (int k = 0; k < 3; ++k) {
+      g1_new(probe[k]);
+      g2_new(r[k]);
+      fp12_new(l[k]);
+    }
+    g2_new(q);
+    g2_new(t);
+    bn_new(a);
+
+    /* the points (0, 0), (1, 0) and (0, 1); the line functions only use their coordinates */
+    for (int k = 0; k < 3; ++k) {
+      fp_zero(probe[k]->x);
+      fp_zero(probe[k]->y);
+      fp_set_dig(probe[k]->z, 1);
+      probe[k]->coord = BASIC;
+    }
+    fp_set_dig(probe[1]->x, 1);
+    fp_set_dig(probe[2]->y, 1);
+
+    /* the point at infinity has no lines; this follows the steps of miller_loop_sim_chunk */
+    if (!g2_is_infty(*g2)) {
+      g2_norm(q, *g2);
+      g2_copy(t, q);
+      fp_prime_get_par(a);
+      for (int i = bn_bits(a) - 2; i >= 0; --i) {
+        if (len == WRAPPER_G2_PREPARED_LINES || !prepare_step(&prepared->lines[len++], t, q, false, r, l, probe)) {
+          RLC_THROW(ERR_NO_VALID);
+        }
+        if (bn_get_bit(a, i)) {
+          if (len == WRAPPER_G2_PREPARED_LINES || !prepare_step(&prepared->lines[len++], t, q, true, r, l, probe)) {
+            RLC_THROW(ERR_NO_VALID);
+          }
+        }
+      }
+      prepared->len = len;
+    }
+  }
+  RLC_FINALLY {
+    for (int k = 0; k < 3; ++k) {
+      g1_free(probe[k]);
+      g2_free(r[k]);
+      fp12_free(l[k]);
+    }
+    g2_free(q);
+    g2_free(t);
+    bn_free(a);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+/* Evaluate the prepared line at the point p using t as temporary. */
+static void eval_line(fp12_t l, const wrapper_g2_line_t* line, const g1_t p, fp2_t t) {
+  fp12_zero(l);
+  fp2_copy(LINE_COEFF(l, line->slots[0]), line->coeffs[0]);
+  fp_mul(t[0], line->coeffs[1][0], p->x);
+  fp_mul(t[1], line->coeffs[1][1], p->x);
+  fp2_add(LINE_COEFF(l, line->slots[1]), LINE_COEFF(l, line->slots[1]), t);
+  fp_mul(t[0], line->coeffs[2][0], p->y);
+  fp_mul(t[1], line->coeffs[2][1], p->y);
+  fp2_add(LINE_COEFF(l, line->slots[2]), LINE_COEFF(l, line->slots[2]), t);
+}
+
+/* Miller loop with prepared points in G2. This performs the same steps as miller_loop_sim_chunk, but evaluates the
+ * precomputed lines instead of computing them. */
+static void miller_loop_prepared_chunk(wrapper_gt_t* gt, const wrapper_g1_t* g1, const wrapper_g2_prepared_t* const* g2,
+                                       size_t len) {
+  g1_t* p                         = RLC_ALLOCA(g1_t, len);
+  g1_t* _p                        = RLC_ALLOCA(g1_t, len);
+  const wrapper_g2_prepared_t** q = RLC_ALLOCA(const wrapper_g2_prepared_t*, len);
+  size_t m                        = 0;
+  fp12_t l;
+  fp2_t t;
+  bn_t a;
+
+  fp12_null(l);
+  fp2_null(t);
+  bn_null(a);
+
+  RLC_TRY {
+    fp12_new(l);
+    fp2_new(t);
+    bn_new(a);
+    if (len && (p == NULL || _p == NULL || q == NULL)) {
+      RLC_THROW(ERR_NO_MEMORY);
+    }
+
+    /* pairs involving the point at infinity do not contribute to the result */
+    for (size_t i = 0; i < len; ++i) {
+      if (!g1_is_infty(g1[i]) && g2[i]->len) {
+        g1_norm(p[m], g1[i]);
+        q[m] = g2[i];
+        ++m;
+      }
+    }
+
+    fp12_set_dig(*gt, 1);
+    if (m > 0) {
+      size_t k = 0;
+
+      for (size_t j = 0; j < m; ++j) {
+#if EP_ADD == BASIC
+        g1_neg(_p[j], p[j]);
+#else
+        fp_add(_p[j]->x, p[j]->x, p[j]->x);
+        fp_add(_p[j]->x, _p[j]->x, p[j]->x);
+        fp_neg(_p[j]->y, p[j]->y);
+#endif
+      }
+
+      fp_prime_get_par(a);
+      for (int i = bn_bits(a) - 2; i >= 0; --i) {
+        fp12_sqr(*gt, *gt);
+        for (size_t j = 0; j < m; ++j) {
+          eval_line(l, &q[j]->lines[k], _p[j], t);
+          fp12_mul_dxs(*gt, *gt, l);
+        }
+        ++k;
+        if (bn_get_bit(a, i)) {
+          for (size_t j = 0; j < m; ++j) {
+            eval_line(l, &q[j]->lines[k], p[j], t);
+            fp12_mul_dxs(*gt, *gt, l);
+          }
+          ++k;
+        }
+      }
+      if (bn_sign(a) == RLC_NEG) {
+        /* f_{-a,Q}(P) = 1/f_{a,Q}(P) */
+        fp12_inv_cyc(*gt, *gt);
+      }
+    }
+  }
+  RLC_FINALLY {
+    fp12_free(l);
+    fp2_free(t);
+    bn_free(a);
+    RLC_FREE(p);
+    RLC_FREE(_p);
+    RLC_FREE(q);
+  }
+  RLC_CATCH_ANY {
+    assert(false);
+  }
+}
+
+void wrapper_pc_miller_loop_prepared(wrapper_gt_t* gt, const wrapper_g1_t* g1, const wrapper_g2_prepared_t* const* g2,
+                                     size_t len) {
+  gt_t ml;
+
+  gt_null(ml);
+
+  RLC_TRY {
+    gt_new(ml);
+    miller_loop_prepared_chunk(gt, g1, g2, RLC_MIN(len, WRAPPER_PC_CHUNK_SIZE));
+    for (size_t i = WRAPPER_PC_CHUNK_SIZE; i < len; i += WRAPPER_PC_CHUNK_SIZE) {
+      miller_loop_prepared_chunk(&ml, g1 + i, g2 + i, RLC_MIN(len - i, WRAPPER_PC_CHUNK_SIZE));
+      fp12_mul(*gt, *gt, ml);
+    }
+  }
+  RLC_FINALLY {
+    gt_free(ml);
   }
   RLC_CATCH_ANY {
     assert(false);
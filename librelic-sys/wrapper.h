@@ -5,8 +5,18 @@
 #include <stdint.h>
 #include <stdbool.h>
 
+void wrapper_init_thread(void);
+size_t wrapper_fp_prime_bits(void);
+size_t wrapper_order_bits(void);
+int wrapper_curve_param(void);
+bool wrapper_curve_is_bls12_381(void);
+const char* wrapper_pairing_type(void);
+const char* wrapper_relic_version(void);
+bool wrapper_is_multithreaded(void);
+
 typedef bn_t wrapper_bn_t;
 
+void wrapper_bn_order(wrapper_bn_t* bn);
 void wrapper_bn_copy(wrapper_bn_t* dst, const wrapper_bn_t* src);
 void wrapper_bn_zero(wrapper_bn_t* bn);
 void wrapper_bn_one(wrapper_bn_t* bn);
@@ -14,6 +24,7 @@ void wrapper_bn_add_assign(wrapper_bn_t* dst, const wrapper_bn_t* rhs);
 void wrapper_bn_add(wrapper_bn_t* dst, const wrapper_bn_t* lhs, const wrapper_bn_t* rhs);
 void wrapper_bn_double(wrapper_bn_t* dst, const wrapper_bn_t* src);
 void wrapper_bn_neg(wrapper_bn_t* bn);
+void wrapper_bn_reduce(wrapper_bn_t* bn);
 void wrapper_bn_sub_assign(wrapper_bn_t* dst, const wrapper_bn_t* rhs);
 void wrapper_bn_sub(wrapper_bn_t* dst, const wrapper_bn_t* lhs, const wrapper_bn_t* rhs);
 void wrapper_bn_mul_assign(wrapper_bn_t* dst, const wrapper_bn_t* rhs);
@@ -23,53 +34,74 @@ int wrapper_bn_inv(wrapper_bn_t* dst, const wrapper_bn_t* val);
 void wrapper_bn_write_bin(uint8_t* dst, size_t len, const wrapper_bn_t* src);
 int wrapper_bn_read_bin(wrapper_bn_t* dst, const uint8_t* src, size_t len, bool pack);
 void wrapper_bn_rand(wrapper_bn_t* dst, const uint8_t* src, size_t len);
+void wrapper_bn_hash_to_field(wrapper_bn_t* bn, const uint8_t* msg, size_t len, const uint8_t* dst, size_t dst_len);
 bool wrapper_bn_is_zero(const wrapper_bn_t* value);
 bool wrapper_bn_is_even(const wrapper_bn_t* value);
 bool wrapper_bn_is_odd(const wrapper_bn_t* value);
 
 typedef g1_t wrapper_g1_t;
+typedef struct {
+  g1_t table[RLC_G1_TABLE];
+} wrapper_g1_table_t;
 
 void wrapper_g1_init(wrapper_g1_t* g1);
 void wrapper_g1_neutral(wrapper_g1_t* g1);
 void wrapper_g1_generator(wrapper_g1_t* g1);
+void wrapper_g1_map_from_field(wrapper_g1_t* g1, const uint8_t* bytes, size_t len);
 void wrapper_g1_hash_to_curve(wrapper_g1_t* g1, const uint8_t* msg, size_t len, const uint8_t* dst, size_t dst_len);
 void wrapper_g1_add_assign(wrapper_g1_t* dst, const wrapper_g1_t* rhs);
 void wrapper_g1_add(wrapper_g1_t* dst, const wrapper_g1_t* lhs, const wrapper_g1_t* rhs);
 void wrapper_g1_double(wrapper_g1_t* dst, const wrapper_g1_t* src);
+void wrapper_g1_double_n(wrapper_g1_t* dst, const wrapper_g1_t* src, size_t k);
 void wrapper_g1_neg(wrapper_g1_t* g1);
 void wrapper_g1_sub_assign(wrapper_g1_t* dst, const wrapper_g1_t* rhs);
 void wrapper_g1_sub(wrapper_g1_t* dst, const wrapper_g1_t* lhs, const wrapper_g1_t* rhs);
 void wrapper_g1_mul_assign(wrapper_g1_t* dst, const wrapper_bn_t* rhs);
 void wrapper_g1_mul(wrapper_g1_t* dst, const wrapper_g1_t* lhs, const wrapper_bn_t* rhs);
+void wrapper_g1_mul_cof(wrapper_g1_t* dst, const wrapper_g1_t* src);
+void wrapper_g1_mul_pre(wrapper_g1_table_t* table, const wrapper_g1_t* base);
+void wrapper_g1_mul_fix(wrapper_g1_t* dst, const wrapper_g1_table_t* table, const wrapper_bn_t* rhs);
 void wrapper_g1_simmul(wrapper_g1_t* dst, const wrapper_g1_t* g1s, const wrapper_bn_t* scalars, size_t len);
 void wrapper_g1_norm(wrapper_g1_t* dst, const wrapper_g1_t* src);
 void wrapper_g1_write_bin(uint8_t* dst, size_t len, const wrapper_g1_t* src, bool compressed);
 int wrapper_g1_read_bin(wrapper_g1_t* dst, const uint8_t* src, size_t len);
 bool wrapper_g1_is_neutral(const wrapper_g1_t* value);
 bool wrapper_g1_is_valid(const wrapper_g1_t* value);
+bool wrapper_g1_on_curve(const wrapper_g1_t* value);
 bool wrapper_g1_is_equal(const wrapper_g1_t* lhs, const wrapper_g1_t* rhs);
+void wrapper_g1_conditional_select(wrapper_g1_t* dst, const wrapper_g1_t* lhs, const wrapper_g1_t* rhs, bool choice);
 
 typedef g2_t wrapper_g2_t;
+typedef struct {
+  g2_t table[RLC_G2_TABLE];
+} wrapper_g2_table_t;
 
 void wrapper_g2_init(wrapper_g2_t* g2);
 void wrapper_g2_neutral(wrapper_g2_t* g2);
 void wrapper_g2_generator(wrapper_g2_t* g2);
+void wrapper_g2_map_from_field(wrapper_g2_t* g2, const uint8_t* bytes, size_t len);
 void wrapper_g2_hash_to_curve(wrapper_g2_t* g2, const uint8_t* msg, size_t len, const uint8_t* dst, size_t dst_len);
 void wrapper_g2_add_assign(wrapper_g2_t* dst, const wrapper_g2_t* rhs);
 void wrapper_g2_add(wrapper_g2_t* dst, const wrapper_g2_t* lhs, const wrapper_g2_t* rhs);
 void wrapper_g2_double(wrapper_g2_t* dst, const wrapper_g2_t* src);
+void wrapper_g2_double_n(wrapper_g2_t* dst, const wrapper_g2_t* src, size_t k);
 void wrapper_g2_neg(wrapper_g2_t* g2);
 void wrapper_g2_sub_assign(wrapper_g2_t* dst, const wrapper_g2_t* rhs);
 void wrapper_g2_sub(wrapper_g2_t* dst, const wrapper_g2_t* lhs, const wrapper_g2_t* rhs);
 void wrapper_g2_mul_assign(wrapper_g2_t* dst, const wrapper_bn_t* rhs);
 void wrapper_g2_mul(wrapper_g2_t* dst, const wrapper_g2_t* lhs, const wrapper_bn_t* rhs);
+void wrapper_g2_mul_cof(wrapper_g2_t* dst, const wrapper_g2_t* src);
+void wrapper_g2_mul_pre(wrapper_g2_table_t* table, const wrapper_g2_t* base);
+void wrapper_g2_mul_fix(wrapper_g2_t* dst, const wrapper_g2_table_t* table, const wrapper_bn_t* rhs);
 void wrapper_g2_simmul(wrapper_g2_t* dst, const wrapper_g2_t* g2s, const wrapper_bn_t* scalars, size_t len);
 void wrapper_g2_norm(wrapper_g2_t* dst, const wrapper_g2_t* src);
 void wrapper_g2_write_bin(uint8_t* dst, size_t len, const wrapper_g2_t* src, bool compressed);
 int wrapper_g2_read_bin(wrapper_g2_t* dst, const uint8_t* src, size_t len);
 bool wrapper_g2_is_neutral(const wrapper_g2_t* value);
 bool wrapper_g2_is_valid(const wrapper_g2_t* value);
+bool wrapper_g2_on_curve(const wrapper_g2_t* value);
 bool wrapper_g2_is_equal(const wrapper_g2_t* lhs, const wrapper_g2_t* rhs);
+void wrapper_g2_conditional_select(wrapper_g2_t* dst, const wrapper_g2_t* lhs, const wrapper_g2_t* rhs, bool choice);
 
 typedef gt_t wrapper_gt_t;
 
@@ -84,13 +116,46 @@ void wrapper_gt_sub_assign(wrapper_gt_t* dst, const wrapper_gt_t* rhs);
 void wrapper_gt_sub(wrapper_gt_t* dst, const wrapper_gt_t* lhs, const wrapper_gt_t* rhs);
 void wrapper_gt_mul_assign(wrapper_gt_t* dst, const wrapper_bn_t* rhs);
 void wrapper_gt_mul(wrapper_gt_t* dst, const wrapper_gt_t* lhs, const wrapper_bn_t* rhs);
+void wrapper_gt_sqr_cyc(wrapper_gt_t* dst, const wrapper_gt_t* src);
+void wrapper_gt_exp_cyc(wrapper_gt_t* dst, const wrapper_gt_t* lhs, const wrapper_bn_t* rhs);
 void wrapper_gt_write_bin(uint8_t* dst, size_t len, const wrapper_gt_t* src, bool compressed);
 int wrapper_gt_read_bin(wrapper_gt_t* dst, const uint8_t* src, size_t len);
+void wrapper_gt_write_torus(uint8_t* dst, size_t len, const wrapper_gt_t* src);
+int wrapper_gt_read_torus(wrapper_gt_t* dst, const uint8_t* src, size_t len);
 bool wrapper_gt_is_neutral(const wrapper_gt_t* value);
 bool wrapper_gt_is_valid(const wrapper_gt_t* value);
 bool wrapper_gt_is_equal(const wrapper_gt_t* lhs, const wrapper_gt_t* rhs);
+void wrapper_gt_conditional_select(wrapper_gt_t* dst, const wrapper_gt_t* lhs, const wrapper_gt_t* rhs, bool choice);
+
+/* Relic does not limit the number of pairs of simultaneous pairings, but allocates temporary copies of all points on
+ * the stack (unless built with ALLOC=DYNAMIC). Hence, larger inputs are processed in chunks of this many pairs. */
+#define WRAPPER_PC_CHUNK_SIZE 128
 
 void wrapper_pc_map(wrapper_gt_t* gt, const wrapper_g1_t* g1, const wrapper_g2_t* g2);
 void wrapper_pc_map_sim(wrapper_gt_t* gt, const wrapper_g1_t* g1, const wrapper_g2_t* g2, size_t len);
+void wrapper_pc_miller_loop_sim(wrapper_gt_t* gt, const wrapper_g1_t* g1, const wrapper_g2_t* g2, size_t len);
+void wrapper_pc_final_exp(wrapper_gt_t* dst, const wrapper_gt_t* src);
+
+/* Number of lines in the Miller loop of BLS12-381: one doubling step for every bit of the curve parameter but the most
+ * significant one, and one addition step for every such bit that is set. */
+#define WRAPPER_G2_PREPARED_LINES 68
+
+/* A line of the Miller loop is a sparse element of Fp12 that is affine in the coordinates of the point in G1. The line
+ * is stored as its constant part and the parts that are scaled by the x and y coordinates, together with the indices of
+ * the coefficients in Fp2 that the parts occupy. */
+typedef struct {
+  fp2_t coeffs[3];
+  uint8_t slots[3];
+} wrapper_g2_line_t;
+
+typedef struct {
+  wrapper_g2_line_t lines[WRAPPER_G2_PREPARED_LINES];
+  /* number of used lines; 0 for the point at infinity */
+  size_t len;
+} wrapper_g2_prepared_t;
+
+void wrapper_g2_prepare(wrapper_g2_prepared_t* prepared, const wrapper_g2_t* g2);
+void wrapper_pc_miller_loop_prepared(wrapper_gt_t* gt, const wrapper_g1_t* g1, const wrapper_g2_prepared_t* const* g2,
+                                     size_t len);
 
 #endif
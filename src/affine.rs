@@ -5,14 +5,24 @@
 //! wrapper around default representation whereas the elements are initially
 //! normalized. Whenever an operation is performed on the group elements, the
 //! default representation is returned as result.
+//!
+//! Affine points can be combined with each other and with projective points
+//! in any combination of owned and borrowed operands. To accumulate into an
+//! affine point, use the projective representation, e.g., `acc += affine`,
+//! and convert back once at the end.
 
-use core::ops::{Add, Mul, Neg, Sub};
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Deref, Mul, Neg, Sub},
+    str::FromStr,
+};
 
 use pairing::group::{
     prime::{PrimeCurve, PrimeCurveAffine},
     GroupEncoding,
 };
-use subtle::Choice;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use crate::Scalar;
 
@@ -35,7 +45,7 @@ pub(crate) mod private {
 /// let g1 = G1Projective::hash_to_curve(b"a point", b"public parameters");
 /// let affine = g1.to_affine();
 /// ```
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
 #[repr(transparent)]
 pub struct Affine<G>(pub(crate) G)
 where
@@ -50,6 +60,34 @@ where
     }
 }
 
+/// Access to the methods of the wrapped element
+///
+/// Methods of the projective representation can be called on affine points
+/// directly. Methods implemented for the affine representation take precedence
+/// over those of the projective representation with the same name. There is
+/// no `DerefMut` implementation since the wrapped element has to stay
+/// normalized.
+///
+/// ```
+/// use bls12_381_relic::{G1Affine, G1Projective};
+/// use bls12_381_relic::group::{Curve, Group};
+///
+/// let affine: G1Affine = G1Projective::hash_to_curve(b"a point", b"public parameters").to_affine();
+/// assert!(affine.is_valid());
+/// assert_eq!(affine.double().double(), affine.double_n(2));
+/// ```
+impl<G> Deref for Affine<G>
+where
+    G: private::Sealed,
+{
+    type Target = G;
+
+    #[inline]
+    fn deref(&self) -> &G {
+        &self.0
+    }
+}
+
 impl<'a, G> TryFrom<&'a [u8]> for Affine<G>
 where
     G: private::Sealed,
@@ -63,6 +101,38 @@ where
     }
 }
 
+impl<G> FromStr for Affine<G>
+where
+    G: private::Sealed,
+    G: FromStr,
+    Affine<G>: From<G>,
+{
+    type Err = G::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        G::from_str(s).map(Self::from)
+    }
+}
+
+impl<'a, G> TryFrom<&'a str> for Affine<G>
+where
+    G: private::Sealed,
+    G: FromStr,
+    Affine<G>: From<G>,
+{
+    type Error = G::Err;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Addition returning the projective representation
+///
+/// The right-hand side can be anything that can be added to `G`, which
+/// includes owned and borrowed affine points since they implement
+/// `AsRef<G>`. A separate implementation for affine operands would overlap
+/// with this one.
 impl<G, Gp> Add<Gp> for Affine<G>
 where
     G: private::Sealed,
@@ -100,6 +170,25 @@ where
     }
 }
 
+impl<'a, G> Neg for &'a Affine<G>
+where
+    G: private::Sealed,
+    &'a G: Neg<Output = G>,
+{
+    type Output = Affine<G>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Affine(-&self.0)
+    }
+}
+
+/// Subtraction returning the projective representation
+///
+/// The right-hand side can be anything that can be subtracted from `G`, which
+/// includes owned and borrowed affine points since they implement
+/// `AsRef<G>`. A separate implementation for affine operands would overlap
+/// with this one.
 impl<G, Gp> Sub<Gp> for Affine<G>
 where
     G: private::Sealed,
@@ -151,6 +240,89 @@ where
     }
 }
 
+/// Lexicographic order of the compressed encodings
+///
+/// This order is arbitrary and not related to the group structure, but it is
+/// stable across platforms and consistent with [Eq]. It allows to sort points
+/// deterministically, e.g., to canonicalize a set of public keys.
+impl<G> Ord for Affine<G>
+where
+    G: private::Sealed + Eq,
+    Affine<G>: GroupEncoding,
+    <Affine<G> as GroupEncoding>::Repr: AsRef<[u8]>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_bytes().as_ref().cmp(other.to_bytes().as_ref())
+    }
+}
+
+impl<G> PartialOrd for Affine<G>
+where
+    G: private::Sealed + Eq,
+    Affine<G>: GroupEncoding,
+    <Affine<G> as GroupEncoding>::Repr: AsRef<[u8]>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<G> fmt::Display for Affine<G>
+where
+    G: private::Sealed,
+    G: fmt::Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<G> fmt::LowerHex for Affine<G>
+where
+    G: private::Sealed,
+    G: fmt::LowerHex,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl<G> fmt::UpperHex for Affine<G>
+where
+    G: private::Sealed,
+    G: fmt::UpperHex,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+impl<G> ConstantTimeEq for Affine<G>
+where
+    G: private::Sealed,
+    G: ConstantTimeEq,
+{
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<G> ConditionallySelectable for Affine<G>
+where
+    G: private::Sealed,
+    G: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(G::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
 impl<G> PrimeCurveAffine for Affine<G>
 where
     G: private::Sealed,
@@ -0,0 +1,527 @@
+//! BLS signatures
+//!
+//! This module implements the BLS signature scheme with signatures in `G1` and
+//! public keys in `G2`.
+//!
+//! ```
+//! use bls12_381_relic::bls::SecretKey;
+//!
+//! let sk = SecretKey::random(rand::thread_rng());
+//! let pk = sk.public_key();
+//!
+//! let sigma = sk.sign(b"this is the message");
+//! assert!(pk.verify(b"this is the message", &sigma));
+//! assert!(!pk.verify(b"this is another message", &sigma));
+//! ```
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use hkdf::HkdfExtract;
+use pairing::group::{ff::Field, Group};
+use rand_core::RngCore;
+use sha2::{Digest, Sha256};
+
+use crate::{pairing_sum_signed, G1Affine, G1Projective, G2Affine, G2Projective, Gt, Scalar};
+
+/// Domain separator used by [SecretKey::sign] and [PublicKey::verify]
+///
+/// Pass it to [fast_aggregate_verify] and [batch_verify] to verify signatures
+/// produced by [SecretKey::sign].
+pub const HASH_DOMAIN_SEPERATOR: &[u8] = b"BLS12381RELIC_BLS_SIG_G1";
+const BATCH_DOMAIN_SEPERATOR: &[u8] = b"BLS12381RELIC_BLS_BATCH";
+
+/// Salt of the key generation as specified for BLS signatures and EIP-2333
+pub const KEYGEN_SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-";
+
+/// BLS secret key
+#[derive(Clone)]
+pub struct SecretKey(Scalar);
+
+impl SecretKey {
+    /// Sample a new secret key
+    pub fn random(rng: impl RngCore) -> Self {
+        Self(Scalar::random(rng))
+    }
+
+    /// Derive a secret key from input keying material
+    ///
+    /// This function implements `KeyGen` from the BLS signature draft
+    /// (draft-irtf-cfrg-bls-signature-05) with an empty `key_info`. With
+    /// [KEYGEN_SALT] as salt, it also computes the master secret key of
+    /// EIP-2333. The input keying material must be at least 32 bytes long and
+    /// is required to be kept secret.
+    ///
+    /// ```
+    /// use bls12_381_relic::bls::{SecretKey, KEYGEN_SALT};
+    ///
+    /// let sk = SecretKey::derive(&[0x42; 32], KEYGEN_SALT);
+    /// assert_eq!(
+    ///     sk.public_key(),
+    ///     SecretKey::derive(&[0x42; 32], KEYGEN_SALT).public_key()
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ikm` is shorter than 32 bytes.
+    pub fn derive(ikm: &[u8], salt: &[u8]) -> Self {
+        assert!(
+            ikm.len() >= 32,
+            "input keying material must be at least 32 bytes long"
+        );
+
+        let mut salt = Sha256::digest(salt);
+        loop {
+            let mut extract = HkdfExtract::<Sha256>::new(Some(&salt));
+            extract.input_ikm(ikm);
+            extract.input_ikm(&[0]);
+            let (_, hkdf) = extract.finalize();
+
+            let mut okm = [0u8; 48];
+            hkdf.expand(&(okm.len() as u16).to_be_bytes(), &mut okm)
+                .expect("48 bytes are a valid output length");
+            let sk = Scalar::from_okm(&okm);
+            if !sk.is_zero_vartime() {
+                return Self(sk);
+            }
+            salt = Sha256::digest(salt);
+        }
+    }
+
+    /// Derive the public key
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(G2Projective::generator() * self.0)
+    }
+
+    /// Sign a message
+    ///
+    /// The message is hashed to `G1` with [HASH_DOMAIN_SEPERATOR].
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        Signature(G1Projective::hash_to_curve(msg, HASH_DOMAIN_SEPERATOR) * self.0)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for SecretKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SecretKey {}
+
+impl From<Scalar> for SecretKey {
+    #[inline]
+    fn from(value: Scalar) -> Self {
+        Self(value)
+    }
+}
+
+/// BLS public key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey(G2Projective);
+
+impl PublicKey {
+    /// Verify a signature on a message
+    ///
+    /// The message is hashed to `G1` with [HASH_DOMAIN_SEPERATOR].
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> bool {
+        // e(H(msg), pk) == e(sigma, h) <=> e(-H(msg), pk) + e(sigma, h) == 0
+        let base_point = G1Projective::hash_to_curve(msg, HASH_DOMAIN_SEPERATOR);
+        pairing_sum_signed([
+            (base_point, self.0, true),
+            (signature.0, G2Projective::generator(), false),
+        ]) == Gt::identity()
+    }
+}
+
+impl From<G2Projective> for PublicKey {
+    #[inline]
+    fn from(value: G2Projective) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PublicKey> for G2Projective {
+    #[inline]
+    fn from(value: PublicKey) -> Self {
+        value.0
+    }
+}
+
+/// BLS signature
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature(G1Projective);
+
+impl From<G1Projective> for Signature {
+    #[inline]
+    fn from(value: G1Projective) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Signature> for G1Projective {
+    #[inline]
+    fn from(value: Signature) -> Self {
+        value.0
+    }
+}
+
+/// Derive a secret key from input keying material
+///
+/// See [SecretKey::derive].
+#[inline]
+pub fn derive_key(ikm: &[u8], salt: &[u8]) -> SecretKey {
+    SecretKey::derive(ikm, salt)
+}
+
+/// Sign a message
+#[inline]
+pub fn sign(sk: &SecretKey, msg: &[u8]) -> Signature {
+    sk.sign(msg)
+}
+
+/// Sign multiple messages
+///
+/// This is a convenience function that signs the messages one after another
+/// with [SecretKey::sign]. It is not faster than signing them individually.
+#[cfg(feature = "alloc")]
+pub fn sign_many(sk: &SecretKey, msgs: &[&[u8]]) -> Vec<Signature> {
+    msgs.iter().map(|msg| sk.sign(msg)).collect()
+}
+
+/// Aggregate public keys
+///
+/// Public keys are aggregated by summing them up. This works for keys in `G2`
+/// as used by this module as well as for keys in `G1` as used by the variant
+/// of BLS signatures with minimal public key size.
+///
+/// ```
+/// use bls12_381_relic::{bls::aggregate_keys, G1Projective, G2Projective};
+/// use bls12_381_relic::group::Group;
+///
+/// let mut rng = rand::thread_rng();
+/// let keys = [G1Projective::random(&mut rng), G1Projective::random(&mut rng)];
+/// assert_eq!(aggregate_keys(&keys), keys[0] + keys[1]);
+///
+/// let keys = [G2Projective::random(&mut rng), G2Projective::random(&mut rng)];
+/// assert_eq!(aggregate_keys(&keys), keys[0] + keys[1]);
+/// ```
+#[inline]
+pub fn aggregate_keys<G: Group>(keys: &[G]) -> G {
+    keys.iter().sum()
+}
+
+/// Aggregate signatures given in affine representation
+///
+/// The aggregated signature can be verified against the aggregated public key
+/// if all signatures are on the same message (see [fast_aggregate_verify]).
+#[inline]
+pub fn aggregate_signatures(signatures: &[G1Affine]) -> G1Projective {
+    signatures.iter().sum()
+}
+
+/// Verify an aggregated signature of multiple signers on the same message
+///
+/// The public keys are aggregated and the aggregated signature is then
+/// verified against the aggregated key. Thereby, only one pairing-sum is
+/// evaluated instead of one per signer. The message is hashed to `G1` with the
+/// domain separator `dst`. Returns `false` if no public keys are provided.
+///
+/// Note that this scheme requires a proof of possession of the secret keys to
+/// prevent rogue-key attacks.
+pub fn fast_aggregate_verify(
+    keys: &[G2Affine],
+    msg: &[u8],
+    signature: &G1Affine,
+    dst: &[u8],
+) -> bool {
+    if keys.is_empty() {
+        return false;
+    }
+
+    let key: G2Projective = keys.iter().sum();
+    let base_point = G1Projective::hash_to_curve(msg, dst);
+    pairing_sum_signed([
+        (base_point, key, true),
+        (
+            G1Projective::from(signature),
+            G2Projective::generator(),
+            false,
+        ),
+    ]) == Gt::identity()
+}
+
+/// Verify multiple signatures at once
+///
+/// Each entry consists of a public key, a message and a signature, where the
+/// messages are hashed to `G1` with the domain separator `dst`. The
+/// signatures are checked with a random linear combination
+///
+/// `e(sum r_i sigma_i, h) == sum e(r_i H(m_i), pk_i)`
+///
+/// which requires a single multi-pairing instead of one pairing-sum per entry.
+/// Like [fast_aggregate_verify], this function returns `false` if no entries
+/// are provided. A 256-bit seed is sampled from `rng` and the 128-bit
+/// coefficients `r_i` are derived from it by hashing. Since the seed is
+/// unknown to whoever produced the entries, invalid signatures cannot be
+/// chosen to cancel each other out.
+///
+/// ```
+/// use bls12_381_relic::{bls::batch_verify, G1Projective, G2Projective, Scalar};
+/// use bls12_381_relic::{ff::Field, group::{Curve, Group}};
+///
+/// let mut rng = rand::thread_rng();
+/// let dst = b"BLS-SIG-DST";
+/// let msgs: [&[u8]; 2] = [b"msg 1", b"msg 2"];
+/// let entries = msgs.map(|msg| {
+///     let sk = Scalar::random(&mut rng);
+///     let pk = (G2Projective::generator() * sk).to_affine();
+///     let sigma = (G1Projective::hash_to_curve(msg, dst) * sk).to_affine();
+///     (pk, msg, sigma)
+/// });
+/// assert!(batch_verify(&entries, dst, &mut rng));
+/// ```
+pub fn batch_verify(
+    entries: &[(G2Affine, &[u8], G1Affine)],
+    dst: &[u8],
+    mut rng: impl RngCore,
+) -> bool {
+    if entries.is_empty() {
+        return false;
+    }
+
+    let mut seed = [0u8; 32];
+    rng.fill_bytes(&mut seed);
+
+    let coefficients = || {
+        (0..entries.len() as u64).map(|i| {
+            let digest = Sha256::new()
+                .chain_update(BATCH_DOMAIN_SEPERATOR)
+                .chain_update(seed)
+                .chain_update(i.to_be_bytes())
+                .finalize();
+            let mut bytes = [0u8; 16];
+            bytes.copy_from_slice(&digest[..16]);
+            Scalar::from_u128(u128::from_be_bytes(bytes))
+        })
+    };
+
+    let signature: G1Projective = entries
+        .iter()
+        .zip(coefficients())
+        .map(|((_, _, sigma), r)| (sigma, r))
+        .sum();
+    pairing_sum_signed(
+        entries
+            .iter()
+            .zip(coefficients())
+            .map(|((pk, msg, _), r)| {
+                (
+                    G1Projective::hash_to_curve(msg, dst) * r,
+                    G2Projective::from(pk),
+                    true,
+                )
+            })
+            .chain(core::iter::once((
+                signature,
+                G2Projective::generator(),
+                false,
+            ))),
+    ) == Gt::identity()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sign_verify() {
+        let sk = SecretKey::random(rand::thread_rng());
+        let pk = sk.public_key();
+
+        let sigma = sign(&sk, b"this is the message");
+        assert!(pk.verify(b"this is the message", &sigma));
+        assert!(!pk.verify(b"this is another message", &sigma));
+
+        let pk = SecretKey::random(rand::thread_rng()).public_key();
+        assert!(!pk.verify(b"this is the message", &sigma));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sign_many() {
+        let sk = SecretKey::random(rand::thread_rng());
+        let msgs: [&[u8]; 3] = [b"msg 1", b"msg 2", b"msg 3"];
+
+        let signatures = super::sign_many(&sk, &msgs);
+        assert_eq!(signatures.len(), msgs.len());
+        for (msg, sigma) in msgs.iter().zip(signatures) {
+            assert_eq!(sigma, sign(&sk, msg));
+        }
+    }
+
+    #[test]
+    fn derive_key() {
+        // test case 0 of EIP-2333
+        let seed = [
+            0xc5, 0x52, 0x57, 0xc3, 0x60, 0xc0, 0x7c, 0x72, 0x02, 0x9a, 0xeb, 0xc1, 0xb5, 0x3c,
+            0x05, 0xed, 0x03, 0x62, 0xad, 0xa3, 0x8e, 0xad, 0x3e, 0x3e, 0x9e, 0xfa, 0x37, 0x08,
+            0xe5, 0x34, 0x95, 0x53, 0x1f, 0x09, 0xa6, 0x98, 0x75, 0x99, 0xd1, 0x82, 0x64, 0xc1,
+            0xe1, 0xc9, 0x2f, 0x2c, 0xf1, 0x41, 0x63, 0x0c, 0x7a, 0x3c, 0x4a, 0xb7, 0xc8, 0x1b,
+            0x2f, 0x00, 0x16, 0x98, 0xe7, 0x46, 0x3b, 0x04,
+        ];
+        // 6083874454709270928345386274498605044986640685124978867557563392430687146096
+        let master_sk = Scalar::from_bytes(&[
+            0x0d, 0x73, 0x59, 0xd5, 0x79, 0x63, 0xab, 0x8f, 0xbb, 0xde, 0x18, 0x52, 0xdc, 0xf5,
+            0x53, 0xfe, 0xdb, 0xc3, 0x1f, 0x46, 0x4d, 0x80, 0xee, 0x7d, 0x40, 0xae, 0x68, 0x31,
+            0x22, 0xb4, 0x50, 0x70,
+        ])
+        .unwrap();
+
+        let sk = super::derive_key(&seed, KEYGEN_SALT);
+        assert_eq!(sk.0, master_sk);
+        assert_eq!(sk.public_key(), SecretKey::from(master_sk).public_key());
+        assert_ne!(SecretKey::derive(&seed, b"other salt").0, master_sk);
+    }
+
+    #[test]
+    #[should_panic]
+    fn derive_key_short_ikm() {
+        SecretKey::derive(&[0x42; 31], KEYGEN_SALT);
+    }
+
+    #[test]
+    fn fast_aggregate_verify() {
+        let mut rng = rand::thread_rng();
+        let msg = b"the common message";
+        let sks: [SecretKey; 3] = core::array::from_fn(|_| SecretKey::random(&mut rng));
+        let keys: [G2Affine; 3] =
+            core::array::from_fn(|i| G2Projective::from(sks[i].public_key()).into());
+        let signatures: [G1Affine; 3] =
+            core::array::from_fn(|i| G1Projective::from(sks[i].sign(msg)).into());
+
+        let signature = G1Affine::from(aggregate_signatures(&signatures));
+        assert_eq!(
+            G1Projective::from(signature),
+            signatures
+                .iter()
+                .fold(G1Projective::identity(), |acc, sigma| acc + sigma)
+        );
+
+        let dst = HASH_DOMAIN_SEPERATOR;
+        assert!(super::fast_aggregate_verify(&keys, msg, &signature, dst));
+        assert!(!super::fast_aggregate_verify(
+            &keys,
+            b"other message",
+            &signature,
+            dst
+        ));
+        assert!(!super::fast_aggregate_verify(
+            &keys,
+            msg,
+            &signature,
+            b"other dst"
+        ));
+        assert!(!super::fast_aggregate_verify(
+            &keys[..2],
+            msg,
+            &signature,
+            dst
+        ));
+        assert!(!super::fast_aggregate_verify(&[], msg, &signature, dst));
+        assert!(!super::fast_aggregate_verify(
+            &keys,
+            msg,
+            &signatures[0],
+            dst
+        ));
+    }
+
+    #[test]
+    fn batch_verify() {
+        let mut rng = rand::thread_rng();
+        let msgs: [&[u8]; 4] = [b"msg 1", b"msg 2", b"msg 3", b"msg 4"];
+        let mut entries = msgs.map(|msg| {
+            let sk = SecretKey::random(&mut rng);
+            let sigma = sk.sign(msg);
+            (
+                G2Affine::from(G2Projective::from(sk.public_key())),
+                msg,
+                G1Affine::from(G1Projective::from(sigma)),
+            )
+        });
+
+        assert!(super::batch_verify(
+            &entries,
+            HASH_DOMAIN_SEPERATOR,
+            &mut rng
+        ));
+        assert!(super::batch_verify(
+            &entries[..1],
+            HASH_DOMAIN_SEPERATOR,
+            &mut rng
+        ));
+        assert!(!super::batch_verify(&[], HASH_DOMAIN_SEPERATOR, &mut rng));
+        assert!(!super::batch_verify(&entries, b"other dst", &mut rng));
+
+        // one invalid signature
+        let valid = entries[2].2;
+        entries[2].2 = G1Affine::from(G1Projective::from(
+            SecretKey::random(&mut rng).sign(msgs[2]),
+        ));
+        assert!(!super::batch_verify(
+            &entries,
+            HASH_DOMAIN_SEPERATOR,
+            &mut rng
+        ));
+
+        // two invalid signatures that cancel each other out in the plain sum
+        let delta = G1Projective::random(&mut rng);
+        entries[2].2 = G1Affine::from(G1Projective::from(valid) + delta);
+        entries[3].2 = G1Affine::from(G1Projective::from(entries[3].2) - delta);
+        assert!(!super::batch_verify(
+            &entries,
+            HASH_DOMAIN_SEPERATOR,
+            &mut rng
+        ));
+    }
+
+    #[test]
+    fn aggregate_keys() {
+        let mut rng = rand::thread_rng();
+        let sks: [Scalar; 3] = core::array::from_fn(|_| Scalar::random(&mut rng));
+        let sk_sum = sks.iter().sum::<Scalar>();
+
+        let keys = sks.map(|sk| G1Projective::generator() * sk);
+        assert_eq!(
+            super::aggregate_keys(&keys),
+            G1Projective::generator() * sk_sum
+        );
+        let keys = sks.map(|sk| G2Projective::generator() * sk);
+        assert_eq!(
+            super::aggregate_keys(&keys),
+            G2Projective::generator() * sk_sum
+        );
+
+        assert_eq!(
+            super::aggregate_keys::<G1Projective>(&[]),
+            G1Projective::identity()
+        );
+        assert_eq!(
+            super::aggregate_keys::<G2Projective>(&[]),
+            G2Projective::identity()
+        );
+    }
+}
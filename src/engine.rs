@@ -7,21 +7,66 @@
 //!
 //! In addition to the engine, this module also provides some additional
 //! functions that evaluate the pairing on projective coordinations and sums of
-//! pairings (as `Gt` is also using additive notation).
+//! pairings (as `Gt` is also using additive notation). Since relic does not
+//! benefit from affine points, the projective variants, e.g.,
+//! [RelicEngine::multi_miller_loop_slices], are the recommended interface
+//! unless points in `G2` are used repeatedly. In that case, [G2Prepared] with
+//! [MultiMillerLoop::multi_miller_loop] avoids recomputing the line functions.
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
-use librelic_sys::wrapper_pc_map;
+use core::{
+    ffi::CStr,
+    fmt,
+    iter::Sum,
+    mem::MaybeUninit,
+    ops::{Add, AddAssign},
+};
+
+#[cfg(feature = "alloc")]
+use crate::zeroize_wrappers;
 #[cfg(feature = "alloc")]
 use librelic_sys::wrapper_pc_map_sim;
-#[cfg(not(feature = "alloc"))]
+use librelic_sys::{
+    wrapper_curve_is_bls12_381, wrapper_curve_param, wrapper_fp_prime_bits, wrapper_g2_prepare,
+    wrapper_g2_prepared_t, wrapper_gt_add, wrapper_gt_add_assign, wrapper_gt_neutral, wrapper_gt_t,
+    wrapper_order_bits, wrapper_pairing_type, wrapper_pc_final_exp, wrapper_pc_map,
+    wrapper_pc_miller_loop_prepared, wrapper_pc_miller_loop_sim, wrapper_relic_version,
+};
 use pairing::group::Group;
 use pairing::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
 
-use crate::{gt::new_wrapper, G1Affine, G1Projective, G2Affine, G2Projective, Gt, Scalar};
+use crate::{
+    g1, g2, gt::new_wrapper, Error, G1Affine, G1Projective, G2Affine, G2Projective, Gt, Scalar,
+};
+
+/// Configuration of the linked relic library
+///
+/// See [RelicEngine::curve_info].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CurveInfo {
+    /// Bit length of the prime of the base field
+    pub prime_bits: usize,
+    /// Bit length of the order of the groups
+    pub order_bits: usize,
+    /// relic's identifier of the curve parameters
+    pub curve_param: i32,
+    /// Family of the pairing-friendly curve, e.g., `"BLS12"`
+    pub pairing_type: &'static str,
+    /// Whether the curve parameters are those of BLS12-381
+    pub is_bls12_381: bool,
+    /// Version of relic
+    pub relic_version: &'static str,
+}
+
+/// Convert a static string returned by the wrapper (internal)
+fn static_str(value: *const core::ffi::c_char) -> &'static str {
+    // SAFETY: the wrapper only returns pointers to string literals
+    unsafe { CStr::from_ptr(value) }.to_str().unwrap_or("")
+}
 
 /// Relic-based [Engine]
 ///
@@ -53,7 +98,38 @@ impl Engine for RelicEngine {
 }
 
 impl RelicEngine {
+    /// Obtain the configuration of the linked relic library
+    ///
+    /// This allows to check at runtime that relic is configured for
+    /// BLS12-381, e.g., if a relic library provided by the system is used.
+    ///
+    /// ```
+    /// use bls12_381_relic::RelicEngine;
+    ///
+    /// let info = RelicEngine::curve_info();
+    /// assert!(info.is_bls12_381);
+    /// assert_eq!(info.prime_bits, 381);
+    /// ```
+    pub fn curve_info() -> CurveInfo {
+        unsafe {
+            CurveInfo {
+                prime_bits: wrapper_fp_prime_bits(),
+                order_bits: wrapper_order_bits(),
+                curve_param: wrapper_curve_param(),
+                pairing_type: static_str(wrapper_pairing_type()),
+                is_bls12_381: wrapper_curve_is_bls12_381(),
+                relic_version: static_str(wrapper_relic_version()),
+            }
+        }
+    }
+
     /// Compute pairing of a point in group `G1` a point in group `G2`
+    ///
+    /// If one of the points is the identity, the result is the identity and
+    /// the pairing is not evaluated. Hence, the running time depends on
+    /// whether one of the inputs is the identity. Note that relic's pairing
+    /// implementation does not run in constant time with respect to the
+    /// identity either.
     #[inline]
     pub fn projective_pairing(p: &G1Projective, q: &G2Projective) -> Gt {
         let mut gt = new_wrapper();
@@ -63,7 +139,121 @@ impl RelicEngine {
         gt.into()
     }
 
+    /// Compute the Miller loop of a point in group `G1` and a point in group
+    /// `G2`
+    ///
+    /// The final exponentiation is not applied. Outputs of multiple Miller
+    /// loops can be added up and mapped to [Gt] with a single final
+    /// exponentiation, e.g., to verify pairing equations such as the one of
+    /// Groth16 proofs.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Projective, G2Projective, RelicEngine, pair};
+    /// use bls12_381_relic::group::Group;
+    /// use bls12_381_relic::pairing::MillerLoopResult;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let g1s = [G1Projective::random(&mut rng), G1Projective::random(&mut rng)];
+    /// let g2s = [G2Projective::random(&mut rng), G2Projective::random(&mut rng)];
+    ///
+    /// let ml = RelicEngine::projective_miller_loop(&g1s[0], &g2s[0])
+    ///     + RelicEngine::projective_miller_loop(&g1s[1], &g2s[1]);
+    /// assert_eq!(
+    ///     ml.final_exponentiation(),
+    ///     pair(g1s[0], g2s[0]) + pair(g1s[1], g2s[1])
+    /// );
+    /// ```
+    #[inline]
+    pub fn projective_miller_loop(p: &G1Projective, q: &G2Projective) -> MillerLoopOutput {
+        let mut ml = new_wrapper();
+        unsafe {
+            wrapper_pc_miller_loop_sim(&mut ml, &p.0, &q.0, 1);
+        }
+        MillerLoopOutput(ml)
+    }
+
+    /// Compute the Miller loops of pairs of points in projective coordinates
+    ///
+    /// This is the recommended interface for multi-Miller loops. The `i`-th
+    /// point of `g1s` is paired with the `i`-th point of `g2s`. The slices are
+    /// passed to relic as they are: in contrast to
+    /// [MultiMillerLoop::multi_miller_loop], the points neither need to be
+    /// converted to affine coordinates by the caller nor copied, and the
+    /// `alloc` feature is not required. If points in `G2` are used for many
+    /// Miller loops, consider preparing them with [G2Prepared] instead.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Projective, G2Projective, RelicEngine, pair};
+    /// use bls12_381_relic::group::Group;
+    /// use bls12_381_relic::pairing::MillerLoopResult;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let g1s = [G1Projective::random(&mut rng), G1Projective::random(&mut rng)];
+    /// let g2s = [G2Projective::random(&mut rng), G2Projective::random(&mut rng)];
+    ///
+    /// assert_eq!(
+    ///     RelicEngine::multi_miller_loop_slices(&g1s, &g2s).final_exponentiation(),
+    ///     pair(g1s[0], g2s[0]) + pair(g1s[1], g2s[1])
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `g1s` and `g2s` have different lengths.
+    pub fn multi_miller_loop_slices(
+        g1s: &[G1Projective],
+        g2s: &[G2Projective],
+    ) -> MillerLoopOutput {
+        assert_eq!(g1s.len(), g2s.len());
+
+        let mut ml = new_wrapper();
+        // G1Projective and G2Projective are transparent wrappers of the relic types
+        unsafe {
+            wrapper_pc_miller_loop_sim(
+                &mut ml,
+                g1s.as_ptr().cast(),
+                g2s.as_ptr().cast(),
+                g1s.len(),
+            );
+        }
+        MillerLoopOutput(ml)
+    }
+
+    /// Compute pairing of points given by their compressed encodings
+    ///
+    /// Both points are decoded and validated, including the check for
+    /// membership in the prime-order subgroups, before the pairing is
+    /// computed.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Projective, G2Projective, RelicEngine, pair};
+    /// use bls12_381_relic::group::Group;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let g1 = G1Projective::random(&mut rng);
+    /// let g2 = G2Projective::random(&mut rng);
+    /// let g1_bytes: [u8; 49] = g1.into();
+    /// let g2_bytes: [u8; 97] = g2.into();
+    ///
+    /// let gt = RelicEngine::pairing_from_bytes(&g1_bytes, &g2_bytes).unwrap();
+    /// assert_eq!(gt, pair(g1, g2));
+    /// ```
+    pub fn pairing_from_bytes(
+        p_bytes: &[u8; g1::COMPRESSED_BYTES_SIZE],
+        q_bytes: &[u8; g2::COMPRESSED_BYTES_SIZE],
+    ) -> Result<Gt, Error> {
+        let p = G1Projective::try_from(p_bytes)?;
+        let q = G2Projective::try_from(q_bytes)?;
+        Ok(Self::projective_pairing(&p, &q))
+    }
+
     /// Compute multiple pairings and their sum
+    ///
+    /// There is no limit on the number of pairings; see [crate::pairing_sum].
+    ///
+    /// The points are copied into contiguous buffers if the `alloc` feature is
+    /// enabled. For points that are already stored in slices,
+    /// [RelicEngine::multi_miller_loop_slices] avoids the copies.
     pub fn projective_multi_miller_loop(terms: &[(&G1Projective, &G2Projective)]) -> Gt {
         #[cfg(feature = "alloc")]
         {
@@ -78,6 +268,8 @@ impl RelicEngine {
             unsafe {
                 wrapper_pc_map_sim(&mut gt, g1s.as_ptr(), g2s.as_ptr(), terms.len());
             }
+            zeroize_wrappers(&mut g1s);
+            zeroize_wrappers(&mut g2s);
             gt.into()
         }
 
@@ -88,6 +280,66 @@ impl RelicEngine {
             })
         }
     }
+
+    /// Check if two sums of pairings are equal
+    ///
+    /// This function checks `sum(e(lhs)) == sum(e(rhs))` by evaluating
+    /// `sum(e(lhs)) + sum(e(-rhs))` with a single multi-pairing and comparing
+    /// the result to the identity. Thereby, only one final exponentiation is
+    /// required for the whole equation.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Projective, G2Projective, RelicEngine, Scalar};
+    /// use bls12_381_relic::{group::Group, ff::Field};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let g1 = G1Projective::random(&mut rng);
+    /// let g2 = G2Projective::random(&mut rng);
+    /// let s = Scalar::random(&mut rng);
+    ///
+    /// assert!(RelicEngine::pairing_products_equal(
+    ///     &[(&(g1 * s), &g2)],
+    ///     &[(&g1, &(g2 * s))]
+    /// ));
+    /// ```
+    pub fn pairing_products_equal(
+        lhs: &[(&G1Projective, &G2Projective)],
+        rhs: &[(&G1Projective, &G2Projective)],
+    ) -> bool {
+        #[cfg(feature = "alloc")]
+        {
+            let len = lhs.len() + rhs.len();
+            let mut g1s = Vec::with_capacity(len);
+            let mut g2s = Vec::with_capacity(len);
+            lhs.iter().for_each(|(g1, g2)| {
+                g1s.push((*g1).into());
+                g2s.push((*g2).into());
+            });
+            rhs.iter().for_each(|(g1, g2)| {
+                g1s.push((-*g1).into());
+                g2s.push((*g2).into());
+            });
+
+            let mut gt = new_wrapper();
+            unsafe {
+                wrapper_pc_map_sim(&mut gt, g1s.as_ptr(), g2s.as_ptr(), len);
+            }
+            zeroize_wrappers(&mut g1s);
+            zeroize_wrappers(&mut g2s);
+            Gt::from(gt).is_identity().into()
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        {
+            let gt = lhs.iter().fold(Gt::identity(), |a, (g1, g2)| {
+                a + Self::projective_pairing(g1, g2)
+            });
+            let gt = rhs
+                .iter()
+                .fold(gt, |a, (g1, g2)| a + Self::projective_pairing(&-*g1, g2));
+            gt.is_identity().into()
+        }
+    }
 }
 
 impl PairingCurveAffine for G1Affine {
@@ -112,11 +364,75 @@ impl PairingCurveAffine for G2Affine {
     }
 }
 
+/// Point in `G2` with precomputed line functions
+///
+/// The Miller loop of the optimal ate pairing computes a sequence of line
+/// functions that only depend on the point in `G2` and evaluates them at the
+/// point in `G1`. Preparing a point computes the coefficients of all lines
+/// once, such that Miller loops with the prepared point only evaluate them.
+/// This speeds up repeated pairings with a fixed point in `G2`, e.g., when
+/// verifying many BLS signatures under the same public key. Preparing a point
+/// costs more than a single Miller loop, so it only pays off if the prepared
+/// point is used more than once. The coefficients amount to roughly 20 KiB.
+///
+/// ```
+/// use bls12_381_relic::{pair, G1Projective, G2Prepared, G2Projective, RelicEngine};
+/// use bls12_381_relic::group::{Curve, Group};
+/// use bls12_381_relic::pairing::{MillerLoopResult, MultiMillerLoop};
+///
+/// let mut rng = rand::thread_rng();
+/// let g2 = G2Projective::random(&mut rng);
+/// let prepared = G2Prepared::from(g2);
+/// for _ in 0..4 {
+///     let g1 = G1Projective::random(&mut rng);
+///     let ml = RelicEngine::multi_miller_loop(&[(&g1.to_affine(), &prepared)]);
+///     assert_eq!(ml.final_exponentiation(), pair(g1, g2));
+/// }
+/// ```
+#[derive(Clone)]
+pub struct G2Prepared(wrapper_g2_prepared_t);
+
+impl fmt::Debug for G2Prepared {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("G2Prepared").finish_non_exhaustive()
+    }
+}
+
+impl From<&G2Projective> for G2Prepared {
+    fn from(value: &G2Projective) -> Self {
+        let mut prepared = MaybeUninit::uninit();
+        unsafe {
+            wrapper_g2_prepare(prepared.as_mut_ptr(), &value.0);
+            Self(prepared.assume_init())
+        }
+    }
+}
+
+impl From<G2Projective> for G2Prepared {
+    #[inline]
+    fn from(value: G2Projective) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&G2Affine> for G2Prepared {
+    #[inline]
+    fn from(value: &G2Affine) -> Self {
+        Self::from(&value.0)
+    }
+}
+
+impl From<G2Affine> for G2Prepared {
+    #[inline]
+    fn from(value: G2Affine) -> Self {
+        Self::from(&value.0)
+    }
+}
+
 impl MultiMillerLoop for RelicEngine {
-    // there is no prepared version
-    type G2Prepared = G2Affine;
+    type G2Prepared = G2Prepared;
 
-    type Result = Gt;
+    type Result = MillerLoopOutput;
 
     #[cfg(feature = "alloc")]
     fn multi_miller_loop(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result {
@@ -124,39 +440,158 @@ impl MultiMillerLoop for RelicEngine {
         let mut g2s = Vec::with_capacity(terms.len());
         terms.iter().for_each(|(g1, g2)| {
             g1s.push((*g1).into());
-            g2s.push((*g2).into());
+            g2s.push(&g2.0 as *const _);
         });
 
-        let mut gt = new_wrapper();
+        let mut ml = new_wrapper();
         unsafe {
-            wrapper_pc_map_sim(&mut gt, g1s.as_ptr(), g2s.as_ptr(), terms.len());
+            wrapper_pc_miller_loop_prepared(&mut ml, g1s.as_ptr(), g2s.as_ptr(), terms.len());
         }
-        gt.into()
+        zeroize_wrappers(&mut g1s);
+        MillerLoopOutput(ml)
     }
 
     #[cfg(not(feature = "alloc"))]
     fn multi_miller_loop(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result {
-        terms
-            .iter()
-            .fold(Gt::identity(), |a, (g1, g2)| a + super::pair(*g1, *g2))
+        // accumulate in place and reuse a single temporary for the Miller loops
+        let mut result = MillerLoopOutput::default();
+        let mut ml = new_wrapper();
+        terms.iter().for_each(|(g1, g2)| unsafe {
+            let g2: *const wrapper_g2_prepared_t = &g2.0;
+            wrapper_pc_miller_loop_prepared(&mut ml, &g1.0 .0, &g2, 1);
+            wrapper_gt_add_assign(&mut result.0, &ml);
+        });
+        result
     }
 }
 
-impl MillerLoopResult for Gt {
-    type Gt = Gt;
+/// Output of a Miller loop
+///
+/// This type represents the result of a (multi-)Miller loop before the final
+/// exponentiation is applied. Outputs can be accumulated cheaply via addition
+/// (which corresponds to multiplication in the underlying field) and then
+/// mapped to [Gt] with a single final exponentiation using
+/// [MillerLoopResult::final_exponentiation].
+///
+/// Note that values of this type are not elements of [Gt] and hence can not be
+/// compared meaningfully.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct MillerLoopOutput(wrapper_gt_t);
+
+impl MillerLoopOutput {
+    /// Check whether the output equals `gt` after applying the final
+    /// exponentiation
+    #[inline]
+    pub fn eq_after_final_exp(&self, gt: &Gt) -> bool {
+        self.final_exponentiation() == *gt
+    }
+}
+
+impl Default for MillerLoopOutput {
+    fn default() -> Self {
+        let mut value = new_wrapper();
+        unsafe {
+            wrapper_gt_neutral(&mut value);
+        }
+        Self(value)
+    }
+}
+
+impl Add for MillerLoopOutput {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl Add<&MillerLoopOutput> for MillerLoopOutput {
+    type Output = Self;
 
     #[inline]
+    fn add(mut self, rhs: &Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl Add for &MillerLoopOutput {
+    type Output = MillerLoopOutput;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut ret = new_wrapper();
+        unsafe {
+            wrapper_gt_add(&mut ret, &self.0, &rhs.0);
+        }
+        MillerLoopOutput(ret)
+    }
+}
+
+impl AddAssign for MillerLoopOutput {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl AddAssign<&MillerLoopOutput> for MillerLoopOutput {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        unsafe {
+            wrapper_gt_add_assign(&mut self.0, &rhs.0);
+        }
+    }
+}
+
+impl Sum for MillerLoopOutput {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, v| acc + v)
+    }
+}
+
+impl<'a> Sum<&'a MillerLoopOutput> for MillerLoopOutput {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, v| acc + v)
+    }
+}
+
+impl MillerLoopResult for MillerLoopOutput {
+    type Gt = Gt;
+
     fn final_exponentiation(&self) -> Self::Gt {
-        *self
+        let mut gt = new_wrapper();
+        unsafe {
+            wrapper_pc_final_exp(&mut gt, &self.0);
+        }
+        gt.into()
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{group::Group, pairing_sum};
+    use crate::{
+        group::{ff::Field, prime::PrimeCurveAffine, Group},
+        pair, pairing_sum,
+    };
 
     use super::*;
 
+    #[test]
+    fn curve_info() {
+        let info = RelicEngine::curve_info();
+        assert_eq!(info.prime_bits, 381);
+        assert_eq!(info.order_bits, 255);
+        assert_eq!(info.pairing_type, "BLS12");
+        assert!(info.is_bls12_381);
+        assert!(!info.relic_version.is_empty());
+    }
+
     #[test]
     fn projective_pairing() {
         let mut rng = rand::thread_rng();
@@ -169,6 +604,23 @@ mod test {
         );
     }
 
+    #[test]
+    fn pairing_with_identity() {
+        let mut rng = rand::thread_rng();
+        let g1 = G1Projective::random(&mut rng);
+        let g2 = G2Projective::random(&mut rng);
+
+        for (p, q) in [
+            (G1Projective::identity(), g2),
+            (g1, G2Projective::identity()),
+            (G1Projective::identity(), G2Projective::identity()),
+        ] {
+            assert_eq!(RelicEngine::projective_pairing(&p, &q), Gt::identity());
+            assert_eq!(pair(p, q), Gt::identity());
+        }
+        assert_ne!(RelicEngine::projective_pairing(&g1, &g2), Gt::identity());
+    }
+
     #[test]
     fn pair_with() {
         let mut rng = rand::thread_rng();
@@ -190,11 +642,235 @@ mod test {
             G2Affine::from(G2Projective::random(&mut rng)),
             G2Affine::from(G2Projective::random(&mut rng)),
         ];
-        let terms = [(&g1s[0], &g2s[0]), (&g1s[1], &g2s[1])];
+        let prepared = [G2Prepared::from(g2s[0]), G2Prepared::from(g2s[1])];
 
-        let mml = RelicEngine::multi_miller_loop(&terms).final_exponentiation();
-        let check = pairing_sum(terms);
+        let mml =
+            RelicEngine::multi_miller_loop(&[(&g1s[0], &prepared[0]), (&g1s[1], &prepared[1])])
+                .final_exponentiation();
+        let check = pairing_sum([(&g1s[0], &g2s[0]), (&g1s[1], &g2s[1])]);
 
         assert_eq!(check, mml);
     }
+
+    #[test]
+    fn prepared() {
+        let mut rng = rand::thread_rng();
+        let g2 = G2Projective::random(&mut rng);
+        let prepared = [
+            G2Prepared::from(g2),
+            G2Prepared::from(G2Projective::identity()),
+            G2Prepared::from(G2Projective::generator()),
+        ];
+        let g1s: Vec<_> = (0..4)
+            .map(|_| G1Affine::from(G1Projective::random(&mut rng)))
+            .chain([G1Affine::identity()])
+            .collect();
+
+        for g1 in &g1s {
+            let ml = RelicEngine::multi_miller_loop(&[(g1, &prepared[0])]);
+            assert_eq!(ml.final_exponentiation(), pair(g1, g2));
+            let ml = RelicEngine::multi_miller_loop(&[(g1, &prepared[1])]);
+            assert_eq!(ml.final_exponentiation(), Gt::identity());
+        }
+
+        let terms: Vec<_> = g1s
+            .iter()
+            .enumerate()
+            .map(|(i, g1)| (g1, &prepared[i % prepared.len()]))
+            .collect();
+        let check = g1s
+            .iter()
+            .enumerate()
+            .map(|(i, g1)| match i % prepared.len() {
+                0 => pair(g1, g2),
+                1 => Gt::identity(),
+                _ => pair(g1, G2Projective::generator()),
+            })
+            .sum::<Gt>();
+        assert_eq!(
+            RelicEngine::multi_miller_loop(&terms).final_exponentiation(),
+            check
+        );
+        assert_eq!(
+            RelicEngine::multi_miller_loop(&[]).final_exponentiation(),
+            Gt::identity()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn prepared_chunks() {
+        let mut rng = rand::thread_rng();
+        let g2 = G2Projective::random(&mut rng);
+        let prepared = G2Prepared::from(g2);
+        // more pairs than processed by the wrapper in one chunk
+        let g1s: Vec<_> = (0..130)
+            .map(|_| G1Affine::from(G1Projective::random(&mut rng)))
+            .collect();
+        let terms: Vec<_> = g1s.iter().map(|g1| (g1, &prepared)).collect();
+
+        let sum = g1s.iter().map(G1Projective::from).sum::<G1Projective>();
+        assert_eq!(
+            RelicEngine::multi_miller_loop(&terms).final_exponentiation(),
+            pair(sum, g2)
+        );
+    }
+
+    #[test]
+    fn accumulate_miller_loops() {
+        let mut rng = rand::thread_rng();
+        let g1s = [
+            G1Affine::from(G1Projective::random(&mut rng)),
+            G1Affine::from(G1Projective::random(&mut rng)),
+            G1Affine::identity(),
+        ];
+        let g2s = [
+            G2Affine::from(G2Projective::random(&mut rng)),
+            G2Affine::from(G2Projective::random(&mut rng)),
+            G2Affine::from(G2Projective::random(&mut rng)),
+        ];
+
+        let prepared = g2s.map(G2Prepared::from);
+
+        let acc = RelicEngine::multi_miller_loop(&[(&g1s[0], &prepared[0])])
+            + RelicEngine::multi_miller_loop(&[(&g1s[1], &prepared[1])])
+            + RelicEngine::multi_miller_loop(&[(&g1s[2], &prepared[2])]);
+        let check = pair(g1s[0], g2s[0]) + pair(g1s[1], g2s[1]) + pair(g1s[2], g2s[2]);
+        assert_eq!(acc.final_exponentiation(), check);
+
+        let mut acc = MillerLoopOutput::default();
+        acc += RelicEngine::multi_miller_loop(&[(&g1s[0], &prepared[0]), (&g1s[1], &prepared[1])]);
+        acc += &RelicEngine::multi_miller_loop(&[(&g1s[2], &prepared[2])]);
+        assert_eq!(acc.final_exponentiation(), check);
+
+        assert_eq!(
+            MillerLoopOutput::default().final_exponentiation(),
+            Gt::identity()
+        );
+    }
+
+    #[test]
+    fn projective_miller_loop() {
+        let mut rng = rand::thread_rng();
+        let g1s = [
+            G1Projective::random(&mut rng),
+            G1Projective::random(&mut rng),
+            G1Projective::identity(),
+        ];
+        let g2s = [
+            G2Projective::random(&mut rng),
+            G2Projective::random(&mut rng),
+            G2Projective::random(&mut rng),
+        ];
+
+        for (g1, g2) in g1s.iter().zip(g2s.iter()) {
+            assert!(RelicEngine::projective_miller_loop(g1, g2)
+                .eq_after_final_exp(&RelicEngine::projective_pairing(g1, g2)));
+        }
+
+        let mls: [MillerLoopOutput; 3] =
+            core::array::from_fn(|i| RelicEngine::projective_miller_loop(&g1s[i], &g2s[i]));
+        let check = pair(g1s[0], g2s[0]) + pair(g1s[1], g2s[1]) + pair(g1s[2], g2s[2]);
+        assert_eq!(
+            mls.iter().sum::<MillerLoopOutput>().final_exponentiation(),
+            check
+        );
+        assert_eq!(
+            mls.into_iter()
+                .sum::<MillerLoopOutput>()
+                .final_exponentiation(),
+            check
+        );
+        assert_eq!(
+            core::iter::empty::<MillerLoopOutput>()
+                .sum::<MillerLoopOutput>()
+                .final_exponentiation(),
+            Gt::identity()
+        );
+    }
+
+    #[test]
+    fn multi_miller_loop_slices() {
+        let mut rng = rand::thread_rng();
+        let g1s = [
+            G1Projective::random(&mut rng),
+            G1Projective::random(&mut rng),
+            G1Projective::identity(),
+        ];
+        let g2s = [
+            G2Projective::random(&mut rng),
+            G2Projective::random(&mut rng),
+            G2Projective::random(&mut rng),
+        ];
+
+        let check = pair(g1s[0], g2s[0]) + pair(g1s[1], g2s[1]) + pair(g1s[2], g2s[2]);
+        assert_eq!(
+            RelicEngine::multi_miller_loop_slices(&g1s, &g2s).final_exponentiation(),
+            check
+        );
+        assert_eq!(
+            RelicEngine::multi_miller_loop_slices(&[], &[]).final_exponentiation(),
+            Gt::identity()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn multi_miller_loop_slices_different_lengths() {
+        RelicEngine::multi_miller_loop_slices(&[G1Projective::generator()], &[]);
+    }
+
+    #[test]
+    fn pairing_from_bytes() {
+        let mut rng = rand::thread_rng();
+        let g1 = G1Projective::random(&mut rng);
+        let g2 = G2Projective::random(&mut rng);
+        let g1_bytes: [u8; g1::COMPRESSED_BYTES_SIZE] = g1.into();
+        let g2_bytes: [u8; g2::COMPRESSED_BYTES_SIZE] = g2.into();
+
+        assert_eq!(
+            RelicEngine::pairing_from_bytes(&g1_bytes, &g2_bytes).unwrap(),
+            RelicEngine::projective_pairing(
+                &G1Projective::try_from(&g1_bytes).unwrap(),
+                &G2Projective::try_from(&g2_bytes).unwrap()
+            )
+        );
+
+        assert!(
+            RelicEngine::pairing_from_bytes(&[0xff; g1::COMPRESSED_BYTES_SIZE], &g2_bytes).is_err()
+        );
+        assert!(
+            RelicEngine::pairing_from_bytes(&g1_bytes, &[0xff; g2::COMPRESSED_BYTES_SIZE]).is_err()
+        );
+    }
+
+    #[test]
+    fn pairing_products_equal() {
+        let mut rng = rand::thread_rng();
+        let g2 = G2Projective::generator();
+        let sks: [Scalar; 2] = core::array::from_fn(|_| Scalar::random(&mut rng));
+        let pks = sks.map(|sk| g2 * sk);
+        let msgs: [G1Projective; 2] = core::array::from_fn(|_| G1Projective::random(&mut rng));
+
+        // e(Z, g2) == e(M_1, X_1) * e(M_2, X_2) with Z = x_1 * M_1 + x_2 * M_2
+        let z = msgs[0] * sks[0] + msgs[1] * sks[1];
+        let rhs = [(&msgs[0], &pks[0]), (&msgs[1], &pks[1])];
+        assert!(RelicEngine::pairing_products_equal(&[(&z, &g2)], &rhs));
+        assert!(RelicEngine::pairing_products_equal(&rhs, &[(&z, &g2)]));
+
+        let z = msgs[0] * sks[0] + msgs[1] * sks[0];
+        assert!(!RelicEngine::pairing_products_equal(&[(&z, &g2)], &rhs));
+    }
+
+    #[test]
+    fn eq_after_final_exp() {
+        let mut rng = rand::thread_rng();
+        let g1 = G1Affine::from(G1Projective::random(&mut rng));
+        let g2 = G2Affine::from(G2Projective::random(&mut rng));
+
+        let ml = RelicEngine::multi_miller_loop(&[(&g1, &g2.into())]);
+        assert!(ml.eq_after_final_exp(&pair(g1, g2)));
+        assert!(!ml.eq_after_final_exp(&Gt::identity()));
+        assert!(MillerLoopOutput::default().eq_after_final_exp(&Gt::identity()));
+    }
 }
@@ -1,43 +1,57 @@
 //! Implementation of the first source group `G1`
 
 use core::{
+    hash::{Hash, Hasher},
     iter::Sum,
     mem::MaybeUninit,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
 };
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 
 use generic_array::{
     typenum::{Unsigned, U49, U97},
     GenericArray,
 };
-#[cfg(feature = "alloc")]
-use librelic_sys::wrapper_g1_simmul;
+#[cfg(feature = "xmd")]
+use librelic_sys::wrapper_g1_map_from_field;
 use librelic_sys::{
-    wrapper_g1_add, wrapper_g1_add_assign, wrapper_g1_double, wrapper_g1_generator,
-    wrapper_g1_hash_to_curve, wrapper_g1_init, wrapper_g1_is_equal, wrapper_g1_is_neutral,
-    wrapper_g1_is_valid, wrapper_g1_mul, wrapper_g1_mul_assign, wrapper_g1_neg, wrapper_g1_neutral,
-    wrapper_g1_norm, wrapper_g1_read_bin, wrapper_g1_sub, wrapper_g1_sub_assign, wrapper_g1_t,
-    wrapper_g1_write_bin, RLC_OK,
+    wrapper_bn_t, wrapper_g1_add, wrapper_g1_add_assign, wrapper_g1_conditional_select,
+    wrapper_g1_double, wrapper_g1_double_n, wrapper_g1_generator, wrapper_g1_hash_to_curve,
+    wrapper_g1_init, wrapper_g1_is_equal, wrapper_g1_is_neutral, wrapper_g1_is_valid,
+    wrapper_g1_mul, wrapper_g1_mul_assign, wrapper_g1_mul_cof, wrapper_g1_neg, wrapper_g1_neutral,
+    wrapper_g1_norm, wrapper_g1_on_curve, wrapper_g1_read_bin, wrapper_g1_simmul, wrapper_g1_sub,
+    wrapper_g1_sub_assign, wrapper_g1_t, wrapper_g1_write_bin, RLC_OK,
 };
+#[cfg(feature = "alloc")]
+use librelic_sys::{wrapper_g1_mul_fix, wrapper_g1_mul_pre, wrapper_g1_table_t};
 use pairing::group::{
+    ff::Field,
     prime::{PrimeCurve, PrimeGroup},
     Curve, Group, GroupEncoding, UncompressedEncoding,
 };
 use rand_core::RngCore;
-use subtle::{Choice, CtOption};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-use crate::{affine, Affine, Error, Scalar, RANDOM_DOMAIN_SEPERATOR};
+#[cfg(feature = "alloc")]
+use crate::pippenger;
+#[cfg(feature = "xmd")]
+use crate::xmd::{expand_message_xmd, FIELD_ELEMENT_BYTES};
+use crate::{
+    affine, parse_hex, zcash, zeroize_wrappers, Affine, Error, ParseElementError, Scalar,
+    RANDOM_DOMAIN_SEPERATOR,
+};
 
 type CompressedSize = U49;
 type UncompressedSize = U97;
 
-const COMPRESSED_BYTES_SIZE: usize = CompressedSize::USIZE;
+pub(crate) const COMPRESSED_BYTES_SIZE: usize = CompressedSize::USIZE;
 const UNCOMPRESSED_BYTES_SIZE: usize = UncompressedSize::USIZE;
+const COORDINATE_BYTES_SIZE: usize = (UNCOMPRESSED_BYTES_SIZE - 1) / 2;
 
 #[inline]
 fn new_wrapper() -> wrapper_g1_t {
@@ -48,8 +62,17 @@ fn new_wrapper() -> wrapper_g1_t {
     }
 }
 
+/// Obtain the generator from relic
+fn new_generator() -> G1Projective {
+    let mut value = new_wrapper();
+    unsafe {
+        wrapper_g1_generator(&mut value);
+    }
+    G1Projective(value)
+}
+
 /// Representation of a G1 element
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct G1Projective(pub(crate) wrapper_g1_t);
 
@@ -64,6 +87,474 @@ impl G1Projective {
         }
         g1.into()
     }
+
+    /// Hash a message given in multiple parts to a point on the curve
+    ///
+    /// This function computes the same result as [Self::hash_to_curve] on the
+    /// concatenation of all parts, but does not need to concatenate them.
+    ///
+    /// ```
+    /// use bls12_381_relic::G1Projective;
+    ///
+    /// assert_eq!(
+    ///     G1Projective::hash_to_curve_chunks(&[b"my ", b"message"], b"dst"),
+    ///     G1Projective::hash_to_curve(b"my message", b"dst")
+    /// );
+    /// ```
+    #[cfg(feature = "xmd")]
+    pub fn hash_to_curve_chunks(parts: &[&[u8]], dst: &[u8]) -> Self {
+        let mut uniform_bytes = [0u8; 2 * FIELD_ELEMENT_BYTES];
+        expand_message_xmd(parts, dst, &mut uniform_bytes);
+
+        let mut g1 = new_wrapper();
+        unsafe {
+            wrapper_g1_map_from_field(&mut g1, uniform_bytes.as_ptr(), uniform_bytes.len());
+        }
+        g1.into()
+    }
+
+    /// Sample a random point by multiplying the generator with a random scalar
+    ///
+    /// This is faster than [Group::random], which hashes random bytes to the
+    /// curve, and is intended for tests and benchmarks.
+    ///
+    /// **Warning**: The discrete logarithm of the point with respect to the
+    /// generator is known while it is computed. This function must not be used
+    /// where points with unknown discrete logarithm are required, e.g., for
+    /// the bases of commitments.
+    pub fn random_fast(rng: impl RngCore) -> Self {
+        Self::generator() * Scalar::random(rng)
+    }
+
+    /// Sample a random point with a custom domain separator
+    ///
+    /// This function behaves like [Group::random], but hashes the random bytes
+    /// to the curve with `dst` instead of the crate's domain separator. See
+    /// [Self::from_uniform_bytes] for the deterministic version.
+    pub fn random_with_dst(mut rng: impl RngCore, dst: &[u8]) -> Self {
+        let mut buf = [0u8; 64];
+        rng.fill_bytes(&mut buf);
+        Self::from_uniform_bytes(&buf, dst)
+    }
+
+    /// Derive a point from 64 uniform bytes with a custom domain separator
+    ///
+    /// The bytes are hashed to the curve with [Self::hash_to_curve]. Hence,
+    /// the discrete logarithm of the point is unknown, which makes this
+    /// function suitable to derive nothing-up-my-sleeve points from a public
+    /// seed.
+    ///
+    /// ```
+    /// use bls12_381_relic::G1Projective;
+    ///
+    /// let seed = [0x42; 64];
+    /// assert_eq!(
+    ///     G1Projective::from_uniform_bytes(&seed, b"my protocol parameters"),
+    ///     G1Projective::hash_to_curve(seed, b"my protocol parameters")
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_uniform_bytes(bytes: &[u8; 64], dst: &[u8]) -> Self {
+        Self::hash_to_curve(bytes, dst)
+    }
+
+    /// Double the point `k` times, i.e., compute `2^k * self`
+    ///
+    /// This computes the same result as calling [Group::double] `k` times,
+    /// but performs all doublings in place in a single call into relic.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Projective, Scalar};
+    /// use bls12_381_relic::group::Group;
+    ///
+    /// let g = G1Projective::generator();
+    /// assert_eq!(g.double_n(10), g * Scalar::from(1024));
+    /// ```
+    pub fn double_n(&self, k: u32) -> Self {
+        let mut ret = new_wrapper();
+        unsafe {
+            wrapper_g1_double_n(&mut ret, &self.0, k as usize);
+        }
+        Self(ret)
+    }
+
+    /// Multiply the point by a small scalar
+    ///
+    /// The multiplication is performed by double-and-add over the bits of
+    /// `scalar`, which is faster than multiplying by a [Scalar] for small
+    /// values. The running time depends on `scalar`, hence this function is
+    /// only intended for public scalars.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Projective, Scalar};
+    /// use bls12_381_relic::group::Group;
+    ///
+    /// let g = G1Projective::generator();
+    /// assert_eq!(g.mul_u64(3), g * Scalar::from(3));
+    /// ```
+    pub fn mul_u64(&self, scalar: u64) -> Self {
+        let mut ret = Self::identity();
+        for bit in (0..u64::BITS - scalar.leading_zeros()).rev() {
+            ret = ret.double();
+            if (scalar >> bit) & 1 == 1 {
+                ret += self;
+            }
+        }
+        ret
+    }
+
+    /// Compute `self * scalar + addend`
+    ///
+    /// The product is computed into the wrapper of the result and the addend
+    /// is added in place, which saves the intermediate point of the expression
+    /// `self * scalar + addend`. This is useful when updating commitments or
+    /// accumulating homomorphically.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Projective, Scalar};
+    /// use bls12_381_relic::{group::Group, ff::Field};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let g = G1Projective::random(&mut rng);
+    /// let h = G1Projective::random(&mut rng);
+    /// let s = Scalar::random(&mut rng);
+    /// assert_eq!(g.mul_add(&s, &h), g * s + h);
+    /// ```
+    pub fn mul_add(&self, scalar: &Scalar, addend: &Self) -> Self {
+        let mut ret = new_wrapper();
+        self.debug_check_valid();
+        addend.debug_check_valid();
+        unsafe {
+            wrapper_g1_mul(&mut ret, &self.0, &scalar.0);
+            wrapper_g1_add_assign(&mut ret, &addend.0);
+        }
+        Self(ret)
+    }
+
+    /// Multiply the generator by a scalar
+    ///
+    /// This computes the same result as `G1Projective::generator() * scalar`,
+    /// e.g., to derive public keys from secret keys. With the `std` feature,
+    /// the multiplication uses a [G1FixedBase] table for the generator which
+    /// is precomputed on the first call and cached afterwards. Otherwise, the
+    /// generic scalar multiplication is used.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Projective, Scalar};
+    /// use bls12_381_relic::{group::Group, ff::Field};
+    ///
+    /// let sk = Scalar::random(rand::thread_rng());
+    /// assert_eq!(G1Projective::mul_generator(&sk), G1Projective::generator() * sk);
+    /// ```
+    pub fn mul_generator(scalar: &Scalar) -> Self {
+        #[cfg(feature = "std")]
+        {
+            static TABLE: std::sync::OnceLock<G1FixedBase> = std::sync::OnceLock::new();
+            TABLE.get_or_init(G1FixedBase::new).mul(scalar)
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            Self::generator() * scalar
+        }
+    }
+
+    /// Compute the sum of products of a fixed number of points and scalars
+    ///
+    /// This function computes the same result as the [Sum] implementation for
+    /// pairs of points and scalars, but does not require the `alloc` feature
+    /// to benefit from the faster simultaneous multiplication.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Projective, Scalar};
+    /// use bls12_381_relic::{group::Group, ff::Field};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let points = [G1Projective::random(&mut rng), G1Projective::random(&mut rng)];
+    /// let scalars = [Scalar::random(&mut rng), Scalar::random(&mut rng)];
+    /// assert_eq!(
+    ///     G1Projective::multiexp_array(&points, &scalars),
+    ///     points[0] * scalars[0] + points[1] * scalars[1]
+    /// );
+    /// ```
+    pub fn multiexp_array<const N: usize>(points: &[Self; N], scalars: &[Scalar; N]) -> Self {
+        let mut g1s: [wrapper_g1_t; N] = core::array::from_fn(|i| points[i].0);
+        let mut bns: [wrapper_bn_t; N] = core::array::from_fn(|i| scalars[i].0);
+
+        let mut g1 = new_wrapper();
+        unsafe {
+            wrapper_g1_simmul(&mut g1, g1s.as_ptr(), bns.as_ptr(), N);
+        }
+        zeroize_wrappers(&mut g1s);
+        zeroize_wrappers(&mut bns);
+        g1.into()
+    }
+
+    /// Compute the sum of products of points and scalars
+    ///
+    /// This is the recommended interface for multi-scalar multiplications. It
+    /// computes the same result as the [Sum] implementation for pairs of
+    /// points and scalars, but does not copy the inputs and hence does not
+    /// require the `alloc` feature. If both slices are empty, the result is
+    /// the identity.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Affine, G1Projective, Scalar};
+    /// use bls12_381_relic::{group::{Curve, Group}, ff::Field};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let points = [G1Projective::random(&mut rng), G1Projective::random(&mut rng)];
+    /// let scalars = [Scalar::random(&mut rng), Scalar::random(&mut rng)];
+    /// let affine_points = [points[0].to_affine(), points[1].to_affine()];
+    /// assert_eq!(
+    ///     G1Projective::msm(&affine_points, &scalars),
+    ///     points[0] * scalars[0] + points[1] * scalars[1]
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `scalars` have different lengths.
+    pub fn msm(points: &[G1Affine], scalars: &[Scalar]) -> Self {
+        assert_eq!(points.len(), scalars.len());
+
+        let mut g1 = new_wrapper();
+        // G1Affine and Scalar are transparent wrappers of the relic types
+        unsafe {
+            wrapper_g1_simmul(
+                &mut g1,
+                points.as_ptr().cast(),
+                scalars.as_ptr().cast(),
+                points.len(),
+            );
+        }
+        g1.into()
+    }
+
+    /// Compute the sum of products of points and scalars with Pippenger's
+    /// bucket method
+    ///
+    /// This function computes the same result as [Self::msm], but is
+    /// implemented on top of relic's point additions and is intended for large
+    /// inputs. Whether it is faster than relic's simultaneous multiplication
+    /// for a given number of terms depends on the platform and on relic's
+    /// configuration, so no crossover point is given here. It can be measured
+    /// with the `msm` benchmarks of `cargo bench --bench engine`, which compare
+    /// both in `G1` for up to 2^16 terms. If `window_bits` is `None`, the
+    /// window size is chosen based on the number of terms.
+    ///
+    /// **Warning**: The running time depends on the scalars. This function
+    /// must not be used with secret scalars.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Projective, Scalar};
+    /// use bls12_381_relic::{group::{Curve, Group}, ff::Field};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let points: Vec<_> = (0..64).map(|_| G1Projective::random(&mut rng).to_affine()).collect();
+    /// let scalars: Vec<_> = (0..64).map(|_| Scalar::random(&mut rng)).collect();
+    /// assert_eq!(
+    ///     G1Projective::msm_pippenger(&points, &scalars, None),
+    ///     G1Projective::msm(&points, &scalars)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `scalars` have different lengths or if
+    /// `window_bits` is not between 1 and 16.
+    #[cfg(feature = "alloc")]
+    pub fn msm_pippenger(
+        points: &[G1Affine],
+        scalars: &[Scalar],
+        window_bits: Option<usize>,
+    ) -> Self {
+        assert_eq!(points.len(), scalars.len());
+        let window_bits =
+            window_bits.unwrap_or_else(|| pippenger::default_window_bits(points.len()));
+        assert!((1..=pippenger::MAX_WINDOW_BITS).contains(&window_bits));
+
+        pippenger::msm(points, scalars, window_bits)
+    }
+
+    /// Compute the sum of products of points and scalars in parallel
+    ///
+    /// The inputs are split into one chunk per thread of the [rayon] thread
+    /// pool. Each chunk is handled by a simultaneous multiplication and the
+    /// partial results are summed up.
+    ///
+    /// Relic does not need to be locked for this: each thread operates on its
+    /// own relic context, which is set up on the first use of relic in that
+    /// thread. This requires relic to be built with multithreading support
+    /// (`MULTI=PTHREAD` or `MULTI=OPENMP`), which is the case for the vendored
+    /// build. If relic was built without it, all threads would share a single
+    /// context. This is checked at runtime and the multiplication is then
+    /// performed on the calling thread only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `scalars` have different lengths.
+    #[cfg(feature = "rayon")]
+    pub fn multi_mul_parallel(points: &[Self], scalars: &[Scalar]) -> Self {
+        use rayon::prelude::*;
+
+        assert_eq!(points.len(), scalars.len());
+        let simmul = |points: &[Self], scalars: &[Scalar]| {
+            let mut g1 = new_wrapper();
+            // G1Projective and Scalar are transparent wrappers of the relic types
+            unsafe {
+                wrapper_g1_simmul(
+                    &mut g1,
+                    points.as_ptr().cast(),
+                    scalars.as_ptr().cast(),
+                    points.len(),
+                );
+            }
+            Self(g1)
+        };
+        // without thread-local contexts, relic must only be used from one thread
+        if !unsafe { librelic_sys::wrapper_is_multithreaded() } {
+            return simmul(points, scalars);
+        }
+
+        let threads = rayon::current_num_threads().max(1);
+        let chunk_size = ((points.len() + threads - 1) / threads).max(1);
+        points
+            .par_chunks(chunk_size)
+            .zip(scalars.par_chunks(chunk_size))
+            .map(|(points, scalars)| simmul(points, scalars))
+            .reduce(Self::identity, |a, b| a + b)
+    }
+
+    /// Multiply the point by the cofactor
+    ///
+    /// This uses relic's cofactor multiplication, which maps any point on the
+    /// curve to the prime-order subgroup.
+    pub fn mul_by_cofactor(&self) -> Self {
+        let mut g1 = new_wrapper();
+        unsafe {
+            wrapper_g1_mul_cof(&mut g1, &self.0);
+        }
+        g1.into()
+    }
+
+    /// Check if the point is on the curve and in the prime-order subgroup
+    ///
+    /// All points obtained from the checked decoding functions and from
+    /// arithmetic on valid points are valid. Points created with
+    /// [Self::from_affine_coordinates_unchecked] may not be.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        unsafe { wrapper_g1_is_valid(&self.0) }
+    }
+
+    /// Check that the point is valid before it is used in arithmetic (internal)
+    ///
+    /// With the `checked-arithmetic` feature, this panics in debug builds if
+    /// the point is neither valid nor the identity. Otherwise, it does nothing.
+    #[inline(always)]
+    fn debug_check_valid(&self) {
+        #[cfg(feature = "checked-arithmetic")]
+        debug_assert!(
+            bool::from(self.is_identity()) || self.is_valid(),
+            "arithmetic on invalid G1 point"
+        );
+    }
+
+    /// Create a point from its affine coordinates without a subgroup check
+    ///
+    /// The coordinates are encoded as in the uncompressed encoding of points.
+    ///
+    /// Coordinates of points that are not on the curve are rejected with
+    /// [Error::InvalidBytesRepresentation].
+    ///
+    /// **Warning**: The point is only checked to be on the curve, but not to
+    /// be a member of the prime-order subgroup. Operations with such points
+    /// can produce invalid results. This function must only be used in tests
+    /// or with coordinates from trusted sources.
+    pub fn from_affine_coordinates_unchecked(
+        x: &[u8; COORDINATE_BYTES_SIZE],
+        y: &[u8; COORDINATE_BYTES_SIZE],
+    ) -> Result<Self, Error> {
+        let mut bytes = [0u8; UNCOMPRESSED_BYTES_SIZE];
+        bytes[0] = 0x04;
+        bytes[1..=COORDINATE_BYTES_SIZE].copy_from_slice(x);
+        bytes[COORDINATE_BYTES_SIZE + 1..].copy_from_slice(y);
+
+        let mut g1 = new_wrapper();
+        let ret = unsafe { wrapper_g1_read_bin(&mut g1, bytes.as_ptr(), bytes.len()) };
+        if ret != RLC_OK {
+            Err(Error::RelicError(ret.into()))
+        } else if !unsafe { wrapper_g1_on_curve(&g1) } {
+            // relic only checks the point if it is built with CHECK
+            Err(Error::InvalidBytesRepresentation)
+        } else {
+            Ok(Self(g1))
+        }
+    }
+
+    /// Encode the point in the compressed Zcash format
+    ///
+    /// This format consists of 48 bytes storing the x-coordinate with the
+    /// compression, infinity and sort flags in its three most significant
+    /// bits. It is used by the `bls12_381` crate and most other implementations
+    /// of BLS12-381, and is thus required to exchange points with them.
+    pub fn to_bytes_zcash(&self) -> [u8; COORDINATE_BYTES_SIZE] {
+        let mut ret = [0u8; COORDINATE_BYTES_SIZE];
+        if bool::from(self.is_identity()) {
+            ret[0] = zcash::FLAG_COMPRESSED | zcash::FLAG_INFINITY;
+            return ret;
+        }
+
+        let bytes: [u8; UNCOMPRESSED_BYTES_SIZE] = self.into();
+        ret.copy_from_slice(&bytes[1..=COORDINATE_BYTES_SIZE]);
+        ret[0] |= zcash::FLAG_COMPRESSED;
+        if zcash::fp_is_lexicographically_largest(&bytes[COORDINATE_BYTES_SIZE + 1..]) {
+            ret[0] |= zcash::FLAG_SORT;
+        }
+        ret
+    }
+
+    /// Decode a point from the compressed Zcash format
+    ///
+    /// The point is checked to be in the prime-order subgroup. See
+    /// [Self::to_bytes_zcash] for details on the format.
+    pub fn from_bytes_zcash(bytes: &[u8; COORDINATE_BYTES_SIZE]) -> CtOption<Self> {
+        let flags = bytes[0] & zcash::FLAGS_MASK;
+        if flags & zcash::FLAG_COMPRESSED == 0 {
+            return CtOption::new(Self::identity(), Choice::from(0));
+        }
+        if flags & zcash::FLAG_INFINITY != 0 {
+            return CtOption::new(
+                Self::identity(),
+                Choice::from(zcash::is_valid_infinity(bytes) as u8),
+            );
+        }
+
+        // decode with relic and fix the sign of y afterwards
+        let mut relic = [0u8; COMPRESSED_BYTES_SIZE];
+        relic[0] = 0x02;
+        relic[1..].copy_from_slice(bytes);
+        relic[1] &= !zcash::FLAGS_MASK;
+        match Self::try_from(&relic) {
+            Ok(point) => {
+                let is_largest = point.to_bytes_zcash()[0] & zcash::FLAG_SORT != 0;
+                let point = if is_largest == (flags & zcash::FLAG_SORT != 0) {
+                    point
+                } else {
+                    -point
+                };
+                CtOption::new(point, Choice::from(1))
+            }
+            Err(_) => CtOption::new(Self::identity(), Choice::from(0)),
+        }
+    }
+
+    /// Feed the compressed encoding ([GroupEncoding::to_bytes]) into a hasher
+    #[cfg(feature = "digest")]
+    pub fn digest_update(&self, hasher: &mut impl digest::Update) {
+        hasher.update(&<[u8; COMPRESSED_BYTES_SIZE]>::from(self));
+    }
 }
 
 impl Default for G1Projective {
@@ -115,10 +606,10 @@ impl TryFrom<&[u8; UNCOMPRESSED_BYTES_SIZE]> for G1Projective {
             if unsafe { wrapper_g1_is_valid(&g1) } {
                 Ok(Self(g1))
             } else {
-                Err(Error::InvalidBytesRepresentation)
+                Err(Error::NotInSubgroup)
             }
         } else {
-            Err(Error::RelicError(ret))
+            Err(Error::RelicError(ret.into()))
         }
     }
 }
@@ -142,10 +633,10 @@ impl TryFrom<&[u8; COMPRESSED_BYTES_SIZE]> for G1Projective {
             if unsafe { wrapper_g1_is_valid(&g1) } {
                 Ok(Self(g1))
             } else {
-                Err(Error::InvalidBytesRepresentation)
+                Err(Error::NotInSubgroup)
             }
         } else {
-            Err(Error::RelicError(ret))
+            Err(Error::RelicError(ret.into()))
         }
     }
 }
@@ -199,21 +690,41 @@ impl From<&G1Projective> for [u8; COMPRESSED_BYTES_SIZE] {
 impl TryFrom<&[u8]> for G1Projective {
     type Error = Error;
 
+    /// Decode from either the compressed or the uncompressed encoding
+    ///
+    /// Slices of any other length are rejected with
+    /// [Error::InvalidBytesRepresentation].
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        let mut g1 = new_wrapper();
-        let ret = unsafe { wrapper_g1_read_bin(&mut g1, value.as_ptr(), value.len()) };
-        if ret == RLC_OK {
-            if unsafe { wrapper_g1_is_valid(&g1) } {
-                Ok(Self(g1))
-            } else {
-                Err(Error::InvalidBytesRepresentation)
-            }
+        if let Ok(bytes) = <&[u8; COMPRESSED_BYTES_SIZE]>::try_from(value) {
+            Self::try_from(bytes)
+        } else if let Ok(bytes) = <&[u8; UNCOMPRESSED_BYTES_SIZE]>::try_from(value) {
+            Self::try_from(bytes)
         } else {
-            Err(Error::RelicError(ret))
+            Err(Error::InvalidBytesRepresentation)
         }
     }
 }
 
+#[cfg(feature = "alloc")]
+impl TryFrom<Vec<u8>> for G1Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&Vec<u8>> for G1Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl<G> Add<G> for G1Projective
 where
     G: AsRef<Self>,
@@ -223,6 +734,8 @@ where
     #[inline]
     fn add(mut self, rhs: G) -> Self::Output {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g1_add_assign(&mut self.0, &rhs.0);
         }
@@ -240,6 +753,8 @@ where
     fn add(self, rhs: G) -> Self::Output {
         let mut ret = new_wrapper();
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g1_add(&mut ret, &self.0, &rhs.0);
         }
@@ -254,6 +769,8 @@ where
     #[inline]
     fn add_assign(&mut self, rhs: G) {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g1_add_assign(&mut self.0, &rhs.0);
         }
@@ -294,6 +811,8 @@ where
     #[inline]
     fn sub(mut self, rhs: G) -> Self::Output {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g1_sub_assign(&mut self.0, &rhs.into());
         }
@@ -311,6 +830,8 @@ where
     fn sub(self, rhs: G) -> Self::Output {
         let mut ret = new_wrapper();
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g1_sub(&mut ret, &self.0, &rhs.0);
         }
@@ -325,6 +846,8 @@ where
     #[inline]
     fn sub_assign(&mut self, rhs: G) {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g1_sub_assign(&mut self.0, &rhs.0);
         }
@@ -350,6 +873,13 @@ where
     }
 }
 
+/// Scalar multiplication
+///
+/// relic multiplies with its configured method. With relic's default
+/// configuration, which is also used with the `vendored` feature, this is the
+/// w-NAF method combined with the GLV decomposition of the scalar based on the
+/// efficiently computable endomorphism of BLS12-381. Hence, there is no
+/// separate GLV-based multiplication.
 impl<S> Mul<S> for G1Projective
 where
     S: AsRef<Scalar>,
@@ -359,6 +889,7 @@ where
     #[inline]
     fn mul(mut self, rhs: S) -> Self::Output {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
         unsafe {
             wrapper_g1_mul_assign(&mut self.0, &rhs.0);
         }
@@ -375,6 +906,7 @@ where
     fn mul(self, rhs: S) -> Self::Output {
         let mut g1 = new_wrapper();
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
         unsafe {
             wrapper_g1_mul(&mut g1, &self.0, &rhs.0);
         }
@@ -418,12 +950,49 @@ impl Mul<&G1Projective> for &Scalar {
     }
 }
 
+impl Mul<G1Affine> for Scalar {
+    type Output = G1Projective;
+
+    #[inline]
+    fn mul(self, rhs: G1Affine) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<&G1Affine> for Scalar {
+    type Output = G1Projective;
+
+    #[inline]
+    fn mul(self, rhs: &G1Affine) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<G1Affine> for &Scalar {
+    type Output = G1Projective;
+
+    #[inline]
+    fn mul(self, rhs: G1Affine) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<&G1Affine> for &Scalar {
+    type Output = G1Projective;
+
+    #[inline]
+    fn mul(self, rhs: &G1Affine) -> Self::Output {
+        rhs * self
+    }
+}
+
 impl<S> MulAssign<S> for G1Projective
 where
     S: AsRef<Scalar>,
 {
     fn mul_assign(&mut self, rhs: S) {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
         unsafe {
             wrapper_g1_mul_assign(&mut self.0, &rhs.0);
         }
@@ -439,6 +1008,87 @@ impl PartialEq for G1Projective {
 
 impl Eq for G1Projective {}
 
+impl_fmt!(
+    G1Projective,
+    "Formatting as hex of the compressed encoding",
+    |g: &G1Projective| <[u8; COMPRESSED_BYTES_SIZE]>::from(g),
+    |g: &G1Projective| <[u8; COMPRESSED_BYTES_SIZE]>::from(g)
+);
+
+/// Parsing from hex of the compressed or uncompressed encoding
+///
+/// The digits may be prefixed with `0x` or `0X`. This is the inverse of the
+/// [core::fmt::Display] implementation.
+///
+/// ```
+/// use bls12_381_relic::{G1Affine, G1Projective};
+/// use bls12_381_relic::group::Group;
+///
+/// let g = G1Projective::generator();
+/// assert_eq!(format!("{g:#x}").parse::<G1Projective>().unwrap(), g);
+/// assert_eq!(G1Affine::try_from(g.to_string().as_str()).unwrap(), g.into());
+/// ```
+impl FromStr for G1Projective {
+    type Err = ParseElementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut buf = [0u8; UNCOMPRESSED_BYTES_SIZE];
+        let bytes = parse_hex(
+            s,
+            &mut buf,
+            &[COMPRESSED_BYTES_SIZE, UNCOMPRESSED_BYTES_SIZE],
+        )?;
+        Self::try_from(bytes).map_err(ParseElementError::InvalidElement)
+    }
+}
+
+impl TryFrom<&str> for G1Projective {
+    type Error = ParseElementError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Hashing via the canonical compressed encoding
+///
+/// Equal elements can have different projective representations. Hence, the
+/// encoding of the elements is hashed to be consistent with [PartialEq].
+impl Hash for G1Projective {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let bytes: [u8; COMPRESSED_BYTES_SIZE] = self.into();
+        bytes.hash(state);
+    }
+}
+
+/// Constant-time equality
+///
+/// The comparison of relic used by [PartialEq] is not constant time. Hence, the
+/// canonical encodings of the elements are compared in constant time instead.
+impl ConstantTimeEq for G1Projective {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let lhs: [u8; COMPRESSED_BYTES_SIZE] = self.into();
+        let rhs: [u8; COMPRESSED_BYTES_SIZE] = other.into();
+        lhs.ct_eq(&rhs)
+    }
+}
+
+/// Selection of the projective coordinates
+///
+/// The coordinates are selected in constant time together with relic's flag
+/// indicating whether they are normalized, hence points in different
+/// representations can be selected.
+impl ConditionallySelectable for G1Projective {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut ret = new_wrapper();
+        unsafe {
+            wrapper_g1_conditional_select(&mut ret, &a.0, &b.0, choice.into());
+        }
+        Self(ret)
+    }
+}
+
 impl GroupEncoding for G1Projective {
     type Repr = GenericArray<u8, CompressedSize>;
 
@@ -488,10 +1138,8 @@ impl UncompressedEncoding for G1Projective {
 impl Group for G1Projective {
     type Scalar = Scalar;
 
-    fn random(mut rng: impl RngCore) -> Self {
-        let mut buf = [0u8; 64];
-        rng.fill_bytes(&mut buf);
-        Self::hash_to_curve(buf, RANDOM_DOMAIN_SEPERATOR)
+    fn random(rng: impl RngCore) -> Self {
+        Self::random_with_dst(rng, RANDOM_DOMAIN_SEPERATOR)
     }
 
     #[inline]
@@ -499,13 +1147,20 @@ impl Group for G1Projective {
         Self::default()
     }
 
+    /// Obtain the generator
+    ///
+    /// With the `std` feature, the generator is obtained from relic on the
+    /// first call and cached afterwards.
     #[inline]
     fn generator() -> Self {
-        let mut value = new_wrapper();
-        unsafe {
-            wrapper_g1_generator(&mut value);
+        #[cfg(feature = "std")]
+        {
+            static GENERATOR: std::sync::OnceLock<G1Projective> = std::sync::OnceLock::new();
+            *GENERATOR.get_or_init(new_generator)
         }
-        Self(value)
+
+        #[cfg(not(feature = "std"))]
+        new_generator()
     }
 
     #[inline]
@@ -525,6 +1180,11 @@ impl Group for G1Projective {
 
 impl PrimeGroup for G1Projective {}
 
+/// Sum of products of points and scalars
+///
+/// The products are evaluated with a simultaneous multiplication if the `alloc`
+/// feature is enabled. [G1Projective::msm] computes the same sum for slices of
+/// points and scalars and is the recommended interface.
 impl<G, S> Sum<(G, S)> for G1Projective
 where
     G: AsRef<G1Projective>,
@@ -545,12 +1205,22 @@ where
         unsafe {
             wrapper_g1_simmul(&mut g1, g1s.as_ptr(), scalars.as_ptr(), g1s.len());
         }
+        zeroize_wrappers(&mut g1s);
+        zeroize_wrappers(&mut scalars);
         g1.into()
     }
 
     #[cfg(not(feature = "alloc"))]
     fn sum<I: Iterator<Item = (G, S)>>(iter: I) -> Self {
-        iter.fold(Self::identity(), |a, (g, s)| a + g.as_ref() * s.as_ref())
+        // accumulate in place and reuse a single temporary for the products
+        let mut sum = Self::identity();
+        let mut product = new_wrapper();
+        iter.for_each(|(g, s)| unsafe {
+            wrapper_g1_mul(&mut product, &g.as_ref().0, &s.as_ref().0);
+            wrapper_g1_add_assign(&mut sum.0, &product);
+        });
+        zeroize_wrappers(core::slice::from_mut(&mut product));
+        sum
     }
 }
 
@@ -574,12 +1244,98 @@ where
         unsafe {
             wrapper_g1_simmul(&mut g1, g1s.as_ptr(), scalars.as_ptr(), g1s.len());
         }
+        zeroize_wrappers(&mut g1s);
+        zeroize_wrappers(&mut scalars);
         g1.into()
     }
 
     #[cfg(not(feature = "alloc"))]
     fn sum<I: Iterator<Item = &'a (G, S)>>(iter: I) -> Self {
-        iter.fold(Self::identity(), |a, (g, s)| a + g.as_ref() * s.as_ref())
+        // accumulate in place and reuse a single temporary for the products
+        let mut sum = Self::identity();
+        let mut product = new_wrapper();
+        iter.for_each(|(g, s)| unsafe {
+            wrapper_g1_mul(&mut product, &g.as_ref().0, &s.as_ref().0);
+            wrapper_g1_add_assign(&mut sum.0, &product);
+        });
+        zeroize_wrappers(core::slice::from_mut(&mut product));
+        sum
+    }
+}
+
+/// Convert an uncompressed encoding of a point to the compressed one
+///
+/// The point is decoded and validated before it is re-encoded.
+pub fn recompress(
+    uncompressed: &[u8; UNCOMPRESSED_BYTES_SIZE],
+) -> Result<[u8; COMPRESSED_BYTES_SIZE], Error> {
+    G1Projective::try_from(uncompressed).map(|g1| g1.into())
+}
+
+/// Convert a compressed encoding of a point to the uncompressed one
+///
+/// The point is decoded and validated before it is re-encoded.
+pub fn decompress(
+    compressed: &[u8; COMPRESSED_BYTES_SIZE],
+) -> Result<[u8; UNCOMPRESSED_BYTES_SIZE], Error> {
+    G1Projective::try_from(compressed).map(|g1| g1.into())
+}
+
+/// Precomputed table for fixed-base scalar multiplications in G1
+///
+/// If many scalar multiplications with the same base are performed, e.g.,
+/// with the generator, precomputing a table of multiples of the base once
+/// speeds up each subsequent multiplication. The size of the table depends on
+/// relic's configuration of fixed-base multiplication. With relic's default
+/// configuration, the table stores 16 points which amounts to roughly 2.4 KiB.
+/// The table is thus allocated on the heap and this type is only available if
+/// the `alloc` feature is enabled.
+///
+/// ```
+/// use bls12_381_relic::{g1::G1FixedBase, G1Projective, Scalar};
+/// use bls12_381_relic::{group::Group, ff::Field};
+///
+/// let fixed_base = G1FixedBase::new();
+/// let s = Scalar::random(rand::thread_rng());
+/// assert_eq!(fixed_base.mul(&s), G1Projective::generator() * s);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct G1FixedBase(Box<wrapper_g1_table_t>);
+
+#[cfg(feature = "alloc")]
+impl G1FixedBase {
+    /// Precompute the table for the generator
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_base(&G1Projective::generator())
+    }
+
+    /// Precompute the table for the given base
+    pub fn with_base(base: &G1Projective) -> Self {
+        let mut table = MaybeUninit::uninit();
+        let table = unsafe {
+            wrapper_g1_mul_pre(table.as_mut_ptr(), &base.0);
+            table.assume_init()
+        };
+        Self(Box::new(table))
+    }
+
+    /// Multiply the base by a scalar using the precomputed table
+    pub fn mul(&self, rhs: &Scalar) -> G1Projective {
+        let mut g1 = new_wrapper();
+        unsafe {
+            wrapper_g1_mul_fix(&mut g1, self.0.as_ref(), &rhs.0);
+        }
+        g1.into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for G1FixedBase {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -651,6 +1407,118 @@ impl From<&Affine<G1Projective>> for wrapper_g1_t {
     }
 }
 
+impl Affine<G1Projective> {
+    /// Decode a point from its compressed encoding
+    ///
+    /// The encoding is the one of relic and consists of 49 bytes: a leading
+    /// byte storing the format and the sign of the y-coordinate followed by the
+    /// big-endian x-coordinate. Hence, it is one byte longer than the
+    /// encoding used by the `bls12_381` crate, which stores these flags in the
+    /// most significant bits of the x-coordinate.
+    ///
+    /// The point is checked to be in the prime-order subgroup.
+    #[inline]
+    pub fn from_compressed(bytes: &[u8; COMPRESSED_BYTES_SIZE]) -> CtOption<Self> {
+        <Self as GroupEncoding>::from_bytes(GenericArray::from_slice(bytes))
+    }
+
+    /// Decode a point from its uncompressed encoding
+    ///
+    /// The encoding consists of 97 bytes: a leading byte storing the format
+    /// followed by the big-endian coordinates. The point is checked to be in
+    /// the prime-order subgroup.
+    #[inline]
+    pub fn from_uncompressed(bytes: &[u8; UNCOMPRESSED_BYTES_SIZE]) -> CtOption<Self> {
+        <Self as UncompressedEncoding>::from_uncompressed(GenericArray::from_slice(bytes))
+    }
+
+    /// Encode the point in compressed form
+    ///
+    /// See [Self::from_compressed] for the format.
+    #[inline]
+    pub fn to_compressed(&self) -> [u8; COMPRESSED_BYTES_SIZE] {
+        (&self.0).into()
+    }
+
+    /// Encode the point in uncompressed form
+    ///
+    /// See [Self::from_uncompressed] for the format.
+    #[inline]
+    pub fn to_uncompressed(&self) -> [u8; UNCOMPRESSED_BYTES_SIZE] {
+        (&self.0).into()
+    }
+
+    /// Feed the compressed encoding ([GroupEncoding::to_bytes]) into a hasher
+    #[cfg(feature = "digest")]
+    #[inline]
+    pub fn digest_update(&self, hasher: &mut impl digest::Update) {
+        self.0.digest_update(hasher);
+    }
+
+    /// Read a point in compressed form from a reader
+    ///
+    /// Exactly [COMPRESSED_BYTES_SIZE] bytes are read and decoded as with
+    /// [Self::from_compressed]. If the reader ends early, an error of kind
+    /// [std::io::ErrorKind::UnexpectedEof] is returned. Invalid points are
+    /// reported as [std::io::ErrorKind::InvalidData].
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut impl std::io::Read) -> std::io::Result<Self> {
+        let mut bytes = [0u8; COMPRESSED_BYTES_SIZE];
+        reader.read_exact(&mut bytes)?;
+        Self::decode_read(&bytes)
+    }
+
+    /// Read points in compressed form from a reader until it ends
+    ///
+    /// The points are read one after another as with [Self::read_from]
+    /// without reading the whole input into memory first. The iterator ends
+    /// after the first error.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G1Affine, G1Projective};
+    /// use bls12_381_relic::group::{Curve, Group};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let points = [G1Projective::random(&mut rng).to_affine(), G1Projective::random(&mut rng).to_affine()];
+    /// let bytes: Vec<u8> = points.iter().flat_map(|p| p.to_compressed()).collect();
+    ///
+    /// let read: Vec<_> = G1Affine::read_many(bytes.as_slice())
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(read, points);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn read_many<R>(mut reader: R) -> impl Iterator<Item = std::io::Result<Self>>
+    where
+        R: std::io::Read,
+    {
+        let mut done = false;
+        core::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            let mut bytes = [0u8; COMPRESSED_BYTES_SIZE];
+            let point = match crate::read_exact_or_eof(&mut reader, &mut bytes) {
+                Ok(true) => Self::decode_read(&bytes),
+                Ok(false) => {
+                    done = true;
+                    return None;
+                }
+                Err(err) => Err(err),
+            };
+            done = point.is_err();
+            Some(point)
+        })
+    }
+
+    #[cfg(feature = "std")]
+    fn decode_read(bytes: &[u8; COMPRESSED_BYTES_SIZE]) -> std::io::Result<Self> {
+        Option::from(Self::from_compressed(bytes))
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid point"))
+    }
+}
+
 impl GroupEncoding for Affine<G1Projective> {
     type Repr = <G1Projective as GroupEncoding>::Repr;
 
@@ -698,46 +1566,310 @@ impl UncompressedEncoding for Affine<G1Projective> {
     }
 }
 
-#[cfg(feature = "zeroize")]
-impl zeroize::Zeroize for G1Projective {
-    fn zeroize(&mut self) {
-        unsafe {
-            wrapper_g1_neutral(&mut self.0);
-        }
+/// Compressed encoding of a point in G1
+///
+/// This type wraps the encoded bytes to keep track of their meaning. Decoding
+/// is deferred until the point is converted into [G1Affine] or [G1Projective].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct G1Compressed([u8; COMPRESSED_BYTES_SIZE]);
+
+impl G1Compressed {
+    /// Obtain the encoded bytes
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; COMPRESSED_BYTES_SIZE] {
+        &self.0
     }
 }
 
-#[cfg(feature = "serde")]
-impl serde::Serialize for G1Projective {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        crate::serde_helpers::serialize(self, serializer)
+impl AsRef<[u8]> for G1Compressed {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
     }
 }
 
-#[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for G1Projective {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        crate::serde_helpers::deserialize(deserializer)
+impl From<[u8; COMPRESSED_BYTES_SIZE]> for G1Compressed {
+    #[inline]
+    fn from(value: [u8; COMPRESSED_BYTES_SIZE]) -> Self {
+        Self(value)
     }
 }
 
-#[cfg(test)]
-mod test {
-    use pairing::group::ff::Field;
+impl From<G1Compressed> for [u8; COMPRESSED_BYTES_SIZE] {
+    #[inline]
+    fn from(value: G1Compressed) -> Self {
+        value.0
+    }
+}
 
-    use super::*;
+impl From<&G1Projective> for G1Compressed {
+    #[inline]
+    fn from(value: &G1Projective) -> Self {
+        Self(value.into())
+    }
+}
 
-    #[test]
+impl From<G1Projective> for G1Compressed {
+    #[inline]
+    fn from(value: G1Projective) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&G1Affine> for G1Compressed {
+    #[inline]
+    fn from(value: &G1Affine) -> Self {
+        Self::from(&value.0)
+    }
+}
+
+impl From<G1Affine> for G1Compressed {
+    #[inline]
+    fn from(value: G1Affine) -> Self {
+        Self::from(&value.0)
+    }
+}
+
+impl TryFrom<&G1Compressed> for G1Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G1Compressed) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<G1Compressed> for G1Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G1Compressed) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<&G1Compressed> for G1Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G1Compressed) -> Result<Self, Self::Error> {
+        G1Projective::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<G1Compressed> for G1Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G1Compressed) -> Result<Self, Self::Error> {
+        G1Projective::try_from(value).map(Self::from)
+    }
+}
+
+/// Uncompressed encoding of a point in G1
+///
+/// This type wraps the encoded bytes to keep track of their meaning. Decoding
+/// is deferred until the point is converted into [G1Affine] or [G1Projective].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct G1Uncompressed([u8; UNCOMPRESSED_BYTES_SIZE]);
+
+impl G1Uncompressed {
+    /// Obtain the encoded bytes
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; UNCOMPRESSED_BYTES_SIZE] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for G1Uncompressed {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; UNCOMPRESSED_BYTES_SIZE]> for G1Uncompressed {
+    #[inline]
+    fn from(value: [u8; UNCOMPRESSED_BYTES_SIZE]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<G1Uncompressed> for [u8; UNCOMPRESSED_BYTES_SIZE] {
+    #[inline]
+    fn from(value: G1Uncompressed) -> Self {
+        value.0
+    }
+}
+
+impl From<&G1Projective> for G1Uncompressed {
+    #[inline]
+    fn from(value: &G1Projective) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<G1Projective> for G1Uncompressed {
+    #[inline]
+    fn from(value: G1Projective) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&G1Affine> for G1Uncompressed {
+    #[inline]
+    fn from(value: &G1Affine) -> Self {
+        Self::from(&value.0)
+    }
+}
+
+impl From<G1Affine> for G1Uncompressed {
+    #[inline]
+    fn from(value: G1Affine) -> Self {
+        Self::from(&value.0)
+    }
+}
+
+impl TryFrom<&G1Uncompressed> for G1Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G1Uncompressed) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<G1Uncompressed> for G1Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G1Uncompressed) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<&G1Uncompressed> for G1Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G1Uncompressed) -> Result<Self, Self::Error> {
+        G1Projective::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<G1Uncompressed> for G1Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G1Uncompressed) -> Result<Self, Self::Error> {
+        G1Projective::try_from(value).map(Self::from)
+    }
+}
+
+/// Sampling with [rand::Rng::gen] via [Group::random]
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<G1Projective> for rand::distributions::Standard {
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> G1Projective {
+        G1Projective::random(rng)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for G1Projective {
+    fn zeroize(&mut self) {
+        zeroize_wrappers(core::slice::from_mut(&mut self.0));
+        unsafe {
+            wrapper_g1_neutral(&mut self.0);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for G1Projective {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serde_helpers::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G1Projective {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde_helpers::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pairing::group::ff::Field;
+
+    use super::*;
+
+    #[test]
+    fn random_fast() {
+        let mut rng = rand::thread_rng();
+        let g = G1Projective::random_fast(&mut rng);
+        assert!(!bool::from(g.is_identity()));
+        assert_ne!(g, G1Projective::random_fast(&mut rng));
+    }
+
+    #[cfg(all(feature = "checked-arithmetic", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "arithmetic on invalid G1 point")]
+    fn checked_arithmetic() {
+        let mut g = G1Projective::random_fast(rand::thread_rng());
+        g.0[0].y[0] ^= 1;
+        assert!(!g.is_valid());
+        let _ = g + G1Projective::generator();
+    }
+
+    #[test]
+    fn from_uniform_bytes() {
+        let seed = [0x42; 64];
+        let v = G1Projective::from_uniform_bytes(&seed, b"dst");
+        assert_eq!(v, G1Projective::from_uniform_bytes(&seed, b"dst"));
+        assert!(v.is_valid());
+        assert_ne!(v, G1Projective::from_uniform_bytes(&seed, b"other dst"));
+        assert_ne!(v, G1Projective::from_uniform_bytes(&[0x43; 64], b"dst"));
+
+        let mut rng = rand::thread_rng();
+        assert_ne!(
+            G1Projective::random_with_dst(&mut rng, b"dst"),
+            G1Projective::random_with_dst(&mut rng, b"dst")
+        );
+    }
+
+    #[test]
+    fn double_n() {
+        let mut rng = rand::thread_rng();
+        let g = G1Projective::random_fast(&mut rng);
+        assert_eq!(g.double_n(0), g);
+        assert_eq!(g.double_n(1), g.double());
+        assert_eq!(g.double_n(3), g.double().double().double());
+        assert_eq!(g.double_n(40), g * Scalar::from(1u64 << 40));
+        assert!(bool::from(
+            G1Projective::identity().double_n(5).is_identity()
+        ));
+    }
+
+    #[test]
     fn generator() {
         let generator = G1Projective::generator();
         let identity = G1Projective::identity();
         assert_ne!(generator, identity);
+        assert_eq!(generator, new_generator());
+        assert_eq!(G1Projective::generator(), generator);
+        #[cfg(feature = "std")]
+        assert_eq!(
+            std::thread::spawn(G1Projective::generator).join().unwrap(),
+            generator
+        );
     }
 
     #[test]
@@ -764,10 +1896,19 @@ mod test {
         let ra1 = &a1;
         let ra2 = &a2;
         assert_eq!(check, ra1 + ra2);
+        assert_eq!(check, a1 + ra2);
+        assert_eq!(check, ra1 + a2);
         assert_eq!(check, ra1 + v2);
         assert_eq!(check, v1 + ra2);
         assert_eq!(check, ra1 + rv2);
         assert_eq!(check, rv1 + ra2);
+
+        let mut acc = v1;
+        acc += a2;
+        assert_eq!(check, acc);
+        let mut acc = G1Projective::from(a1);
+        acc += ra2;
+        assert_eq!(check, acc);
     }
 
     #[test]
@@ -794,10 +1935,94 @@ mod test {
         let ra1 = &a1;
         let ra2 = &a2;
         assert_eq!(check, ra1 - ra2);
+        assert_eq!(check, a1 - ra2);
+        assert_eq!(check, ra1 - a2);
         assert_eq!(check, ra1 - v2);
         assert_eq!(check, v1 - ra2);
         assert_eq!(check, ra1 - rv2);
         assert_eq!(check, rv1 - ra2);
+
+        let mut acc = v1;
+        acc -= a2;
+        assert_eq!(check, acc);
+        let mut acc = G1Projective::from(a1);
+        acc -= ra2;
+        assert_eq!(check, acc);
+    }
+
+    #[test]
+    fn mul_u64() {
+        let mut rng = rand::thread_rng();
+        let g = G1Projective::random(&mut rng);
+        for scalar in [0, 1, 2, 3, 5, 1 << 20, u64::MAX] {
+            assert_eq!(g.mul_u64(scalar), g * Scalar::from(scalar));
+        }
+        assert_eq!(
+            G1Projective::identity().mul_u64(5),
+            G1Projective::identity()
+        );
+    }
+
+    #[test]
+    fn mul_add() {
+        let mut rng = rand::thread_rng();
+        let g = G1Projective::random(&mut rng);
+        let h = G1Projective::random(&mut rng);
+        let s = Scalar::random(&mut rng);
+
+        assert_eq!(g.mul_add(&s, &h), g * s + h);
+        assert_eq!(g.mul_add(&Scalar::ZERO, &h), h);
+        assert_eq!(g.mul_add(&Scalar::ONE, &-g), G1Projective::identity());
+        assert_eq!(G1Projective::identity().mul_add(&s, &h), h);
+        assert_eq!(g.mul_add(&s, &G1Projective::identity()), g * s);
+        assert_eq!(g.mul_add(&s, &g), g * (s + Scalar::ONE));
+    }
+
+    #[test]
+    fn mul_generator() {
+        let mut rng = rand::thread_rng();
+        for s in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::random(&mut rng),
+            Scalar::random(&mut rng),
+        ] {
+            assert_eq!(
+                G1Projective::mul_generator(&s),
+                G1Projective::generator() * s
+            );
+        }
+    }
+
+    #[test]
+    fn affine_operands() {
+        // only the bounds are checked here, the results by the add and sub tests
+        fn assert_ops<A>()
+        where
+            A: Add<A, Output = G1Projective> + Sub<A, Output = G1Projective>,
+            A: for<'a> Add<&'a A, Output = G1Projective>
+                + for<'a> Sub<&'a A, Output = G1Projective>,
+            for<'a> &'a A: Add<A, Output = G1Projective> + Sub<A, Output = G1Projective>,
+            for<'a> &'a A: Add<&'a A, Output = G1Projective> + Sub<&'a A, Output = G1Projective>,
+        {
+        }
+        assert_ops::<G1Affine>();
+    }
+
+    #[test]
+    fn neg() {
+        let v = G1Projective::random(rand::thread_rng());
+        let check = G1Projective::identity() - v;
+
+        let rv = &v;
+        assert_eq!(check, -v);
+        assert_eq!(check, -rv);
+
+        let a = v.to_affine();
+        let ra = &a;
+        assert_eq!(G1Affine::from(check), -a);
+        assert_eq!(G1Affine::from(check), -ra);
     }
 
     #[test]
@@ -822,10 +2047,10 @@ mod test {
         assert_eq!(check, ra * s);
         assert_eq!(check, ra * rs);
         assert_eq!(check, a * rs);
-        // assert_eq!(check, s * a);
-        // assert_eq!(check, rs * a);
-        // assert_eq!(check, rs * ra);
-        // assert_eq!(check, s * ra);
+        assert_eq!(check, s * a);
+        assert_eq!(check, rs * a);
+        assert_eq!(check, rs * ra);
+        assert_eq!(check, s * ra);
 
         let mut mv = v;
         mv *= s;
@@ -849,6 +2074,285 @@ mod test {
         assert_eq!(G1Projective::sum([(v1, s1), (v2, s2)].into_iter()), check);
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ord() {
+        let mut rng = rand::thread_rng();
+        let mut points: Vec<_> = (0..16)
+            .map(|_| G1Projective::random_fast(&mut rng).to_affine())
+            .collect();
+        points.push(points[3]);
+        points.sort();
+
+        for pair in points.windows(2) {
+            assert!(pair[0].to_compressed() <= pair[1].to_compressed());
+            assert_eq!(pair[0] == pair[1], pair[0].cmp(&pair[1]).is_eq());
+            assert_eq!(pair[1].cmp(&pair[0]), pair[0].cmp(&pair[1]).reverse());
+        }
+        assert_eq!(
+            points.windows(2).filter(|pair| pair[0] == pair[1]).count(),
+            1
+        );
+
+        let mut shuffled = points.clone();
+        shuffled.reverse();
+        shuffled.sort();
+        assert_eq!(shuffled, points);
+    }
+
+    #[test]
+    fn sum_of_products() {
+        let mut rng = rand::thread_rng();
+        let terms: [(G1Projective, Scalar); 4] = core::array::from_fn(|_| {
+            (
+                G1Projective::random_fast(&mut rng),
+                Scalar::random(&mut rng),
+            )
+        });
+        let check = terms
+            .iter()
+            .fold(G1Projective::identity(), |acc, (g, s)| acc + g * s);
+
+        assert_eq!(terms.iter().sum::<G1Projective>(), check);
+        assert_eq!(terms.into_iter().sum::<G1Projective>(), check);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fixed_base() {
+        let mut rng = rand::thread_rng();
+        let s = Scalar::random(&mut rng);
+
+        let fixed_base = G1FixedBase::new();
+        assert_eq!(fixed_base.mul(&s), G1Projective::generator() * s);
+        assert_eq!(fixed_base.mul(&Scalar::ZERO), G1Projective::identity());
+
+        let base = G1Projective::random(&mut rng);
+        let fixed_base = G1FixedBase::with_base(&base);
+        assert_eq!(fixed_base.mul(&s), base * s);
+    }
+
+    #[test]
+    fn multiexp_array() {
+        let mut rng = rand::thread_rng();
+        let points: [G1Projective; 3] = core::array::from_fn(|_| G1Projective::random(&mut rng));
+        let scalars: [Scalar; 3] = core::array::from_fn(|_| Scalar::random(&mut rng));
+
+        let check = points
+            .iter()
+            .zip(scalars.iter())
+            .fold(G1Projective::identity(), |acc, (p, s)| acc + p * s);
+        assert_eq!(G1Projective::multiexp_array(&points, &scalars), check);
+    }
+
+    #[test]
+    fn msm() {
+        let mut rng = rand::thread_rng();
+        let points: [G1Projective; 5] = core::array::from_fn(|_| G1Projective::random(&mut rng));
+        let scalars: [Scalar; 5] = core::array::from_fn(|_| Scalar::random(&mut rng));
+        let affine_points: [G1Affine; 5] = core::array::from_fn(|i| points[i].to_affine());
+
+        let check = points
+            .iter()
+            .zip(scalars.iter())
+            .fold(G1Projective::identity(), |acc, (p, s)| acc + p * s);
+        assert_eq!(G1Projective::msm(&affine_points, &scalars), check);
+        assert_eq!(
+            G1Projective::msm(&affine_points[..1], &scalars[..1]),
+            points[0] * scalars[0]
+        );
+        assert_eq!(G1Projective::msm(&[], &[]), G1Projective::identity());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn msm_pippenger() {
+        let mut rng = rand::thread_rng();
+        let points: Vec<_> = (0..40).map(|_| G1Projective::random(&mut rng)).collect();
+        let scalars: Vec<_> = (0..40).map(|_| Scalar::random(&mut rng)).collect();
+        let affine_points: Vec<_> = points.iter().map(|p| p.to_affine()).collect();
+
+        let check = G1Projective::msm(&affine_points, &scalars);
+        assert_eq!(
+            G1Projective::msm_pippenger(&affine_points, &scalars, None),
+            check
+        );
+        for window_bits in [1, 4, 7] {
+            assert_eq!(
+                G1Projective::msm_pippenger(&affine_points, &scalars, Some(window_bits)),
+                check
+            );
+        }
+
+        let edge_scalars = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE];
+        assert_eq!(
+            G1Projective::msm_pippenger(&affine_points[..3], &edge_scalars, Some(5)),
+            points[1] - points[2]
+        );
+        assert_eq!(
+            G1Projective::msm_pippenger(&[], &[], None),
+            G1Projective::identity()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn msm_pippenger_window_too_large() {
+        G1Projective::msm_pippenger(&[], &[], Some(17));
+    }
+
+    #[test]
+    #[should_panic]
+    fn msm_different_lengths() {
+        let mut rng = rand::thread_rng();
+        let points = [G1Projective::random(&mut rng).to_affine()];
+        G1Projective::msm(&points, &[]);
+    }
+
+    #[test]
+    fn sum_of_products_edge_cases() {
+        let mut rng = rand::thread_rng();
+        let v = G1Projective::random(&mut rng);
+        let s = Scalar::random(&mut rng);
+        let empty: [(G1Projective, Scalar); 0] = [];
+
+        assert_eq!(
+            core::iter::empty::<(G1Projective, Scalar)>().sum::<G1Projective>(),
+            G1Projective::identity()
+        );
+        assert_eq!(empty.iter().sum::<G1Projective>(), G1Projective::identity());
+        assert_eq!(
+            G1Projective::multiexp_array::<0>(&[], &[]),
+            G1Projective::identity()
+        );
+
+        assert_eq!(core::iter::once((v, s)).sum::<G1Projective>(), v * s);
+        assert_eq!([(v, s)].iter().sum::<G1Projective>(), v * s);
+        assert_eq!(G1Projective::multiexp_array(&[v], &[s]), v * s);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn multi_mul_parallel() {
+        let mut rng = rand::thread_rng();
+        for len in [0, 1, 7, 64] {
+            let points: Vec<_> = (0..len).map(|_| G1Projective::random(&mut rng)).collect();
+            let scalars: Vec<_> = (0..len).map(|_| Scalar::random(&mut rng)).collect();
+
+            let check = points
+                .iter()
+                .zip(scalars.iter())
+                .fold(G1Projective::identity(), |acc, (p, s)| acc + p * s);
+            assert_eq!(G1Projective::multi_mul_parallel(&points, &scalars), check);
+        }
+    }
+
+    #[test]
+    fn format() {
+        let g = G1Projective::generator();
+        let hex: String = <[u8; COMPRESSED_BYTES_SIZE]>::from(g)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+
+        assert_eq!(format!("{g}"), hex);
+        assert_eq!(format!("{g:x}"), hex);
+        assert_eq!(format!("{g:#X}"), format!("0x{}", hex.to_uppercase()));
+        assert_eq!(format!("{g:?}"), format!("G1Projective(0x{hex})"));
+        assert_eq!(format!("{}", G1Affine::from(g)), hex);
+    }
+
+    #[test]
+    fn parse() {
+        let g = G1Projective::generator();
+        let uncompressed: String = <[u8; UNCOMPRESSED_BYTES_SIZE]>::from(g)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+
+        assert_eq!(g.to_string().parse::<G1Projective>().unwrap(), g);
+        assert_eq!(format!("{g:#x}").parse::<G1Projective>().unwrap(), g);
+        assert_eq!(format!("{g:#X}").parse::<G1Projective>().unwrap(), g);
+        assert_eq!(uncompressed.parse::<G1Projective>().unwrap(), g);
+        assert_eq!(
+            G1Projective::try_from(format!("0X{uncompressed}").as_str()).unwrap(),
+            g
+        );
+        assert_eq!(
+            G1Affine::try_from(g.to_string().as_str()).unwrap(),
+            G1Affine::from(g)
+        );
+        assert_eq!(
+            G1Projective::identity()
+                .to_string()
+                .parse::<G1Projective>()
+                .unwrap(),
+            G1Projective::identity()
+        );
+
+        let hex = g.to_string();
+        assert!(matches!(
+            hex[2..].parse::<G1Projective>(),
+            Err(ParseElementError::InvalidLength(len)) if len == hex.len() - 2
+        ));
+        assert!(matches!(
+            hex[1..].parse::<G1Projective>(),
+            Err(ParseElementError::InvalidLength(_))
+        ));
+        assert!(matches!(
+            "".parse::<G1Projective>(),
+            Err(ParseElementError::InvalidLength(0))
+        ));
+        assert!(matches!(
+            format!("{}g", &hex[1..]).parse::<G1Projective>(),
+            Err(ParseElementError::InvalidDigit('g'))
+        ));
+        assert!(matches!(
+            "ff".repeat(COMPRESSED_BYTES_SIZE).parse::<G1Projective>(),
+            Err(ParseElementError::InvalidElement(_))
+        ));
+    }
+
+    #[test]
+    fn hash_set() {
+        let g = G1Projective::generator();
+        let set: std::collections::HashSet<_> = [
+            g.double(),
+            g + g,
+            g * Scalar::from(2),
+            G1Projective::from(G1Affine::from(g.double())),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[cfg(feature = "xmd")]
+    #[test]
+    fn hash_chunks() {
+        let msg = b"this is a message split into multiple parts";
+        let dst = b"BLS12381G1_XMD:SHA-256_SSWU_RO_TESTGEN";
+        let check = G1Projective::hash_to_curve(msg, dst);
+
+        assert_eq!(G1Projective::hash_to_curve_chunks(&[msg], dst), check);
+        assert_eq!(
+            G1Projective::hash_to_curve_chunks(&[&msg[..4], b"", &msg[4..20], &msg[20..]], dst),
+            check
+        );
+        assert_eq!(
+            G1Projective::hash_to_curve_chunks(&[], dst),
+            G1Projective::hash_to_curve(b"", dst)
+        );
+
+        // DSTs longer than 255 bytes are hashed first
+        let long_dst = [b'a'; 300];
+        assert_eq!(
+            G1Projective::hash_to_curve_chunks(&[msg], &long_dst),
+            G1Projective::hash_to_curve(msg, &long_dst)
+        );
+    }
+
     #[test]
     fn hash() {
         let h1 = G1Projective::hash_to_curve(b"1", b"dst");
@@ -857,6 +2361,110 @@ mod test {
         assert_ne!(h1, h2);
     }
 
+    #[test]
+    fn constant_time() {
+        let mut rng = rand::thread_rng();
+        let v1 = G1Projective::random(&mut rng);
+        let v2 = G1Projective::random(&mut rng);
+
+        assert_eq!(v1.ct_eq(&v1).unwrap_u8(), 1);
+        assert_eq!(v1.ct_eq(&(v1 + v2 - v2)).unwrap_u8(), 1);
+        assert_eq!(v1.ct_eq(&v2).unwrap_u8(), 0);
+
+        assert_eq!(
+            G1Projective::conditional_select(&v1, &v2, Choice::from(0)),
+            v1
+        );
+        assert_eq!(
+            G1Projective::conditional_select(&v1, &v2, Choice::from(1)),
+            v2
+        );
+    }
+
+    #[test]
+    fn conditional_select_representations() {
+        let mut rng = rand::thread_rng();
+        let v1 = G1Projective::random(&mut rng);
+        let v2 = G1Projective::random(&mut rng);
+        // normalized coordinates
+        let v1_normalized = G1Projective::from_bytes(&v1.to_bytes()).unwrap();
+        // coordinates that are not normalized
+        let v2_projective = v2.double() - v2;
+
+        for (choice, expected) in [(0, v1), (1, v2)] {
+            let selected = G1Projective::conditional_select(
+                &v1_normalized,
+                &v2_projective,
+                Choice::from(choice),
+            );
+            assert_eq!(selected, expected);
+            assert_eq!(selected + v1, expected + v1);
+            assert!(selected.is_valid());
+            let selected = G1Projective::conditional_select(
+                &v2_projective,
+                &v1_normalized,
+                Choice::from(1 - choice),
+            );
+            assert_eq!(selected, expected);
+            assert_eq!(selected.double(), expected.double());
+        }
+    }
+
+    #[test]
+    fn zcash_format() {
+        let mut rng = rand::thread_rng();
+        let s = Scalar::random(&mut rng);
+        let mut s_bytes = s.to_bytes();
+        s_bytes.reverse();
+        let s_ref = bls12_381::Scalar::from_bytes(&s_bytes).unwrap();
+
+        for (v, v_ref) in [
+            (G1Projective::identity(), bls12_381::G1Affine::identity()),
+            (G1Projective::generator(), bls12_381::G1Affine::generator()),
+            (
+                G1Projective::generator() * s,
+                bls12_381::G1Affine::from(bls12_381::G1Affine::generator() * s_ref),
+            ),
+            (
+                -G1Projective::generator() * s,
+                bls12_381::G1Affine::from(-bls12_381::G1Affine::generator() * s_ref),
+            ),
+        ] {
+            let bytes = v.to_bytes_zcash();
+            assert_eq!(bytes, v_ref.to_compressed());
+            assert_eq!(G1Projective::from_bytes_zcash(&bytes).unwrap(), v);
+        }
+
+        let mut bytes = G1Projective::generator().to_bytes_zcash();
+        bytes[0] &= !zcash::FLAG_COMPRESSED;
+        assert!(bool::from(G1Projective::from_bytes_zcash(&bytes).is_none()));
+
+        let mut bytes = G1Projective::identity().to_bytes_zcash();
+        bytes[COORDINATE_BYTES_SIZE - 1] = 1;
+        assert!(bool::from(G1Projective::from_bytes_zcash(&bytes).is_none()));
+    }
+
+    #[test]
+    fn affine_encoding() {
+        let mut rng = rand::thread_rng();
+        let v = G1Affine::from(G1Projective::random(&mut rng));
+
+        let compressed = v.to_compressed();
+        assert_eq!(compressed, <[u8; COMPRESSED_BYTES_SIZE]>::from(v.0));
+        assert_eq!(G1Affine::from_compressed(&compressed).unwrap(), v);
+
+        let uncompressed = v.to_uncompressed();
+        assert_eq!(uncompressed, <[u8; UNCOMPRESSED_BYTES_SIZE]>::from(v.0));
+        assert_eq!(G1Affine::from_uncompressed(&uncompressed).unwrap(), v);
+
+        assert!(bool::from(
+            G1Affine::from_compressed(&[0xff; COMPRESSED_BYTES_SIZE]).is_none()
+        ));
+        assert!(bool::from(
+            G1Affine::from_uncompressed(&[0xff; UNCOMPRESSED_BYTES_SIZE]).is_none()
+        ));
+    }
+
     #[test]
     fn bytes() {
         let mut rng = rand::thread_rng();
@@ -879,6 +2487,195 @@ mod test {
         assert_eq!(v1, v2);
     }
 
+    #[test]
+    fn recompress_decompress() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..8 {
+            let v = G1Projective::random(&mut rng);
+            let compressed: [u8; COMPRESSED_BYTES_SIZE] = v.into();
+            let uncompressed: [u8; UNCOMPRESSED_BYTES_SIZE] = v.into();
+
+            assert_eq!(recompress(&uncompressed).unwrap(), compressed);
+            assert_eq!(decompress(&compressed).unwrap(), uncompressed);
+        }
+
+        assert!(recompress(&[0xff; UNCOMPRESSED_BYTES_SIZE]).is_err());
+        assert!(decompress(&[0xff; COMPRESSED_BYTES_SIZE]).is_err());
+    }
+
+    #[test]
+    fn encoded_newtypes() {
+        let mut rng = rand::thread_rng();
+        let v = G1Projective::random(&mut rng);
+        let a = G1Affine::from(v);
+
+        let compressed = G1Compressed::from(a);
+        assert_eq!(compressed, G1Compressed::from(v));
+        assert_eq!(
+            compressed.as_bytes(),
+            &<[u8; COMPRESSED_BYTES_SIZE]>::from(v)
+        );
+        assert_eq!(compressed.as_ref(), &compressed.as_bytes()[..]);
+        assert_eq!(G1Affine::try_from(compressed).unwrap(), a);
+        assert_eq!(G1Projective::try_from(&compressed).unwrap(), v);
+
+        let uncompressed = G1Uncompressed::from(a);
+        assert_eq!(uncompressed, G1Uncompressed::from(v));
+        assert_eq!(
+            uncompressed.as_bytes(),
+            &<[u8; UNCOMPRESSED_BYTES_SIZE]>::from(v)
+        );
+        assert_eq!(G1Affine::try_from(&uncompressed).unwrap(), a);
+        assert_eq!(G1Projective::try_from(uncompressed).unwrap(), v);
+
+        assert!(G1Affine::try_from(G1Compressed::from([0xff; COMPRESSED_BYTES_SIZE])).is_err());
+        assert!(G1Affine::try_from(G1Uncompressed::from([0xff; UNCOMPRESSED_BYTES_SIZE])).is_err());
+    }
+
+    #[test]
+    fn affine_coordinates_and_cofactor() {
+        let g = G1Projective::generator();
+        let bytes: [u8; UNCOMPRESSED_BYTES_SIZE] = g.into();
+        let x: [u8; COORDINATE_BYTES_SIZE] = bytes[1..=COORDINATE_BYTES_SIZE].try_into().unwrap();
+        let y: [u8; COORDINATE_BYTES_SIZE] = bytes[COORDINATE_BYTES_SIZE + 1..].try_into().unwrap();
+        assert_eq!(
+            G1Projective::from_affine_coordinates_unchecked(&x, &y).unwrap(),
+            g
+        );
+
+        // (0, 2) is on the curve and has order 3, which divides the cofactor
+        let mut y = [0u8; COORDINATE_BYTES_SIZE];
+        y[COORDINATE_BYTES_SIZE - 1] = 2;
+        let v = G1Projective::from_affine_coordinates_unchecked(&[0; COORDINATE_BYTES_SIZE], &y)
+            .unwrap();
+        assert!(!v.is_valid());
+        assert!(matches!(
+            G1Projective::try_from(&<[u8; UNCOMPRESSED_BYTES_SIZE]>::from(v)),
+            Err(Error::NotInSubgroup)
+        ));
+        assert!(bool::from(v.mul_by_cofactor().is_identity()));
+
+        // (4, y) is on the curve and its order is a multiple of the group order
+        let mut x = [0u8; COORDINATE_BYTES_SIZE];
+        x[COORDINATE_BYTES_SIZE - 1] = 4;
+        let y: [u8; COORDINATE_BYTES_SIZE] = core::array::from_fn(|i| {
+            u8::from_str_radix(
+                &"0a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c"[2 * i..2 * i + 2],
+                16,
+            )
+            .unwrap()
+        });
+        let v = G1Projective::from_affine_coordinates_unchecked(&x, &y).unwrap();
+        assert!(!v.is_valid());
+        assert!(matches!(
+            G1Projective::try_from(&<[u8; UNCOMPRESSED_BYTES_SIZE]>::from(v)),
+            Err(Error::NotInSubgroup)
+        ));
+
+        let v = v.mul_by_cofactor();
+        assert!(v.is_valid());
+        assert!(!bool::from(v.is_identity()));
+
+        assert!(G1Projective::from_affine_coordinates_unchecked(
+            &[0; COORDINATE_BYTES_SIZE],
+            &[0; COORDINATE_BYTES_SIZE]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let mut rng = rand::thread_rng();
+        let v = G1Projective::random(&mut rng);
+        let compressed: [u8; COMPRESSED_BYTES_SIZE] = v.into();
+        let uncompressed: [u8; UNCOMPRESSED_BYTES_SIZE] = v.into();
+        assert_eq!(G1Projective::try_from(&compressed[..]).unwrap(), v);
+        assert_eq!(G1Projective::try_from(&uncompressed[..]).unwrap(), v);
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(G1Projective::try_from(&compressed.to_vec()).unwrap(), v);
+            assert_eq!(G1Projective::try_from(uncompressed.to_vec()).unwrap(), v);
+        }
+
+        let bytes = [0u8; 200 + 1];
+        for len in 0..=bytes.len() {
+            if len == COMPRESSED_BYTES_SIZE || len == UNCOMPRESSED_BYTES_SIZE {
+                continue;
+            }
+            assert!(matches!(
+                G1Projective::try_from(&bytes[..len]),
+                Err(Error::InvalidBytesRepresentation)
+            ));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_from() {
+        use std::io::ErrorKind;
+
+        let mut rng = rand::thread_rng();
+        let points: Vec<_> = (0..10)
+            .map(|_| G1Projective::random(&mut rng).to_affine())
+            .collect();
+        let mut bytes = Vec::new();
+        for point in &points {
+            bytes.extend_from_slice(&point.to_compressed());
+        }
+
+        let mut reader = bytes.as_slice();
+        for point in &points {
+            assert_eq!(G1Affine::read_from(&mut reader).unwrap(), *point);
+        }
+        assert_eq!(
+            G1Affine::read_from(&mut reader).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+
+        let read: Vec<_> = G1Affine::read_many(bytes.as_slice())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read, points);
+        assert_eq!(G1Affine::read_many(&[][..]).count(), 0);
+
+        // truncated input
+        let mut results = G1Affine::read_many(&bytes[..bytes.len() - 1]);
+        assert_eq!(results.by_ref().take(9).filter(|r| r.is_ok()).count(), 9);
+        assert_eq!(
+            results.next().unwrap().unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+        assert!(results.next().is_none());
+        drop(results);
+
+        // invalid point
+        bytes[COMPRESSED_BYTES_SIZE..2 * COMPRESSED_BYTES_SIZE].fill(0xff);
+        let mut results = G1Affine::read_many(bytes.as_slice());
+        assert!(results.next().unwrap().is_ok());
+        assert_eq!(
+            results.next().unwrap().unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert!(results.next().is_none());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut g = G1Projective::random(rand::thread_rng());
+        g.zeroize();
+        let point = &g.0[0];
+        assert!(point
+            .x
+            .iter()
+            .chain(point.y.iter())
+            .chain(point.z.iter())
+            .all(|limb| *limb == 0));
+        assert_eq!(g, G1Projective::identity());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_serialization() {
@@ -895,5 +2692,25 @@ mod test {
 
         let abytes = bincode::serialize(&a1).unwrap();
         assert_eq!(bytes, abytes);
+
+        let json = serde_json::to_string(&v1).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                "\"{}\"",
+                v1.to_bytes()
+                    .iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<String>()
+            )
+        );
+        let v3: G1Projective = serde_json::from_str(&json).unwrap();
+        assert_eq!(v2, v3);
+        let a3: G1Affine = serde_json::from_str(&json).unwrap();
+        assert_eq!(a2, a3);
+        assert_eq!(json, serde_json::to_string(&a1).unwrap());
+
+        assert!(serde_json::from_str::<G1Projective>("\"00\"").is_err());
+        assert!(serde_json::from_str::<G1Projective>("\"not hex\"").is_err());
     }
 }
@@ -1,43 +1,57 @@
 //! Implementation of the second source group `G2`
 
 use core::{
+    hash::{Hash, Hasher},
     iter::Sum,
     mem::MaybeUninit,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
 };
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 
 use generic_array::{
     typenum::{Unsigned, U193, U97},
     GenericArray,
 };
-#[cfg(feature = "alloc")]
-use librelic_sys::wrapper_g2_simmul;
+#[cfg(feature = "xmd")]
+use librelic_sys::wrapper_g2_map_from_field;
 use librelic_sys::{
-    wrapper_g2_add, wrapper_g2_add_assign, wrapper_g2_double, wrapper_g2_generator,
-    wrapper_g2_hash_to_curve, wrapper_g2_init, wrapper_g2_is_equal, wrapper_g2_is_neutral,
-    wrapper_g2_is_valid, wrapper_g2_mul, wrapper_g2_mul_assign, wrapper_g2_neg, wrapper_g2_neutral,
-    wrapper_g2_norm, wrapper_g2_read_bin, wrapper_g2_sub, wrapper_g2_sub_assign, wrapper_g2_t,
-    wrapper_g2_write_bin, RLC_OK,
+    wrapper_bn_t, wrapper_g2_add, wrapper_g2_add_assign, wrapper_g2_conditional_select,
+    wrapper_g2_double, wrapper_g2_double_n, wrapper_g2_generator, wrapper_g2_hash_to_curve,
+    wrapper_g2_init, wrapper_g2_is_equal, wrapper_g2_is_neutral, wrapper_g2_is_valid,
+    wrapper_g2_mul, wrapper_g2_mul_assign, wrapper_g2_mul_cof, wrapper_g2_neg, wrapper_g2_neutral,
+    wrapper_g2_norm, wrapper_g2_on_curve, wrapper_g2_read_bin, wrapper_g2_simmul, wrapper_g2_sub,
+    wrapper_g2_sub_assign, wrapper_g2_t, wrapper_g2_write_bin, RLC_OK,
 };
+#[cfg(feature = "alloc")]
+use librelic_sys::{wrapper_g2_mul_fix, wrapper_g2_mul_pre, wrapper_g2_table_t};
 use pairing::group::{
+    ff::Field,
     prime::{PrimeCurve, PrimeGroup},
     Curve, Group, GroupEncoding, UncompressedEncoding,
 };
 use rand_core::RngCore;
-use subtle::{Choice, CtOption};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-use crate::{affine, Affine, Error, Scalar, RANDOM_DOMAIN_SEPERATOR};
+#[cfg(feature = "alloc")]
+use crate::pippenger;
+#[cfg(feature = "xmd")]
+use crate::xmd::{expand_message_xmd, FIELD_ELEMENT_BYTES};
+use crate::{
+    affine, parse_hex, zcash, zeroize_wrappers, Affine, Error, ParseElementError, Scalar,
+    RANDOM_DOMAIN_SEPERATOR,
+};
 
 type CompressedSize = U97;
 type UncompressedSize = U193;
 
-const COMPRESSED_BYTES_SIZE: usize = CompressedSize::USIZE;
+pub(crate) const COMPRESSED_BYTES_SIZE: usize = CompressedSize::USIZE;
 const UNCOMPRESSED_BYTES_SIZE: usize = UncompressedSize::USIZE;
+const COORDINATE_BYTES_SIZE: usize = (UNCOMPRESSED_BYTES_SIZE - 1) / 2;
 
 #[inline]
 fn new_wrapper() -> wrapper_g2_t {
@@ -48,8 +62,17 @@ fn new_wrapper() -> wrapper_g2_t {
     }
 }
 
+/// Obtain the generator from relic
+fn new_generator() -> G2Projective {
+    let mut value = new_wrapper();
+    unsafe {
+        wrapper_g2_generator(&mut value);
+    }
+    G2Projective(value)
+}
+
 /// Representation of a G2 element
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct G2Projective(pub(crate) wrapper_g2_t);
 
@@ -64,6 +87,482 @@ impl G2Projective {
         }
         g2.into()
     }
+
+    /// Hash a message given in multiple parts to a point on the curve
+    ///
+    /// This function computes the same result as [Self::hash_to_curve] on the
+    /// concatenation of all parts, but does not need to concatenate them.
+    ///
+    /// ```
+    /// use bls12_381_relic::G2Projective;
+    ///
+    /// assert_eq!(
+    ///     G2Projective::hash_to_curve_chunks(&[b"my ", b"message"], b"dst"),
+    ///     G2Projective::hash_to_curve(b"my message", b"dst")
+    /// );
+    /// ```
+    #[cfg(feature = "xmd")]
+    pub fn hash_to_curve_chunks(parts: &[&[u8]], dst: &[u8]) -> Self {
+        let mut uniform_bytes = [0u8; 4 * FIELD_ELEMENT_BYTES];
+        expand_message_xmd(parts, dst, &mut uniform_bytes);
+
+        let mut g2 = new_wrapper();
+        unsafe {
+            wrapper_g2_map_from_field(&mut g2, uniform_bytes.as_ptr(), uniform_bytes.len());
+        }
+        g2.into()
+    }
+
+    /// Sample a random point by multiplying the generator with a random scalar
+    ///
+    /// This is faster than [Group::random], which hashes random bytes to the
+    /// curve, and is intended for tests and benchmarks.
+    ///
+    /// **Warning**: The discrete logarithm of the point with respect to the
+    /// generator is known while it is computed. This function must not be used
+    /// where points with unknown discrete logarithm are required, e.g., for
+    /// the bases of commitments.
+    pub fn random_fast(rng: impl RngCore) -> Self {
+        Self::generator() * Scalar::random(rng)
+    }
+
+    /// Sample a random point with a custom domain separator
+    ///
+    /// This function behaves like [Group::random], but hashes the random bytes
+    /// to the curve with `dst` instead of the crate's domain separator. See
+    /// [Self::from_uniform_bytes] for the deterministic version.
+    pub fn random_with_dst(mut rng: impl RngCore, dst: &[u8]) -> Self {
+        let mut buf = [0u8; 64];
+        rng.fill_bytes(&mut buf);
+        Self::from_uniform_bytes(&buf, dst)
+    }
+
+    /// Derive a point from 64 uniform bytes with a custom domain separator
+    ///
+    /// The bytes are hashed to the curve with [Self::hash_to_curve]. Hence,
+    /// the discrete logarithm of the point is unknown, which makes this
+    /// function suitable to derive nothing-up-my-sleeve points from a public
+    /// seed.
+    ///
+    /// ```
+    /// use bls12_381_relic::G2Projective;
+    ///
+    /// let seed = [0x42; 64];
+    /// assert_eq!(
+    ///     G2Projective::from_uniform_bytes(&seed, b"my protocol parameters"),
+    ///     G2Projective::hash_to_curve(seed, b"my protocol parameters")
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_uniform_bytes(bytes: &[u8; 64], dst: &[u8]) -> Self {
+        Self::hash_to_curve(bytes, dst)
+    }
+
+    /// Double the point `k` times, i.e., compute `2^k * self`
+    ///
+    /// This computes the same result as calling [Group::double] `k` times,
+    /// but performs all doublings in place in a single call into relic.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G2Projective, Scalar};
+    /// use bls12_381_relic::group::Group;
+    ///
+    /// let g = G2Projective::generator();
+    /// assert_eq!(g.double_n(10), g * Scalar::from(1024));
+    /// ```
+    pub fn double_n(&self, k: u32) -> Self {
+        let mut ret = new_wrapper();
+        unsafe {
+            wrapper_g2_double_n(&mut ret, &self.0, k as usize);
+        }
+        Self(ret)
+    }
+
+    /// Multiply the point by a small scalar
+    ///
+    /// The multiplication is performed by double-and-add over the bits of
+    /// `scalar`, which is faster than multiplying by a [Scalar] for small
+    /// values. The running time depends on `scalar`, hence this function is
+    /// only intended for public scalars.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G2Projective, Scalar};
+    /// use bls12_381_relic::group::Group;
+    ///
+    /// let g = G2Projective::generator();
+    /// assert_eq!(g.mul_u64(3), g * Scalar::from(3));
+    /// ```
+    pub fn mul_u64(&self, scalar: u64) -> Self {
+        let mut ret = Self::identity();
+        for bit in (0..u64::BITS - scalar.leading_zeros()).rev() {
+            ret = ret.double();
+            if (scalar >> bit) & 1 == 1 {
+                ret += self;
+            }
+        }
+        ret
+    }
+
+    /// Compute `self * scalar + addend`
+    ///
+    /// The product is computed into the wrapper of the result and the addend
+    /// is added in place, which saves the intermediate point of the expression
+    /// `self * scalar + addend`. This is useful when updating commitments or
+    /// accumulating homomorphically.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G2Projective, Scalar};
+    /// use bls12_381_relic::{group::Group, ff::Field};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let g = G2Projective::random(&mut rng);
+    /// let h = G2Projective::random(&mut rng);
+    /// let s = Scalar::random(&mut rng);
+    /// assert_eq!(g.mul_add(&s, &h), g * s + h);
+    /// ```
+    pub fn mul_add(&self, scalar: &Scalar, addend: &Self) -> Self {
+        let mut ret = new_wrapper();
+        self.debug_check_valid();
+        addend.debug_check_valid();
+        unsafe {
+            wrapper_g2_mul(&mut ret, &self.0, &scalar.0);
+            wrapper_g2_add_assign(&mut ret, &addend.0);
+        }
+        Self(ret)
+    }
+
+    /// Multiply the generator by a scalar
+    ///
+    /// This computes the same result as `G2Projective::generator() * scalar`,
+    /// e.g., to derive public keys from secret keys. With the `std` feature,
+    /// the multiplication uses a [G2FixedBase] table for the generator which
+    /// is precomputed on the first call and cached afterwards. Otherwise, the
+    /// generic scalar multiplication is used.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G2Projective, Scalar};
+    /// use bls12_381_relic::{group::Group, ff::Field};
+    ///
+    /// let sk = Scalar::random(rand::thread_rng());
+    /// assert_eq!(G2Projective::mul_generator(&sk), G2Projective::generator() * sk);
+    /// ```
+    pub fn mul_generator(scalar: &Scalar) -> Self {
+        #[cfg(feature = "std")]
+        {
+            static TABLE: std::sync::OnceLock<G2FixedBase> = std::sync::OnceLock::new();
+            TABLE.get_or_init(G2FixedBase::new).mul(scalar)
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            Self::generator() * scalar
+        }
+    }
+
+    /// Compute the sum of products of a fixed number of points and scalars
+    ///
+    /// This function computes the same result as the [Sum] implementation for
+    /// pairs of points and scalars, but does not require the `alloc` feature
+    /// to benefit from the faster simultaneous multiplication.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G2Projective, Scalar};
+    /// use bls12_381_relic::{group::Group, ff::Field};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let points = [G2Projective::random(&mut rng), G2Projective::random(&mut rng)];
+    /// let scalars = [Scalar::random(&mut rng), Scalar::random(&mut rng)];
+    /// assert_eq!(
+    ///     G2Projective::multiexp_array(&points, &scalars),
+    ///     points[0] * scalars[0] + points[1] * scalars[1]
+    /// );
+    /// ```
+    pub fn multiexp_array<const N: usize>(points: &[Self; N], scalars: &[Scalar; N]) -> Self {
+        let mut g2s: [wrapper_g2_t; N] = core::array::from_fn(|i| points[i].0);
+        let mut bns: [wrapper_bn_t; N] = core::array::from_fn(|i| scalars[i].0);
+
+        let mut g2 = new_wrapper();
+        unsafe {
+            wrapper_g2_simmul(&mut g2, g2s.as_ptr(), bns.as_ptr(), N);
+        }
+        zeroize_wrappers(&mut g2s);
+        zeroize_wrappers(&mut bns);
+        g2.into()
+    }
+
+    /// Compute the sum of products of points and scalars
+    ///
+    /// This is the recommended interface for multi-scalar multiplications. It
+    /// computes the same result as the [Sum] implementation for pairs of
+    /// points and scalars, but does not copy the inputs and hence does not
+    /// require the `alloc` feature. If both slices are empty, the result is
+    /// the identity.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G2Affine, G2Projective, Scalar};
+    /// use bls12_381_relic::{group::{Curve, Group}, ff::Field};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let points = [G2Projective::random(&mut rng), G2Projective::random(&mut rng)];
+    /// let scalars = [Scalar::random(&mut rng), Scalar::random(&mut rng)];
+    /// let affine_points = [points[0].to_affine(), points[1].to_affine()];
+    /// assert_eq!(
+    ///     G2Projective::msm(&affine_points, &scalars),
+    ///     points[0] * scalars[0] + points[1] * scalars[1]
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `scalars` have different lengths.
+    pub fn msm(points: &[G2Affine], scalars: &[Scalar]) -> Self {
+        assert_eq!(points.len(), scalars.len());
+
+        let mut g2 = new_wrapper();
+        // G2Affine and Scalar are transparent wrappers of the relic types
+        unsafe {
+            wrapper_g2_simmul(
+                &mut g2,
+                points.as_ptr().cast(),
+                scalars.as_ptr().cast(),
+                points.len(),
+            );
+        }
+        g2.into()
+    }
+
+    /// Compute the sum of products of points and scalars with Pippenger's
+    /// bucket method
+    ///
+    /// This function computes the same result as [Self::msm], but is
+    /// implemented on top of relic's point additions and is intended for large
+    /// inputs. Whether it is faster than relic's simultaneous multiplication
+    /// for a given number of terms depends on the platform and on relic's
+    /// configuration, so no crossover point is given here. It can be measured
+    /// with the `msm` benchmarks of `cargo bench --bench engine`, which compare
+    /// both in `G1` for up to 2^16 terms. If `window_bits` is `None`, the
+    /// window size is chosen based on the number of terms.
+    ///
+    /// **Warning**: The running time depends on the scalars. This function
+    /// must not be used with secret scalars.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G2Projective, Scalar};
+    /// use bls12_381_relic::{group::{Curve, Group}, ff::Field};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let points: Vec<_> = (0..64).map(|_| G2Projective::random(&mut rng).to_affine()).collect();
+    /// let scalars: Vec<_> = (0..64).map(|_| Scalar::random(&mut rng)).collect();
+    /// assert_eq!(
+    ///     G2Projective::msm_pippenger(&points, &scalars, None),
+    ///     G2Projective::msm(&points, &scalars)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `scalars` have different lengths or if
+    /// `window_bits` is not between 1 and 16.
+    #[cfg(feature = "alloc")]
+    pub fn msm_pippenger(
+        points: &[G2Affine],
+        scalars: &[Scalar],
+        window_bits: Option<usize>,
+    ) -> Self {
+        assert_eq!(points.len(), scalars.len());
+        let window_bits =
+            window_bits.unwrap_or_else(|| pippenger::default_window_bits(points.len()));
+        assert!((1..=pippenger::MAX_WINDOW_BITS).contains(&window_bits));
+
+        pippenger::msm(points, scalars, window_bits)
+    }
+
+    /// Compute the sum of products of points and scalars in parallel
+    ///
+    /// The inputs are split into one chunk per thread of the [rayon] thread
+    /// pool. Each chunk is handled by a simultaneous multiplication and the
+    /// partial results are summed up.
+    ///
+    /// Relic does not need to be locked for this: each thread operates on its
+    /// own relic context, which is set up on the first use of relic in that
+    /// thread. This requires relic to be built with multithreading support
+    /// (`MULTI=PTHREAD` or `MULTI=OPENMP`), which is the case for the vendored
+    /// build. If relic was built without it, all threads would share a single
+    /// context. This is checked at runtime and the multiplication is then
+    /// performed on the calling thread only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `scalars` have different lengths.
+    #[cfg(feature = "rayon")]
+    pub fn multi_mul_parallel(points: &[Self], scalars: &[Scalar]) -> Self {
+        use rayon::prelude::*;
+
+        assert_eq!(points.len(), scalars.len());
+        let simmul = |points: &[Self], scalars: &[Scalar]| {
+            let mut g2 = new_wrapper();
+            // G2Projective and Scalar are transparent wrappers of the relic types
+            unsafe {
+                wrapper_g2_simmul(
+                    &mut g2,
+                    points.as_ptr().cast(),
+                    scalars.as_ptr().cast(),
+                    points.len(),
+                );
+            }
+            Self(g2)
+        };
+        // without thread-local contexts, relic must only be used from one thread
+        if !unsafe { librelic_sys::wrapper_is_multithreaded() } {
+            return simmul(points, scalars);
+        }
+
+        let threads = rayon::current_num_threads().max(1);
+        let chunk_size = ((points.len() + threads - 1) / threads).max(1);
+        points
+            .par_chunks(chunk_size)
+            .zip(scalars.par_chunks(chunk_size))
+            .map(|(points, scalars)| simmul(points, scalars))
+            .reduce(Self::identity, |a, b| a + b)
+    }
+
+    /// Multiply the point by the cofactor
+    ///
+    /// This uses relic's cofactor multiplication, which maps any point on the
+    /// curve to the prime-order subgroup.
+    pub fn mul_by_cofactor(&self) -> Self {
+        let mut g2 = new_wrapper();
+        unsafe {
+            wrapper_g2_mul_cof(&mut g2, &self.0);
+        }
+        g2.into()
+    }
+
+    /// Check if the point is on the curve and in the prime-order subgroup
+    ///
+    /// All points obtained from the checked decoding functions and from
+    /// arithmetic on valid points are valid. Points created with
+    /// [Self::from_affine_coordinates_unchecked] may not be.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        unsafe { wrapper_g2_is_valid(&self.0) }
+    }
+
+    /// Check that the point is valid before it is used in arithmetic (internal)
+    ///
+    /// With the `checked-arithmetic` feature, this panics in debug builds if
+    /// the point is neither valid nor the identity. Otherwise, it does nothing.
+    #[inline(always)]
+    fn debug_check_valid(&self) {
+        #[cfg(feature = "checked-arithmetic")]
+        debug_assert!(
+            bool::from(self.is_identity()) || self.is_valid(),
+            "arithmetic on invalid G2 point"
+        );
+    }
+
+    /// Create a point from its affine coordinates without a subgroup check
+    ///
+    /// The coordinates are encoded as in the uncompressed encoding of points.
+    ///
+    /// Coordinates of points that are not on the curve are rejected with
+    /// [Error::InvalidBytesRepresentation].
+    ///
+    /// **Warning**: The point is only checked to be on the curve, but not to
+    /// be a member of the prime-order subgroup. Operations with such points
+    /// can produce invalid results. This function must only be used in tests
+    /// or with coordinates from trusted sources.
+    pub fn from_affine_coordinates_unchecked(
+        x: &[u8; COORDINATE_BYTES_SIZE],
+        y: &[u8; COORDINATE_BYTES_SIZE],
+    ) -> Result<Self, Error> {
+        let mut bytes = [0u8; UNCOMPRESSED_BYTES_SIZE];
+        bytes[0] = 0x04;
+        bytes[1..=COORDINATE_BYTES_SIZE].copy_from_slice(x);
+        bytes[COORDINATE_BYTES_SIZE + 1..].copy_from_slice(y);
+
+        let mut g2 = new_wrapper();
+        let ret = unsafe { wrapper_g2_read_bin(&mut g2, bytes.as_ptr(), bytes.len()) };
+        if ret != RLC_OK {
+            Err(Error::RelicError(ret.into()))
+        } else if !unsafe { wrapper_g2_on_curve(&g2) } {
+            // relic only checks the point if it is built with CHECK
+            Err(Error::InvalidBytesRepresentation)
+        } else {
+            Ok(Self(g2))
+        }
+    }
+
+    /// Encode the point in the compressed Zcash format
+    ///
+    /// This format consists of 96 bytes storing the x-coordinate (encoded as
+    /// `c1 || c0`) with the compression, infinity and sort flags in its three
+    /// most significant bits. It is used by the `bls12_381` crate and most
+    /// other implementations of BLS12-381, and is thus required to exchange
+    /// points with them.
+    pub fn to_bytes_zcash(&self) -> [u8; COORDINATE_BYTES_SIZE] {
+        let mut ret = [0u8; COORDINATE_BYTES_SIZE];
+        if bool::from(self.is_identity()) {
+            ret[0] = zcash::FLAG_COMPRESSED | zcash::FLAG_INFINITY;
+            return ret;
+        }
+
+        let bytes: [u8; UNCOMPRESSED_BYTES_SIZE] = self.into();
+        // relic encodes c0 || c1, whereas the Zcash format uses c1 || c0
+        ret[..zcash::FP_BYTES_SIZE]
+            .copy_from_slice(&bytes[1 + zcash::FP_BYTES_SIZE..=COORDINATE_BYTES_SIZE]);
+        ret[zcash::FP_BYTES_SIZE..].copy_from_slice(&bytes[1..=zcash::FP_BYTES_SIZE]);
+        ret[0] |= zcash::FLAG_COMPRESSED;
+        if zcash::fp2_is_lexicographically_largest(
+            &bytes[COORDINATE_BYTES_SIZE + 1..COORDINATE_BYTES_SIZE + 1 + zcash::FP_BYTES_SIZE],
+            &bytes[COORDINATE_BYTES_SIZE + 1 + zcash::FP_BYTES_SIZE..],
+        ) {
+            ret[0] |= zcash::FLAG_SORT;
+        }
+        ret
+    }
+
+    /// Decode a point from the compressed Zcash format
+    ///
+    /// The point is checked to be in the prime-order subgroup. See
+    /// [Self::to_bytes_zcash] for details on the format.
+    pub fn from_bytes_zcash(bytes: &[u8; COORDINATE_BYTES_SIZE]) -> CtOption<Self> {
+        let flags = bytes[0] & zcash::FLAGS_MASK;
+        if flags & zcash::FLAG_COMPRESSED == 0 {
+            return CtOption::new(Self::identity(), Choice::from(0));
+        }
+        if flags & zcash::FLAG_INFINITY != 0 {
+            return CtOption::new(
+                Self::identity(),
+                Choice::from(zcash::is_valid_infinity(bytes) as u8),
+            );
+        }
+
+        // decode with relic and fix the sign of y afterwards
+        let mut relic = [0u8; COMPRESSED_BYTES_SIZE];
+        relic[0] = 0x02;
+        relic[1..=zcash::FP_BYTES_SIZE].copy_from_slice(&bytes[zcash::FP_BYTES_SIZE..]);
+        relic[1 + zcash::FP_BYTES_SIZE..].copy_from_slice(&bytes[..zcash::FP_BYTES_SIZE]);
+        relic[1 + zcash::FP_BYTES_SIZE] &= !zcash::FLAGS_MASK;
+        match Self::try_from(&relic) {
+            Ok(point) => {
+                let is_largest = point.to_bytes_zcash()[0] & zcash::FLAG_SORT != 0;
+                let point = if is_largest == (flags & zcash::FLAG_SORT != 0) {
+                    point
+                } else {
+                    -point
+                };
+                CtOption::new(point, Choice::from(1))
+            }
+            Err(_) => CtOption::new(Self::identity(), Choice::from(0)),
+        }
+    }
+
+    /// Feed the compressed encoding ([GroupEncoding::to_bytes]) into a hasher
+    #[cfg(feature = "digest")]
+    pub fn digest_update(&self, hasher: &mut impl digest::Update) {
+        hasher.update(&<[u8; COMPRESSED_BYTES_SIZE]>::from(self));
+    }
 }
 
 impl Default for G2Projective {
@@ -115,10 +614,10 @@ impl TryFrom<&[u8; UNCOMPRESSED_BYTES_SIZE]> for G2Projective {
             if unsafe { wrapper_g2_is_valid(&g2) } {
                 Ok(Self(g2))
             } else {
-                Err(Error::InvalidBytesRepresentation)
+                Err(Error::NotInSubgroup)
             }
         } else {
-            Err(Error::RelicError(ret))
+            Err(Error::RelicError(ret.into()))
         }
     }
 }
@@ -142,10 +641,10 @@ impl TryFrom<&[u8; COMPRESSED_BYTES_SIZE]> for G2Projective {
             if unsafe { wrapper_g2_is_valid(&g2) } {
                 Ok(Self(g2))
             } else {
-                Err(Error::InvalidBytesRepresentation)
+                Err(Error::NotInSubgroup)
             }
         } else {
-            Err(Error::RelicError(ret))
+            Err(Error::RelicError(ret.into()))
         }
     }
 }
@@ -199,21 +698,41 @@ impl From<&G2Projective> for [u8; COMPRESSED_BYTES_SIZE] {
 impl TryFrom<&[u8]> for G2Projective {
     type Error = Error;
 
+    /// Decode from either the compressed or the uncompressed encoding
+    ///
+    /// Slices of any other length are rejected with
+    /// [Error::InvalidBytesRepresentation].
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        let mut g2 = new_wrapper();
-        let ret = unsafe { wrapper_g2_read_bin(&mut g2, value.as_ptr(), value.len()) };
-        if ret == RLC_OK {
-            if unsafe { wrapper_g2_is_valid(&g2) } {
-                Ok(Self(g2))
-            } else {
-                Err(Error::InvalidBytesRepresentation)
-            }
+        if let Ok(bytes) = <&[u8; COMPRESSED_BYTES_SIZE]>::try_from(value) {
+            Self::try_from(bytes)
+        } else if let Ok(bytes) = <&[u8; UNCOMPRESSED_BYTES_SIZE]>::try_from(value) {
+            Self::try_from(bytes)
         } else {
-            Err(Error::RelicError(ret))
+            Err(Error::InvalidBytesRepresentation)
         }
     }
 }
 
+#[cfg(feature = "alloc")]
+impl TryFrom<Vec<u8>> for G2Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&Vec<u8>> for G2Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl<G> Add<G> for G2Projective
 where
     G: AsRef<Self>,
@@ -223,6 +742,8 @@ where
     #[inline]
     fn add(mut self, rhs: G) -> Self::Output {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g2_add_assign(&mut self.0, &rhs.0);
         }
@@ -240,6 +761,8 @@ where
     fn add(self, rhs: G) -> Self::Output {
         let mut ret = new_wrapper();
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g2_add(&mut ret, &self.0, &rhs.0);
         }
@@ -254,6 +777,8 @@ where
     #[inline]
     fn add_assign(&mut self, rhs: G) {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g2_add_assign(&mut self.0, &rhs.0);
         }
@@ -294,6 +819,8 @@ where
     #[inline]
     fn sub(mut self, rhs: G) -> Self::Output {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g2_sub_assign(&mut self.0, &rhs.0);
         }
@@ -311,6 +838,8 @@ where
     fn sub(self, rhs: G) -> Self::Output {
         let mut ret = new_wrapper();
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g2_sub(&mut ret, &self.0, &rhs.0);
         }
@@ -325,6 +854,8 @@ where
     #[inline]
     fn sub_assign(&mut self, rhs: G) {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
+        rhs.debug_check_valid();
         unsafe {
             wrapper_g2_sub_assign(&mut self.0, &rhs.0);
         }
@@ -359,6 +890,7 @@ where
     #[inline]
     fn mul(mut self, rhs: S) -> Self::Output {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
         unsafe {
             wrapper_g2_mul_assign(&mut self.0, &rhs.0);
         }
@@ -375,6 +907,7 @@ where
     fn mul(self, rhs: S) -> Self::Output {
         let mut g2 = new_wrapper();
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
         unsafe {
             wrapper_g2_mul(&mut g2, &self.0, &rhs.0);
         }
@@ -418,12 +951,49 @@ impl Mul<&G2Projective> for &Scalar {
     }
 }
 
+impl Mul<G2Affine> for Scalar {
+    type Output = G2Projective;
+
+    #[inline]
+    fn mul(self, rhs: G2Affine) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<&G2Affine> for Scalar {
+    type Output = G2Projective;
+
+    #[inline]
+    fn mul(self, rhs: &G2Affine) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<G2Affine> for &Scalar {
+    type Output = G2Projective;
+
+    #[inline]
+    fn mul(self, rhs: G2Affine) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<&G2Affine> for &Scalar {
+    type Output = G2Projective;
+
+    #[inline]
+    fn mul(self, rhs: &G2Affine) -> Self::Output {
+        rhs * self
+    }
+}
+
 impl<S> MulAssign<S> for G2Projective
 where
     S: AsRef<Scalar>,
 {
     fn mul_assign(&mut self, rhs: S) {
         let rhs = rhs.as_ref();
+        self.debug_check_valid();
         unsafe {
             wrapper_g2_mul_assign(&mut self.0, &rhs.0);
         }
@@ -439,6 +1009,87 @@ impl PartialEq for G2Projective {
 
 impl Eq for G2Projective {}
 
+impl_fmt!(
+    G2Projective,
+    "Formatting as hex of the compressed encoding",
+    |g: &G2Projective| <[u8; COMPRESSED_BYTES_SIZE]>::from(g),
+    |g: &G2Projective| <[u8; COMPRESSED_BYTES_SIZE]>::from(g)
+);
+
+/// Parsing from hex of the compressed or uncompressed encoding
+///
+/// The digits may be prefixed with `0x` or `0X`. This is the inverse of the
+/// [core::fmt::Display] implementation.
+///
+/// ```
+/// use bls12_381_relic::{G2Affine, G2Projective};
+/// use bls12_381_relic::group::Group;
+///
+/// let g = G2Projective::generator();
+/// assert_eq!(format!("{g:#x}").parse::<G2Projective>().unwrap(), g);
+/// assert_eq!(G2Affine::try_from(g.to_string().as_str()).unwrap(), g.into());
+/// ```
+impl FromStr for G2Projective {
+    type Err = ParseElementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut buf = [0u8; UNCOMPRESSED_BYTES_SIZE];
+        let bytes = parse_hex(
+            s,
+            &mut buf,
+            &[COMPRESSED_BYTES_SIZE, UNCOMPRESSED_BYTES_SIZE],
+        )?;
+        Self::try_from(bytes).map_err(ParseElementError::InvalidElement)
+    }
+}
+
+impl TryFrom<&str> for G2Projective {
+    type Error = ParseElementError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Hashing via the canonical compressed encoding
+///
+/// Equal elements can have different projective representations. Hence, the
+/// encoding of the elements is hashed to be consistent with [PartialEq].
+impl Hash for G2Projective {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let bytes: [u8; COMPRESSED_BYTES_SIZE] = self.into();
+        bytes.hash(state);
+    }
+}
+
+/// Constant-time equality
+///
+/// The comparison of relic used by [PartialEq] is not constant time. Hence, the
+/// canonical encodings of the elements are compared in constant time instead.
+impl ConstantTimeEq for G2Projective {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let lhs: [u8; COMPRESSED_BYTES_SIZE] = self.into();
+        let rhs: [u8; COMPRESSED_BYTES_SIZE] = other.into();
+        lhs.ct_eq(&rhs)
+    }
+}
+
+/// Selection of the projective coordinates
+///
+/// The coordinates are selected in constant time together with relic's flag
+/// indicating whether they are normalized, hence points in different
+/// representations can be selected.
+impl ConditionallySelectable for G2Projective {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut ret = new_wrapper();
+        unsafe {
+            wrapper_g2_conditional_select(&mut ret, &a.0, &b.0, choice.into());
+        }
+        Self(ret)
+    }
+}
+
 impl GroupEncoding for G2Projective {
     type Repr = GenericArray<u8, CompressedSize>;
 
@@ -488,10 +1139,8 @@ impl UncompressedEncoding for G2Projective {
 impl Group for G2Projective {
     type Scalar = Scalar;
 
-    fn random(mut rng: impl RngCore) -> Self {
-        let mut buf = [0u8; 64];
-        rng.fill_bytes(&mut buf);
-        Self::hash_to_curve(buf, RANDOM_DOMAIN_SEPERATOR)
+    fn random(rng: impl RngCore) -> Self {
+        Self::random_with_dst(rng, RANDOM_DOMAIN_SEPERATOR)
     }
 
     #[inline]
@@ -499,13 +1148,20 @@ impl Group for G2Projective {
         Self::default()
     }
 
+    /// Obtain the generator
+    ///
+    /// With the `std` feature, the generator is obtained from relic on the
+    /// first call and cached afterwards.
     #[inline]
     fn generator() -> Self {
-        let mut value = new_wrapper();
-        unsafe {
-            wrapper_g2_generator(&mut value);
+        #[cfg(feature = "std")]
+        {
+            static GENERATOR: std::sync::OnceLock<G2Projective> = std::sync::OnceLock::new();
+            *GENERATOR.get_or_init(new_generator)
         }
-        Self(value)
+
+        #[cfg(not(feature = "std"))]
+        new_generator()
     }
 
     #[inline]
@@ -525,6 +1181,11 @@ impl Group for G2Projective {
 
 impl PrimeGroup for G2Projective {}
 
+/// Sum of products of points and scalars
+///
+/// The products are evaluated with a simultaneous multiplication if the `alloc`
+/// feature is enabled. [G2Projective::msm] computes the same sum for slices of
+/// points and scalars and is the recommended interface.
 impl<G, S> Sum<(G, S)> for G2Projective
 where
     G: AsRef<G2Projective>,
@@ -545,12 +1206,22 @@ where
         unsafe {
             wrapper_g2_simmul(&mut g2, g2s.as_ptr(), scalars.as_ptr(), g2s.len());
         }
+        zeroize_wrappers(&mut g2s);
+        zeroize_wrappers(&mut scalars);
         g2.into()
     }
 
     #[cfg(not(feature = "alloc"))]
     fn sum<I: Iterator<Item = (G, S)>>(iter: I) -> Self {
-        iter.fold(Self::identity(), |a, (g, s)| a + g.as_ref() * s.as_ref())
+        // accumulate in place and reuse a single temporary for the products
+        let mut sum = Self::identity();
+        let mut product = new_wrapper();
+        iter.for_each(|(g, s)| unsafe {
+            wrapper_g2_mul(&mut product, &g.as_ref().0, &s.as_ref().0);
+            wrapper_g2_add_assign(&mut sum.0, &product);
+        });
+        zeroize_wrappers(core::slice::from_mut(&mut product));
+        sum
     }
 }
 
@@ -575,12 +1246,98 @@ where
         unsafe {
             wrapper_g2_simmul(&mut g2, g2s.as_ptr(), scalars.as_ptr(), g2s.len());
         }
+        zeroize_wrappers(&mut g2s);
+        zeroize_wrappers(&mut scalars);
         g2.into()
     }
 
     #[cfg(not(feature = "alloc"))]
     fn sum<I: Iterator<Item = &'a (G, S)>>(iter: I) -> Self {
-        iter.fold(Self::identity(), |a, (g, s)| a + g.as_ref() * s.as_ref())
+        // accumulate in place and reuse a single temporary for the products
+        let mut sum = Self::identity();
+        let mut product = new_wrapper();
+        iter.for_each(|(g, s)| unsafe {
+            wrapper_g2_mul(&mut product, &g.as_ref().0, &s.as_ref().0);
+            wrapper_g2_add_assign(&mut sum.0, &product);
+        });
+        zeroize_wrappers(core::slice::from_mut(&mut product));
+        sum
+    }
+}
+
+/// Convert an uncompressed encoding of a point to the compressed one
+///
+/// The point is decoded and validated before it is re-encoded.
+pub fn recompress(
+    uncompressed: &[u8; UNCOMPRESSED_BYTES_SIZE],
+) -> Result<[u8; COMPRESSED_BYTES_SIZE], Error> {
+    G2Projective::try_from(uncompressed).map(|g2| g2.into())
+}
+
+/// Convert a compressed encoding of a point to the uncompressed one
+///
+/// The point is decoded and validated before it is re-encoded.
+pub fn decompress(
+    compressed: &[u8; COMPRESSED_BYTES_SIZE],
+) -> Result<[u8; UNCOMPRESSED_BYTES_SIZE], Error> {
+    G2Projective::try_from(compressed).map(|g2| g2.into())
+}
+
+/// Precomputed table for fixed-base scalar multiplications in G2
+///
+/// If many scalar multiplications with the same base are performed, e.g.,
+/// with the generator, precomputing a table of multiples of the base once
+/// speeds up each subsequent multiplication. The size of the table depends on
+/// relic's configuration of fixed-base multiplication. With relic's default
+/// configuration, the table stores 16 points which amounts to roughly 4.7 KiB.
+/// The table is thus allocated on the heap and this type is only available if
+/// the `alloc` feature is enabled.
+///
+/// ```
+/// use bls12_381_relic::{g2::G2FixedBase, G2Projective, Scalar};
+/// use bls12_381_relic::{group::Group, ff::Field};
+///
+/// let fixed_base = G2FixedBase::new();
+/// let s = Scalar::random(rand::thread_rng());
+/// assert_eq!(fixed_base.mul(&s), G2Projective::generator() * s);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct G2FixedBase(Box<wrapper_g2_table_t>);
+
+#[cfg(feature = "alloc")]
+impl G2FixedBase {
+    /// Precompute the table for the generator
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_base(&G2Projective::generator())
+    }
+
+    /// Precompute the table for the given base
+    pub fn with_base(base: &G2Projective) -> Self {
+        let mut table = MaybeUninit::uninit();
+        let table = unsafe {
+            wrapper_g2_mul_pre(table.as_mut_ptr(), &base.0);
+            table.assume_init()
+        };
+        Self(Box::new(table))
+    }
+
+    /// Multiply the base by a scalar using the precomputed table
+    pub fn mul(&self, rhs: &Scalar) -> G2Projective {
+        let mut g2 = new_wrapper();
+        unsafe {
+            wrapper_g2_mul_fix(&mut g2, self.0.as_ref(), &rhs.0);
+        }
+        g2.into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for G2FixedBase {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -652,6 +1409,118 @@ impl From<&Affine<G2Projective>> for wrapper_g2_t {
     }
 }
 
+impl Affine<G2Projective> {
+    /// Decode a point from its compressed encoding
+    ///
+    /// The encoding is the one of relic and consists of 97 bytes: a leading
+    /// byte storing the format and the sign of the y-coordinate followed by the
+    /// big-endian x-coordinate (an element of `Fp2`). Hence, it is one byte
+    /// longer than the encoding used by the `bls12_381` crate, which stores
+    /// these flags in the most significant bits of the x-coordinate.
+    ///
+    /// The point is checked to be in the prime-order subgroup.
+    #[inline]
+    pub fn from_compressed(bytes: &[u8; COMPRESSED_BYTES_SIZE]) -> CtOption<Self> {
+        <Self as GroupEncoding>::from_bytes(GenericArray::from_slice(bytes))
+    }
+
+    /// Decode a point from its uncompressed encoding
+    ///
+    /// The encoding consists of 193 bytes: a leading byte storing the format
+    /// followed by the big-endian coordinates. The point is checked to be in
+    /// the prime-order subgroup.
+    #[inline]
+    pub fn from_uncompressed(bytes: &[u8; UNCOMPRESSED_BYTES_SIZE]) -> CtOption<Self> {
+        <Self as UncompressedEncoding>::from_uncompressed(GenericArray::from_slice(bytes))
+    }
+
+    /// Encode the point in compressed form
+    ///
+    /// See [Self::from_compressed] for the format.
+    #[inline]
+    pub fn to_compressed(&self) -> [u8; COMPRESSED_BYTES_SIZE] {
+        (&self.0).into()
+    }
+
+    /// Encode the point in uncompressed form
+    ///
+    /// See [Self::from_uncompressed] for the format.
+    #[inline]
+    pub fn to_uncompressed(&self) -> [u8; UNCOMPRESSED_BYTES_SIZE] {
+        (&self.0).into()
+    }
+
+    /// Feed the compressed encoding ([GroupEncoding::to_bytes]) into a hasher
+    #[cfg(feature = "digest")]
+    #[inline]
+    pub fn digest_update(&self, hasher: &mut impl digest::Update) {
+        self.0.digest_update(hasher);
+    }
+
+    /// Read a point in compressed form from a reader
+    ///
+    /// Exactly [COMPRESSED_BYTES_SIZE] bytes are read and decoded as with
+    /// [Self::from_compressed]. If the reader ends early, an error of kind
+    /// [std::io::ErrorKind::UnexpectedEof] is returned. Invalid points are
+    /// reported as [std::io::ErrorKind::InvalidData].
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut impl std::io::Read) -> std::io::Result<Self> {
+        let mut bytes = [0u8; COMPRESSED_BYTES_SIZE];
+        reader.read_exact(&mut bytes)?;
+        Self::decode_read(&bytes)
+    }
+
+    /// Read points in compressed form from a reader until it ends
+    ///
+    /// The points are read one after another as with [Self::read_from]
+    /// without reading the whole input into memory first. The iterator ends
+    /// after the first error.
+    ///
+    /// ```
+    /// use bls12_381_relic::{G2Affine, G2Projective};
+    /// use bls12_381_relic::group::{Curve, Group};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let points = [G2Projective::random(&mut rng).to_affine(), G2Projective::random(&mut rng).to_affine()];
+    /// let bytes: Vec<u8> = points.iter().flat_map(|p| p.to_compressed()).collect();
+    ///
+    /// let read: Vec<_> = G2Affine::read_many(bytes.as_slice())
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(read, points);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn read_many<R>(mut reader: R) -> impl Iterator<Item = std::io::Result<Self>>
+    where
+        R: std::io::Read,
+    {
+        let mut done = false;
+        core::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            let mut bytes = [0u8; COMPRESSED_BYTES_SIZE];
+            let point = match crate::read_exact_or_eof(&mut reader, &mut bytes) {
+                Ok(true) => Self::decode_read(&bytes),
+                Ok(false) => {
+                    done = true;
+                    return None;
+                }
+                Err(err) => Err(err),
+            };
+            done = point.is_err();
+            Some(point)
+        })
+    }
+
+    #[cfg(feature = "std")]
+    fn decode_read(bytes: &[u8; COMPRESSED_BYTES_SIZE]) -> std::io::Result<Self> {
+        Option::from(Self::from_compressed(bytes))
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid point"))
+    }
+}
+
 impl GroupEncoding for Affine<G2Projective> {
     type Repr = <G2Projective as GroupEncoding>::Repr;
 
@@ -699,46 +1568,310 @@ impl UncompressedEncoding for Affine<G2Projective> {
     }
 }
 
-#[cfg(feature = "zeroize")]
-impl zeroize::Zeroize for G2Projective {
-    fn zeroize(&mut self) {
-        unsafe {
-            wrapper_g2_neutral(&mut self.0);
-        }
+/// Compressed encoding of a point in G2
+///
+/// This type wraps the encoded bytes to keep track of their meaning. Decoding
+/// is deferred until the point is converted into [G2Affine] or [G2Projective].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct G2Compressed([u8; COMPRESSED_BYTES_SIZE]);
+
+impl G2Compressed {
+    /// Obtain the encoded bytes
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; COMPRESSED_BYTES_SIZE] {
+        &self.0
     }
 }
 
-#[cfg(feature = "serde")]
-impl serde::Serialize for G2Projective {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        crate::serde_helpers::serialize(self, serializer)
+impl AsRef<[u8]> for G2Compressed {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
     }
 }
 
-#[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for G2Projective {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        crate::serde_helpers::deserialize(deserializer)
+impl From<[u8; COMPRESSED_BYTES_SIZE]> for G2Compressed {
+    #[inline]
+    fn from(value: [u8; COMPRESSED_BYTES_SIZE]) -> Self {
+        Self(value)
     }
 }
 
-#[cfg(test)]
-mod test {
-    use pairing::group::ff::Field;
+impl From<G2Compressed> for [u8; COMPRESSED_BYTES_SIZE] {
+    #[inline]
+    fn from(value: G2Compressed) -> Self {
+        value.0
+    }
+}
 
-    use super::*;
+impl From<&G2Projective> for G2Compressed {
+    #[inline]
+    fn from(value: &G2Projective) -> Self {
+        Self(value.into())
+    }
+}
 
-    #[test]
+impl From<G2Projective> for G2Compressed {
+    #[inline]
+    fn from(value: G2Projective) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&G2Affine> for G2Compressed {
+    #[inline]
+    fn from(value: &G2Affine) -> Self {
+        Self::from(&value.0)
+    }
+}
+
+impl From<G2Affine> for G2Compressed {
+    #[inline]
+    fn from(value: G2Affine) -> Self {
+        Self::from(&value.0)
+    }
+}
+
+impl TryFrom<&G2Compressed> for G2Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G2Compressed) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<G2Compressed> for G2Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G2Compressed) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<&G2Compressed> for G2Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G2Compressed) -> Result<Self, Self::Error> {
+        G2Projective::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<G2Compressed> for G2Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G2Compressed) -> Result<Self, Self::Error> {
+        G2Projective::try_from(value).map(Self::from)
+    }
+}
+
+/// Uncompressed encoding of a point in G2
+///
+/// This type wraps the encoded bytes to keep track of their meaning. Decoding
+/// is deferred until the point is converted into [G2Affine] or [G2Projective].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct G2Uncompressed([u8; UNCOMPRESSED_BYTES_SIZE]);
+
+impl G2Uncompressed {
+    /// Obtain the encoded bytes
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; UNCOMPRESSED_BYTES_SIZE] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for G2Uncompressed {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; UNCOMPRESSED_BYTES_SIZE]> for G2Uncompressed {
+    #[inline]
+    fn from(value: [u8; UNCOMPRESSED_BYTES_SIZE]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<G2Uncompressed> for [u8; UNCOMPRESSED_BYTES_SIZE] {
+    #[inline]
+    fn from(value: G2Uncompressed) -> Self {
+        value.0
+    }
+}
+
+impl From<&G2Projective> for G2Uncompressed {
+    #[inline]
+    fn from(value: &G2Projective) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<G2Projective> for G2Uncompressed {
+    #[inline]
+    fn from(value: G2Projective) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&G2Affine> for G2Uncompressed {
+    #[inline]
+    fn from(value: &G2Affine) -> Self {
+        Self::from(&value.0)
+    }
+}
+
+impl From<G2Affine> for G2Uncompressed {
+    #[inline]
+    fn from(value: G2Affine) -> Self {
+        Self::from(&value.0)
+    }
+}
+
+impl TryFrom<&G2Uncompressed> for G2Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G2Uncompressed) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<G2Uncompressed> for G2Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G2Uncompressed) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<&G2Uncompressed> for G2Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G2Uncompressed) -> Result<Self, Self::Error> {
+        G2Projective::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<G2Uncompressed> for G2Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G2Uncompressed) -> Result<Self, Self::Error> {
+        G2Projective::try_from(value).map(Self::from)
+    }
+}
+
+/// Sampling with [rand::Rng::gen] via [Group::random]
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<G2Projective> for rand::distributions::Standard {
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> G2Projective {
+        G2Projective::random(rng)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for G2Projective {
+    fn zeroize(&mut self) {
+        zeroize_wrappers(core::slice::from_mut(&mut self.0));
+        unsafe {
+            wrapper_g2_neutral(&mut self.0);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for G2Projective {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serde_helpers::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G2Projective {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde_helpers::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pairing::group::ff::Field;
+
+    use super::*;
+
+    #[test]
+    fn random_fast() {
+        let mut rng = rand::thread_rng();
+        let g = G2Projective::random_fast(&mut rng);
+        assert!(!bool::from(g.is_identity()));
+        assert_ne!(g, G2Projective::random_fast(&mut rng));
+    }
+
+    #[cfg(all(feature = "checked-arithmetic", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "arithmetic on invalid G2 point")]
+    fn checked_arithmetic() {
+        let mut g = G2Projective::random_fast(rand::thread_rng());
+        g.0[0].y[0][0] ^= 1;
+        assert!(!g.is_valid());
+        let _ = g + G2Projective::generator();
+    }
+
+    #[test]
+    fn from_uniform_bytes() {
+        let seed = [0x42; 64];
+        let v = G2Projective::from_uniform_bytes(&seed, b"dst");
+        assert_eq!(v, G2Projective::from_uniform_bytes(&seed, b"dst"));
+        assert!(v.is_valid());
+        assert_ne!(v, G2Projective::from_uniform_bytes(&seed, b"other dst"));
+        assert_ne!(v, G2Projective::from_uniform_bytes(&[0x43; 64], b"dst"));
+
+        let mut rng = rand::thread_rng();
+        assert_ne!(
+            G2Projective::random_with_dst(&mut rng, b"dst"),
+            G2Projective::random_with_dst(&mut rng, b"dst")
+        );
+    }
+
+    #[test]
+    fn double_n() {
+        let mut rng = rand::thread_rng();
+        let g = G2Projective::random_fast(&mut rng);
+        assert_eq!(g.double_n(0), g);
+        assert_eq!(g.double_n(1), g.double());
+        assert_eq!(g.double_n(3), g.double().double().double());
+        assert_eq!(g.double_n(40), g * Scalar::from(1u64 << 40));
+        assert!(bool::from(
+            G2Projective::identity().double_n(5).is_identity()
+        ));
+    }
+
+    #[test]
     fn generator() {
         let generator = G2Projective::generator();
         let identity = G2Projective::identity();
         assert_ne!(generator, identity);
+        assert_eq!(generator, new_generator());
+        assert_eq!(G2Projective::generator(), generator);
+        #[cfg(feature = "std")]
+        assert_eq!(
+            std::thread::spawn(G2Projective::generator).join().unwrap(),
+            generator
+        );
     }
 
     #[test]
@@ -765,10 +1898,19 @@ mod test {
         let ra1 = &a1;
         let ra2 = &a2;
         assert_eq!(check, ra1 + ra2);
+        assert_eq!(check, a1 + ra2);
+        assert_eq!(check, ra1 + a2);
         assert_eq!(check, ra1 + v2);
         assert_eq!(check, v1 + ra2);
         assert_eq!(check, ra1 + rv2);
         assert_eq!(check, rv1 + ra2);
+
+        let mut acc = v1;
+        acc += a2;
+        assert_eq!(check, acc);
+        let mut acc = G2Projective::from(a1);
+        acc += ra2;
+        assert_eq!(check, acc);
     }
 
     #[test]
@@ -795,10 +1937,94 @@ mod test {
         let ra1 = &a1;
         let ra2 = &a2;
         assert_eq!(check, ra1 - ra2);
+        assert_eq!(check, a1 - ra2);
+        assert_eq!(check, ra1 - a2);
         assert_eq!(check, ra1 - v2);
         assert_eq!(check, v1 - ra2);
         assert_eq!(check, ra1 - rv2);
         assert_eq!(check, rv1 - ra2);
+
+        let mut acc = v1;
+        acc -= a2;
+        assert_eq!(check, acc);
+        let mut acc = G2Projective::from(a1);
+        acc -= ra2;
+        assert_eq!(check, acc);
+    }
+
+    #[test]
+    fn mul_u64() {
+        let mut rng = rand::thread_rng();
+        let g = G2Projective::random(&mut rng);
+        for scalar in [0, 1, 2, 3, 5, 1 << 20, u64::MAX] {
+            assert_eq!(g.mul_u64(scalar), g * Scalar::from(scalar));
+        }
+        assert_eq!(
+            G2Projective::identity().mul_u64(5),
+            G2Projective::identity()
+        );
+    }
+
+    #[test]
+    fn mul_add() {
+        let mut rng = rand::thread_rng();
+        let g = G2Projective::random(&mut rng);
+        let h = G2Projective::random(&mut rng);
+        let s = Scalar::random(&mut rng);
+
+        assert_eq!(g.mul_add(&s, &h), g * s + h);
+        assert_eq!(g.mul_add(&Scalar::ZERO, &h), h);
+        assert_eq!(g.mul_add(&Scalar::ONE, &-g), G2Projective::identity());
+        assert_eq!(G2Projective::identity().mul_add(&s, &h), h);
+        assert_eq!(g.mul_add(&s, &G2Projective::identity()), g * s);
+        assert_eq!(g.mul_add(&s, &g), g * (s + Scalar::ONE));
+    }
+
+    #[test]
+    fn mul_generator() {
+        let mut rng = rand::thread_rng();
+        for s in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::random(&mut rng),
+            Scalar::random(&mut rng),
+        ] {
+            assert_eq!(
+                G2Projective::mul_generator(&s),
+                G2Projective::generator() * s
+            );
+        }
+    }
+
+    #[test]
+    fn affine_operands() {
+        // only the bounds are checked here, the results by the add and sub tests
+        fn assert_ops<A>()
+        where
+            A: Add<A, Output = G2Projective> + Sub<A, Output = G2Projective>,
+            A: for<'a> Add<&'a A, Output = G2Projective>
+                + for<'a> Sub<&'a A, Output = G2Projective>,
+            for<'a> &'a A: Add<A, Output = G2Projective> + Sub<A, Output = G2Projective>,
+            for<'a> &'a A: Add<&'a A, Output = G2Projective> + Sub<&'a A, Output = G2Projective>,
+        {
+        }
+        assert_ops::<G2Affine>();
+    }
+
+    #[test]
+    fn neg() {
+        let v = G2Projective::random(rand::thread_rng());
+        let check = G2Projective::identity() - v;
+
+        let rv = &v;
+        assert_eq!(check, -v);
+        assert_eq!(check, -rv);
+
+        let a = v.to_affine();
+        let ra = &a;
+        assert_eq!(G2Affine::from(check), -a);
+        assert_eq!(G2Affine::from(check), -ra);
     }
 
     #[test]
@@ -823,10 +2049,10 @@ mod test {
         assert_eq!(check, ra * s);
         assert_eq!(check, ra * rs);
         assert_eq!(check, a * rs);
-        // assert_eq!(check, s * a);
-        // assert_eq!(check, rs * a);
-        // assert_eq!(check, rs * ra);
-        // assert_eq!(check, s * ra);
+        assert_eq!(check, s * a);
+        assert_eq!(check, rs * a);
+        assert_eq!(check, rs * ra);
+        assert_eq!(check, s * ra);
 
         let mut mv = v;
         mv *= s;
@@ -850,6 +2076,285 @@ mod test {
         assert_eq!(G2Projective::sum([(v1, s1), (v2, s2)].into_iter()), check);
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ord() {
+        let mut rng = rand::thread_rng();
+        let mut points: Vec<_> = (0..16)
+            .map(|_| G2Projective::random_fast(&mut rng).to_affine())
+            .collect();
+        points.push(points[3]);
+        points.sort();
+
+        for pair in points.windows(2) {
+            assert!(pair[0].to_compressed() <= pair[1].to_compressed());
+            assert_eq!(pair[0] == pair[1], pair[0].cmp(&pair[1]).is_eq());
+            assert_eq!(pair[1].cmp(&pair[0]), pair[0].cmp(&pair[1]).reverse());
+        }
+        assert_eq!(
+            points.windows(2).filter(|pair| pair[0] == pair[1]).count(),
+            1
+        );
+
+        let mut shuffled = points.clone();
+        shuffled.reverse();
+        shuffled.sort();
+        assert_eq!(shuffled, points);
+    }
+
+    #[test]
+    fn sum_of_products() {
+        let mut rng = rand::thread_rng();
+        let terms: [(G2Projective, Scalar); 4] = core::array::from_fn(|_| {
+            (
+                G2Projective::random_fast(&mut rng),
+                Scalar::random(&mut rng),
+            )
+        });
+        let check = terms
+            .iter()
+            .fold(G2Projective::identity(), |acc, (g, s)| acc + g * s);
+
+        assert_eq!(terms.iter().sum::<G2Projective>(), check);
+        assert_eq!(terms.into_iter().sum::<G2Projective>(), check);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fixed_base() {
+        let mut rng = rand::thread_rng();
+        let s = Scalar::random(&mut rng);
+
+        let fixed_base = G2FixedBase::new();
+        assert_eq!(fixed_base.mul(&s), G2Projective::generator() * s);
+        assert_eq!(fixed_base.mul(&Scalar::ZERO), G2Projective::identity());
+
+        let base = G2Projective::random(&mut rng);
+        let fixed_base = G2FixedBase::with_base(&base);
+        assert_eq!(fixed_base.mul(&s), base * s);
+    }
+
+    #[test]
+    fn multiexp_array() {
+        let mut rng = rand::thread_rng();
+        let points: [G2Projective; 3] = core::array::from_fn(|_| G2Projective::random(&mut rng));
+        let scalars: [Scalar; 3] = core::array::from_fn(|_| Scalar::random(&mut rng));
+
+        let check = points
+            .iter()
+            .zip(scalars.iter())
+            .fold(G2Projective::identity(), |acc, (p, s)| acc + p * s);
+        assert_eq!(G2Projective::multiexp_array(&points, &scalars), check);
+    }
+
+    #[test]
+    fn msm() {
+        let mut rng = rand::thread_rng();
+        let points: [G2Projective; 5] = core::array::from_fn(|_| G2Projective::random(&mut rng));
+        let scalars: [Scalar; 5] = core::array::from_fn(|_| Scalar::random(&mut rng));
+        let affine_points: [G2Affine; 5] = core::array::from_fn(|i| points[i].to_affine());
+
+        let check = points
+            .iter()
+            .zip(scalars.iter())
+            .fold(G2Projective::identity(), |acc, (p, s)| acc + p * s);
+        assert_eq!(G2Projective::msm(&affine_points, &scalars), check);
+        assert_eq!(
+            G2Projective::msm(&affine_points[..1], &scalars[..1]),
+            points[0] * scalars[0]
+        );
+        assert_eq!(G2Projective::msm(&[], &[]), G2Projective::identity());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn msm_pippenger() {
+        let mut rng = rand::thread_rng();
+        let points: Vec<_> = (0..40).map(|_| G2Projective::random(&mut rng)).collect();
+        let scalars: Vec<_> = (0..40).map(|_| Scalar::random(&mut rng)).collect();
+        let affine_points: Vec<_> = points.iter().map(|p| p.to_affine()).collect();
+
+        let check = G2Projective::msm(&affine_points, &scalars);
+        assert_eq!(
+            G2Projective::msm_pippenger(&affine_points, &scalars, None),
+            check
+        );
+        for window_bits in [1, 4, 7] {
+            assert_eq!(
+                G2Projective::msm_pippenger(&affine_points, &scalars, Some(window_bits)),
+                check
+            );
+        }
+
+        let edge_scalars = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE];
+        assert_eq!(
+            G2Projective::msm_pippenger(&affine_points[..3], &edge_scalars, Some(5)),
+            points[1] - points[2]
+        );
+        assert_eq!(
+            G2Projective::msm_pippenger(&[], &[], None),
+            G2Projective::identity()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn msm_pippenger_window_too_large() {
+        G2Projective::msm_pippenger(&[], &[], Some(17));
+    }
+
+    #[test]
+    #[should_panic]
+    fn msm_different_lengths() {
+        let mut rng = rand::thread_rng();
+        let points = [G2Projective::random(&mut rng).to_affine()];
+        G2Projective::msm(&points, &[]);
+    }
+
+    #[test]
+    fn sum_of_products_edge_cases() {
+        let mut rng = rand::thread_rng();
+        let v = G2Projective::random(&mut rng);
+        let s = Scalar::random(&mut rng);
+        let empty: [(G2Projective, Scalar); 0] = [];
+
+        assert_eq!(
+            core::iter::empty::<(G2Projective, Scalar)>().sum::<G2Projective>(),
+            G2Projective::identity()
+        );
+        assert_eq!(empty.iter().sum::<G2Projective>(), G2Projective::identity());
+        assert_eq!(
+            G2Projective::multiexp_array::<0>(&[], &[]),
+            G2Projective::identity()
+        );
+
+        assert_eq!(core::iter::once((v, s)).sum::<G2Projective>(), v * s);
+        assert_eq!([(v, s)].iter().sum::<G2Projective>(), v * s);
+        assert_eq!(G2Projective::multiexp_array(&[v], &[s]), v * s);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn multi_mul_parallel() {
+        let mut rng = rand::thread_rng();
+        for len in [0, 1, 7, 64] {
+            let points: Vec<_> = (0..len).map(|_| G2Projective::random(&mut rng)).collect();
+            let scalars: Vec<_> = (0..len).map(|_| Scalar::random(&mut rng)).collect();
+
+            let check = points
+                .iter()
+                .zip(scalars.iter())
+                .fold(G2Projective::identity(), |acc, (p, s)| acc + p * s);
+            assert_eq!(G2Projective::multi_mul_parallel(&points, &scalars), check);
+        }
+    }
+
+    #[test]
+    fn format() {
+        let g = G2Projective::generator();
+        let hex: String = <[u8; COMPRESSED_BYTES_SIZE]>::from(g)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+
+        assert_eq!(format!("{g}"), hex);
+        assert_eq!(format!("{g:x}"), hex);
+        assert_eq!(format!("{g:#X}"), format!("0x{}", hex.to_uppercase()));
+        assert_eq!(format!("{g:?}"), format!("G2Projective(0x{hex})"));
+        assert_eq!(format!("{}", G2Affine::from(g)), hex);
+    }
+
+    #[test]
+    fn parse() {
+        let g = G2Projective::generator();
+        let uncompressed: String = <[u8; UNCOMPRESSED_BYTES_SIZE]>::from(g)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+
+        assert_eq!(g.to_string().parse::<G2Projective>().unwrap(), g);
+        assert_eq!(format!("{g:#x}").parse::<G2Projective>().unwrap(), g);
+        assert_eq!(format!("{g:#X}").parse::<G2Projective>().unwrap(), g);
+        assert_eq!(uncompressed.parse::<G2Projective>().unwrap(), g);
+        assert_eq!(
+            G2Projective::try_from(format!("0X{uncompressed}").as_str()).unwrap(),
+            g
+        );
+        assert_eq!(
+            G2Affine::try_from(g.to_string().as_str()).unwrap(),
+            G2Affine::from(g)
+        );
+        assert_eq!(
+            G2Projective::identity()
+                .to_string()
+                .parse::<G2Projective>()
+                .unwrap(),
+            G2Projective::identity()
+        );
+
+        let hex = g.to_string();
+        assert!(matches!(
+            hex[2..].parse::<G2Projective>(),
+            Err(ParseElementError::InvalidLength(len)) if len == hex.len() - 2
+        ));
+        assert!(matches!(
+            hex[1..].parse::<G2Projective>(),
+            Err(ParseElementError::InvalidLength(_))
+        ));
+        assert!(matches!(
+            "".parse::<G2Projective>(),
+            Err(ParseElementError::InvalidLength(0))
+        ));
+        assert!(matches!(
+            format!("{}g", &hex[1..]).parse::<G2Projective>(),
+            Err(ParseElementError::InvalidDigit('g'))
+        ));
+        assert!(matches!(
+            "ff".repeat(COMPRESSED_BYTES_SIZE).parse::<G2Projective>(),
+            Err(ParseElementError::InvalidElement(_))
+        ));
+    }
+
+    #[test]
+    fn hash_set() {
+        let g = G2Projective::generator();
+        let set: std::collections::HashSet<_> = [
+            g.double(),
+            g + g,
+            g * Scalar::from(2),
+            G2Projective::from(G2Affine::from(g.double())),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[cfg(feature = "xmd")]
+    #[test]
+    fn hash_chunks() {
+        let msg = b"this is a message split into multiple parts";
+        let dst = b"BLS12381G2_XMD:SHA-256_SSWU_RO_TESTGEN";
+        let check = G2Projective::hash_to_curve(msg, dst);
+
+        assert_eq!(G2Projective::hash_to_curve_chunks(&[msg], dst), check);
+        assert_eq!(
+            G2Projective::hash_to_curve_chunks(&[&msg[..4], b"", &msg[4..20], &msg[20..]], dst),
+            check
+        );
+        assert_eq!(
+            G2Projective::hash_to_curve_chunks(&[], dst),
+            G2Projective::hash_to_curve(b"", dst)
+        );
+
+        // DSTs longer than 255 bytes are hashed first
+        let long_dst = [b'a'; 300];
+        assert_eq!(
+            G2Projective::hash_to_curve_chunks(&[msg], &long_dst),
+            G2Projective::hash_to_curve(msg, &long_dst)
+        );
+    }
+
     #[test]
     fn hash() {
         let h1 = G2Projective::hash_to_curve(b"1", b"dst");
@@ -858,6 +2363,110 @@ mod test {
         assert_ne!(h1, h2);
     }
 
+    #[test]
+    fn constant_time() {
+        let mut rng = rand::thread_rng();
+        let v1 = G2Projective::random(&mut rng);
+        let v2 = G2Projective::random(&mut rng);
+
+        assert_eq!(v1.ct_eq(&v1).unwrap_u8(), 1);
+        assert_eq!(v1.ct_eq(&(v1 + v2 - v2)).unwrap_u8(), 1);
+        assert_eq!(v1.ct_eq(&v2).unwrap_u8(), 0);
+
+        assert_eq!(
+            G2Projective::conditional_select(&v1, &v2, Choice::from(0)),
+            v1
+        );
+        assert_eq!(
+            G2Projective::conditional_select(&v1, &v2, Choice::from(1)),
+            v2
+        );
+    }
+
+    #[test]
+    fn conditional_select_representations() {
+        let mut rng = rand::thread_rng();
+        let v1 = G2Projective::random(&mut rng);
+        let v2 = G2Projective::random(&mut rng);
+        // normalized coordinates
+        let v1_normalized = G2Projective::from_bytes(&v1.to_bytes()).unwrap();
+        // coordinates that are not normalized
+        let v2_projective = v2.double() - v2;
+
+        for (choice, expected) in [(0, v1), (1, v2)] {
+            let selected = G2Projective::conditional_select(
+                &v1_normalized,
+                &v2_projective,
+                Choice::from(choice),
+            );
+            assert_eq!(selected, expected);
+            assert_eq!(selected + v1, expected + v1);
+            assert!(selected.is_valid());
+            let selected = G2Projective::conditional_select(
+                &v2_projective,
+                &v1_normalized,
+                Choice::from(1 - choice),
+            );
+            assert_eq!(selected, expected);
+            assert_eq!(selected.double(), expected.double());
+        }
+    }
+
+    #[test]
+    fn zcash_format() {
+        let mut rng = rand::thread_rng();
+        let s = Scalar::random(&mut rng);
+        let mut s_bytes = s.to_bytes();
+        s_bytes.reverse();
+        let s_ref = bls12_381::Scalar::from_bytes(&s_bytes).unwrap();
+
+        for (v, v_ref) in [
+            (G2Projective::identity(), bls12_381::G2Affine::identity()),
+            (G2Projective::generator(), bls12_381::G2Affine::generator()),
+            (
+                G2Projective::generator() * s,
+                bls12_381::G2Affine::from(bls12_381::G2Affine::generator() * s_ref),
+            ),
+            (
+                -G2Projective::generator() * s,
+                bls12_381::G2Affine::from(-bls12_381::G2Affine::generator() * s_ref),
+            ),
+        ] {
+            let bytes = v.to_bytes_zcash();
+            assert_eq!(bytes, v_ref.to_compressed());
+            assert_eq!(G2Projective::from_bytes_zcash(&bytes).unwrap(), v);
+        }
+
+        let mut bytes = G2Projective::generator().to_bytes_zcash();
+        bytes[0] &= !zcash::FLAG_COMPRESSED;
+        assert!(bool::from(G2Projective::from_bytes_zcash(&bytes).is_none()));
+
+        let mut bytes = G2Projective::identity().to_bytes_zcash();
+        bytes[COORDINATE_BYTES_SIZE - 1] = 1;
+        assert!(bool::from(G2Projective::from_bytes_zcash(&bytes).is_none()));
+    }
+
+    #[test]
+    fn affine_encoding() {
+        let mut rng = rand::thread_rng();
+        let v = G2Affine::from(G2Projective::random(&mut rng));
+
+        let compressed = v.to_compressed();
+        assert_eq!(compressed, <[u8; COMPRESSED_BYTES_SIZE]>::from(v.0));
+        assert_eq!(G2Affine::from_compressed(&compressed).unwrap(), v);
+
+        let uncompressed = v.to_uncompressed();
+        assert_eq!(uncompressed, <[u8; UNCOMPRESSED_BYTES_SIZE]>::from(v.0));
+        assert_eq!(G2Affine::from_uncompressed(&uncompressed).unwrap(), v);
+
+        assert!(bool::from(
+            G2Affine::from_compressed(&[0xff; COMPRESSED_BYTES_SIZE]).is_none()
+        ));
+        assert!(bool::from(
+            G2Affine::from_uncompressed(&[0xff; UNCOMPRESSED_BYTES_SIZE]).is_none()
+        ));
+    }
+
     #[test]
     fn bytes() {
         let mut rng = rand::thread_rng();
@@ -880,6 +2489,182 @@ mod test {
         assert_eq!(v1, v2);
     }
 
+    #[test]
+    fn recompress_decompress() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..8 {
+            let v = G2Projective::random(&mut rng);
+            let compressed: [u8; COMPRESSED_BYTES_SIZE] = v.into();
+            let uncompressed: [u8; UNCOMPRESSED_BYTES_SIZE] = v.into();
+
+            assert_eq!(recompress(&uncompressed).unwrap(), compressed);
+            assert_eq!(decompress(&compressed).unwrap(), uncompressed);
+        }
+
+        assert!(recompress(&[0xff; UNCOMPRESSED_BYTES_SIZE]).is_err());
+        assert!(decompress(&[0xff; COMPRESSED_BYTES_SIZE]).is_err());
+    }
+
+    #[test]
+    fn encoded_newtypes() {
+        let mut rng = rand::thread_rng();
+        let v = G2Projective::random(&mut rng);
+        let a = G2Affine::from(v);
+
+        let compressed = G2Compressed::from(a);
+        assert_eq!(compressed, G2Compressed::from(v));
+        assert_eq!(
+            compressed.as_bytes(),
+            &<[u8; COMPRESSED_BYTES_SIZE]>::from(v)
+        );
+        assert_eq!(compressed.as_ref(), &compressed.as_bytes()[..]);
+        assert_eq!(G2Affine::try_from(compressed).unwrap(), a);
+        assert_eq!(G2Projective::try_from(&compressed).unwrap(), v);
+
+        let uncompressed = G2Uncompressed::from(a);
+        assert_eq!(uncompressed, G2Uncompressed::from(v));
+        assert_eq!(
+            uncompressed.as_bytes(),
+            &<[u8; UNCOMPRESSED_BYTES_SIZE]>::from(v)
+        );
+        assert_eq!(G2Affine::try_from(&uncompressed).unwrap(), a);
+        assert_eq!(G2Projective::try_from(uncompressed).unwrap(), v);
+
+        assert!(G2Affine::try_from(G2Compressed::from([0xff; COMPRESSED_BYTES_SIZE])).is_err());
+        assert!(G2Affine::try_from(G2Uncompressed::from([0xff; UNCOMPRESSED_BYTES_SIZE])).is_err());
+    }
+
+    #[test]
+    fn affine_coordinates_and_cofactor() {
+        let g = G2Projective::generator();
+        let bytes: [u8; UNCOMPRESSED_BYTES_SIZE] = g.into();
+        let x: [u8; COORDINATE_BYTES_SIZE] = bytes[1..=COORDINATE_BYTES_SIZE].try_into().unwrap();
+        let y: [u8; COORDINATE_BYTES_SIZE] = bytes[COORDINATE_BYTES_SIZE + 1..].try_into().unwrap();
+        assert_eq!(
+            G2Projective::from_affine_coordinates_unchecked(&x, &y).unwrap(),
+            g
+        );
+
+        let v = G2Projective::generator().mul_by_cofactor();
+        assert!(v.is_valid());
+        assert!(!bool::from(v.is_identity()));
+
+        // (2, y) is on the curve and its order is a multiple of the group order
+        let mut x = [0u8; COORDINATE_BYTES_SIZE];
+        x[COORDINATE_BYTES_SIZE / 2 - 1] = 2;
+        let y: [u8; COORDINATE_BYTES_SIZE] = core::array::from_fn(|i| {
+            u8::from_str_radix(
+                &"013a59858b6809fca4d9a3b6539246a70051a3c88899964a42bc9a69cf9acdd9dd387cfa9086b894185b9a46a402be7302d27e0ec3356299a346a09ad7dc4ef68a483c3aed53f9139d2f929a3eecebf72082e5e58c6da24ee32e03040c406d4f"[2 * i..2 * i + 2],
+                16,
+            )
+            .unwrap()
+        });
+        let v = G2Projective::from_affine_coordinates_unchecked(&x, &y).unwrap();
+        assert!(!v.is_valid());
+        assert!(matches!(
+            G2Projective::try_from(&<[u8; UNCOMPRESSED_BYTES_SIZE]>::from(v)),
+            Err(Error::NotInSubgroup)
+        ));
+
+        let v = v.mul_by_cofactor();
+        assert!(v.is_valid());
+        assert!(!bool::from(v.is_identity()));
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let mut rng = rand::thread_rng();
+        let v = G2Projective::random(&mut rng);
+        let compressed: [u8; COMPRESSED_BYTES_SIZE] = v.into();
+        let uncompressed: [u8; UNCOMPRESSED_BYTES_SIZE] = v.into();
+        assert_eq!(G2Projective::try_from(&compressed[..]).unwrap(), v);
+        assert_eq!(G2Projective::try_from(&uncompressed[..]).unwrap(), v);
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(G2Projective::try_from(&compressed.to_vec()).unwrap(), v);
+            assert_eq!(G2Projective::try_from(uncompressed.to_vec()).unwrap(), v);
+        }
+
+        let bytes = [0u8; 200 + 1];
+        for len in 0..=bytes.len() {
+            if len == COMPRESSED_BYTES_SIZE || len == UNCOMPRESSED_BYTES_SIZE {
+                continue;
+            }
+            assert!(matches!(
+                G2Projective::try_from(&bytes[..len]),
+                Err(Error::InvalidBytesRepresentation)
+            ));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_from() {
+        use std::io::ErrorKind;
+
+        let mut rng = rand::thread_rng();
+        let points: Vec<_> = (0..10)
+            .map(|_| G2Projective::random(&mut rng).to_affine())
+            .collect();
+        let mut bytes = Vec::new();
+        for point in &points {
+            bytes.extend_from_slice(&point.to_compressed());
+        }
+
+        let mut reader = bytes.as_slice();
+        for point in &points {
+            assert_eq!(G2Affine::read_from(&mut reader).unwrap(), *point);
+        }
+        assert_eq!(
+            G2Affine::read_from(&mut reader).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+
+        let read: Vec<_> = G2Affine::read_many(bytes.as_slice())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read, points);
+        assert_eq!(G2Affine::read_many(&[][..]).count(), 0);
+
+        // truncated input
+        let mut results = G2Affine::read_many(&bytes[..bytes.len() - 1]);
+        assert_eq!(results.by_ref().take(9).filter(|r| r.is_ok()).count(), 9);
+        assert_eq!(
+            results.next().unwrap().unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+        assert!(results.next().is_none());
+        drop(results);
+
+        // invalid point
+        bytes[COMPRESSED_BYTES_SIZE..2 * COMPRESSED_BYTES_SIZE].fill(0xff);
+        let mut results = G2Affine::read_many(bytes.as_slice());
+        assert!(results.next().unwrap().is_ok());
+        assert_eq!(
+            results.next().unwrap().unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert!(results.next().is_none());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut g = G2Projective::random(rand::thread_rng());
+        g.zeroize();
+        let point = &g.0[0];
+        assert!(point
+            .x
+            .iter()
+            .chain(point.y.iter())
+            .chain(point.z.iter())
+            .flatten()
+            .all(|limb| *limb == 0));
+        assert_eq!(g, G2Projective::identity());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_serialization() {
@@ -896,5 +2681,25 @@ mod test {
 
         let abytes = bincode::serialize(&a1).unwrap();
         assert_eq!(bytes, abytes);
+
+        let json = serde_json::to_string(&v1).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                "\"{}\"",
+                v1.to_bytes()
+                    .iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<String>()
+            )
+        );
+        let v3: G2Projective = serde_json::from_str(&json).unwrap();
+        assert_eq!(v2, v3);
+        let a3: G2Affine = serde_json::from_str(&json).unwrap();
+        assert_eq!(a2, a3);
+        assert_eq!(json, serde_json::to_string(&a1).unwrap());
+
+        assert!(serde_json::from_str::<G2Projective>("\"00\"").is_err());
+        assert!(serde_json::from_str::<G2Projective>("\"not hex\"").is_err());
     }
 }
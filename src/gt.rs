@@ -1,33 +1,69 @@
 //! Implementation of the target group `Gt`
+//!
+//! ## Encodings
+//!
+//! Elements of `Gt` are elements of `Fp12 = Fp6[w] / (w^2 - v)` with
+//! `Fp6 = Fp2[v] / (v^3 - (u + 1))`. Elements of `Fp` are encoded as 48 bytes
+//! in big-endian order, elements of the extension fields as the concatenation
+//! of their coefficients starting with the constant one. Three encodings are
+//! supported:
+//!
+//! * The compressed encoding (`[u8; 384]`) is relic's packed encoding of
+//!   elements of the cyclotomic subgroup, which omits two of the six
+//!   coefficients in `Fp2`. This encoding is used by [GroupEncoding], for
+//!   serialization and for formatting.
+//! * The torus-based compression ([Gt::to_compressed_torus] and [GtTorus])
+//!   requires 288 bytes and encodes a single element of `Fp6`.
+//! * The uncompressed encoding (`[u8; 576]`) of [UncompressedEncoding]
+//!   contains all twelve coefficients in `Fp`.
+//!
+//! All three encodings are accepted by the `TryFrom<&[u8]>` implementation.
 
 use core::{
-    iter::Sum,
+    hash::{Hash, Hasher},
+    iter::{Product, Sum},
     mem::MaybeUninit,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
 };
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use generic_array::{
-    typenum::{Unsigned, U384, U576},
+    typenum::{Unsigned, U288, U384, U576},
     GenericArray,
 };
 use librelic_sys::{
-    wrapper_gt_add, wrapper_gt_add_assign, wrapper_gt_double, wrapper_gt_generator,
-    wrapper_gt_init, wrapper_gt_is_equal, wrapper_gt_is_neutral, wrapper_gt_is_valid,
-    wrapper_gt_mul, wrapper_gt_mul_assign, wrapper_gt_neg, wrapper_gt_neutral, wrapper_gt_read_bin,
-    wrapper_gt_sub, wrapper_gt_sub_assign, wrapper_gt_t, wrapper_gt_write_bin, RLC_OK,
+    wrapper_gt_add, wrapper_gt_add_assign, wrapper_gt_conditional_select, wrapper_gt_double,
+    wrapper_gt_exp_cyc, wrapper_gt_generator, wrapper_gt_init, wrapper_gt_is_equal,
+    wrapper_gt_is_neutral, wrapper_gt_is_valid, wrapper_gt_mul, wrapper_gt_mul_assign,
+    wrapper_gt_neg, wrapper_gt_neutral, wrapper_gt_read_bin, wrapper_gt_read_torus,
+    wrapper_gt_sqr_cyc, wrapper_gt_sub, wrapper_gt_sub_assign, wrapper_gt_t, wrapper_gt_write_bin,
+    wrapper_gt_write_torus, RLC_OK,
 };
 use pairing::group::{prime::PrimeGroup, Group, GroupEncoding, UncompressedEncoding};
-use subtle::{Choice, CtOption};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-use crate::{pair, Error, G1Projective, G2Projective, Scalar};
+#[cfg(feature = "zeroize")]
+use crate::zeroize_wrappers;
+use crate::{pair, parse_hex, Error, G1Projective, G2Projective, ParseElementError, Scalar};
 use rand_core::RngCore;
 
+type TorusSize = U288;
 type CompressedSize = U384;
 type UncompressedSize = U576;
 
+/// Size of the torus-based compression (one element of `Fp6`)
+const TORUS_BYTES_SIZE: usize = TorusSize::USIZE;
 const COMPRESSED_BYTES_SIZE: usize = CompressedSize::USIZE;
 const UNCOMPRESSED_BYTES_SIZE: usize = UncompressedSize::USIZE;
 
+const NUMS_G1_DOMAIN_SEPERATOR: &[u8] = b"nums-g1";
+const NUMS_G2_DOMAIN_SEPERATOR: &[u8] = b"nums-g2";
+
 #[inline]
 pub(crate) fn new_wrapper() -> wrapper_gt_t {
     let mut gt = MaybeUninit::uninit();
@@ -37,11 +73,188 @@ pub(crate) fn new_wrapper() -> wrapper_gt_t {
     }
 }
 
+/// Obtain the generator from relic
+fn new_generator() -> Gt {
+    let mut value = new_wrapper();
+    unsafe {
+        wrapper_gt_generator(&mut value);
+    }
+    Gt(value)
+}
+
 /// Representation of an group element in the target group
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct Gt(pub(crate) wrapper_gt_t);
 
+impl Gt {
+    /// Derive a "nothing-up-my-sleeve" element from a domain separator
+    ///
+    /// The element is computed as pairing of the points obtained by hashing
+    /// `dst` to `G1` and `G2`, respectively. Hence, it is reproducible and its
+    /// discrete logarithm with respect to the generator is unknown.
+    ///
+    /// ```
+    /// use bls12_381_relic::Gt;
+    ///
+    /// assert_eq!(Gt::from_nums(b"my protocol"), Gt::from_nums(b"my protocol"));
+    /// ```
+    pub fn from_nums(dst: &[u8]) -> Self {
+        pair(
+            G1Projective::hash_to_curve(dst, NUMS_G1_DOMAIN_SEPERATOR),
+            G2Projective::hash_to_curve(dst, NUMS_G2_DOMAIN_SEPERATOR),
+        )
+    }
+
+    /// Exponentiate by a scalar
+    ///
+    /// As `Gt` uses additive notation, this is the same as multiplying with the
+    /// scalar, i.e., `a.pow(&s) == a * s`. This function is provided for users
+    /// used to the multiplicative notation of the target group.
+    ///
+    /// As all elements of `Gt` are contained in the cyclotomic subgroup, the
+    /// exponentiation is performed with cyclotomic squarings.
+    ///
+    /// ```
+    /// use bls12_381_relic::{Gt, Scalar};
+    /// use bls12_381_relic::group::Group;
+    ///
+    /// let s = Scalar::from(5);
+    /// assert_eq!(Gt::generator().pow(&s), Gt::generator() * s);
+    /// ```
+    #[inline]
+    pub fn pow(&self, exp: &Scalar) -> Self {
+        let mut gt = new_wrapper();
+        unsafe {
+            wrapper_gt_exp_cyc(&mut gt, &self.0, &exp.0);
+        }
+        Self(gt)
+    }
+
+    /// Compute the element to the power of a small exponent
+    ///
+    /// The exponentiation is performed by square-and-multiply over the bits of
+    /// `exp` using cyclotomic squarings, which is faster than [Gt::pow] for
+    /// small exponents. The running time depends on `exp`, hence this function
+    /// is only intended for public exponents.
+    ///
+    /// ```
+    /// use bls12_381_relic::{Gt, Scalar};
+    /// use bls12_381_relic::group::Group;
+    ///
+    /// assert_eq!(Gt::generator().pow_u64(3), Gt::generator() * Scalar::from(3));
+    /// ```
+    pub fn pow_u64(&self, exp: u64) -> Self {
+        let mut ret = Self::identity();
+        for bit in (0..u64::BITS - exp.leading_zeros()).rev() {
+            ret = ret.cyclotomic_square();
+            if (exp >> bit) & 1 == 1 {
+                ret += self;
+            }
+        }
+        ret
+    }
+
+    /// Compute `self * scalar + addend`
+    ///
+    /// In multiplicative notation, this is `self^scalar * addend`. The
+    /// exponentiation is computed into the wrapper of the result and the
+    /// addend is multiplied in place, which saves the intermediate element of
+    /// the expression `self * scalar + addend`.
+    ///
+    /// ```
+    /// use bls12_381_relic::{Gt, Scalar};
+    /// use bls12_381_relic::{group::Group, ff::Field};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let g = Gt::random(&mut rng);
+    /// let h = Gt::random(&mut rng);
+    /// let s = Scalar::random(&mut rng);
+    /// assert_eq!(g.mul_add(&s, &h), g * s + h);
+    /// ```
+    pub fn mul_add(&self, scalar: &Scalar, addend: &Self) -> Self {
+        let mut gt = new_wrapper();
+        unsafe {
+            wrapper_gt_mul(&mut gt, &self.0, &scalar.0);
+            wrapper_gt_add_assign(&mut gt, &addend.0);
+        }
+        Self(gt)
+    }
+
+    /// Square the element
+    ///
+    /// In additive notation, this is the same as [Group::double].
+    #[inline]
+    pub fn square(&self) -> Self {
+        self.double()
+    }
+
+    /// Square the element using the cyclotomic squaring
+    ///
+    /// The cyclotomic squaring is considerably cheaper than a generic squaring
+    /// in `Fp12`. As all elements of `Gt` are contained in the cyclotomic
+    /// subgroup, the result is the same as for [Gt::square].
+    #[inline]
+    pub fn cyclotomic_square(&self) -> Self {
+        let mut gt = new_wrapper();
+        unsafe {
+            wrapper_gt_sqr_cyc(&mut gt, &self.0);
+        }
+        Self(gt)
+    }
+
+    /// Compress the element using the torus representation
+    ///
+    /// Elements of `Gt` are contained in the algebraic torus `T2(Fp6)` and can
+    /// thus be represented by a single element of `Fp6`. The encoding requires
+    /// 288 bytes compared to the 384 bytes of the compressed encoding.
+    /// Compression requires an inversion in `Fp6`.
+    ///
+    /// An element `g = g0 + g1 w` is encoded as `(1 + g0) / g1`. The identity
+    /// is the only element with `g1 = 0` and is encoded as zero.
+    ///
+    /// ```
+    /// use bls12_381_relic::Gt;
+    /// use bls12_381_relic::group::Group;
+    ///
+    /// let gt = Gt::random(rand::thread_rng());
+    /// let compressed = gt.to_compressed_torus();
+    /// assert_eq!(compressed.as_bytes().len(), 288);
+    /// assert_eq!(Gt::from_compressed_torus(&compressed).unwrap(), gt);
+    /// ```
+    pub fn to_compressed_torus(&self) -> GtTorus {
+        let mut bytes = [0u8; TORUS_BYTES_SIZE];
+        unsafe {
+            wrapper_gt_write_torus(bytes.as_mut_ptr(), bytes.len(), &self.0);
+        }
+        GtTorus(bytes)
+    }
+
+    /// Decompress an element from its torus representation
+    ///
+    /// Decompression requires an inversion in `Fp6` and the check whether the
+    /// element is contained in `Gt`.
+    pub fn from_compressed_torus(bytes: &GtTorus) -> Result<Self, Error> {
+        let mut gt = new_wrapper();
+        let ret = unsafe { wrapper_gt_read_torus(&mut gt, bytes.0.as_ptr(), bytes.0.len()) };
+        if ret == RLC_OK {
+            if unsafe { wrapper_gt_is_valid(&gt) } {
+                Ok(Self(gt))
+            } else {
+                Err(Error::NotInSubgroup)
+            }
+        } else {
+            Err(Error::RelicError(ret.into()))
+        }
+    }
+
+    /// Feed the compressed encoding ([GroupEncoding::to_bytes]) into a hasher
+    #[cfg(feature = "digest")]
+    pub fn digest_update(&self, hasher: &mut impl digest::Update) {
+        hasher.update(&<[u8; COMPRESSED_BYTES_SIZE]>::from(self));
+    }
+}
+
 impl AsRef<Gt> for Gt {
     fn as_ref(&self) -> &Gt {
         self
@@ -91,10 +304,10 @@ impl TryFrom<&[u8; UNCOMPRESSED_BYTES_SIZE]> for Gt {
             if unsafe { wrapper_gt_is_valid(&gt) } {
                 Ok(Self(gt))
             } else {
-                Err(Error::InvalidBytesRepresentation)
+                Err(Error::NotInSubgroup)
             }
         } else {
-            Err(Error::RelicError(ret))
+            Err(Error::RelicError(ret.into()))
         }
     }
 }
@@ -118,10 +331,10 @@ impl TryFrom<&[u8; COMPRESSED_BYTES_SIZE]> for Gt {
             if unsafe { wrapper_gt_is_valid(&gt) } {
                 Ok(Self(gt))
             } else {
-                Err(Error::InvalidBytesRepresentation)
+                Err(Error::NotInSubgroup)
             }
         } else {
-            Err(Error::RelicError(ret))
+            Err(Error::RelicError(ret.into()))
         }
     }
 }
@@ -175,21 +388,43 @@ impl From<&Gt> for [u8; COMPRESSED_BYTES_SIZE] {
 impl TryFrom<&[u8]> for Gt {
     type Error = Error;
 
+    /// Decode from the torus-based, the compressed or the uncompressed encoding
+    ///
+    /// Slices of any other length are rejected with
+    /// [Error::InvalidBytesRepresentation].
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        let mut gt = new_wrapper();
-        let ret = unsafe { wrapper_gt_read_bin(&mut gt, value.as_ptr(), value.len()) };
-        if ret == RLC_OK {
-            if unsafe { wrapper_gt_is_valid(&gt) } {
-                Ok(Self(gt))
-            } else {
-                Err(Error::InvalidBytesRepresentation)
-            }
+        if let Ok(bytes) = <[u8; TORUS_BYTES_SIZE]>::try_from(value) {
+            Self::from_compressed_torus(&GtTorus(bytes))
+        } else if let Ok(bytes) = <&[u8; COMPRESSED_BYTES_SIZE]>::try_from(value) {
+            Self::try_from(bytes)
+        } else if let Ok(bytes) = <&[u8; UNCOMPRESSED_BYTES_SIZE]>::try_from(value) {
+            Self::try_from(bytes)
         } else {
-            Err(Error::RelicError(ret))
+            Err(Error::InvalidBytesRepresentation)
         }
     }
 }
 
+#[cfg(feature = "alloc")]
+impl TryFrom<Vec<u8>> for Gt {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&Vec<u8>> for Gt {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+
 impl<G> Add<G> for Gt
 where
     G: AsRef<Self>,
@@ -326,6 +561,21 @@ where
     }
 }
 
+/// Product of elements in multiplicative notation
+///
+/// `Gt` is written additively in this crate, so the product of elements in
+/// multiplicative notation is the same as their [Sum]. This implementation
+/// is provided for generic code that is written multiplicatively.
+impl<G> Product<G> for Gt
+where
+    G: AsRef<Self>,
+{
+    #[inline]
+    fn product<I: Iterator<Item = G>>(iter: I) -> Self {
+        iter.sum()
+    }
+}
+
 impl<S> Mul<S> for Gt
 where
     S: AsRef<Scalar>,
@@ -417,6 +667,89 @@ impl PartialEq for Gt {
 
 impl Eq for Gt {}
 
+impl_fmt!(
+    Gt,
+    "Formatting as hex of the compressed encoding",
+    |gt: &Gt| <[u8; COMPRESSED_BYTES_SIZE]>::from(gt),
+    |gt: &Gt| <[u8; UNCOMPRESSED_BYTES_SIZE]>::from(gt)
+);
+
+/// Parsing from hex of the torus-based, the compressed or the uncompressed
+/// encoding
+///
+/// The digits may be prefixed with `0x` or `0X`. This is the inverse of the
+/// [core::fmt::Display] implementation.
+///
+/// ```
+/// use bls12_381_relic::Gt;
+/// use bls12_381_relic::group::Group;
+///
+/// let g = Gt::generator();
+/// assert_eq!(g.to_string().parse::<Gt>().unwrap(), g);
+/// assert_eq!(Gt::try_from(format!("{g:#X}").as_str()).unwrap(), g);
+/// ```
+impl FromStr for Gt {
+    type Err = ParseElementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut buf = [0u8; UNCOMPRESSED_BYTES_SIZE];
+        let bytes = parse_hex(
+            s,
+            &mut buf,
+            &[
+                TORUS_BYTES_SIZE,
+                COMPRESSED_BYTES_SIZE,
+                UNCOMPRESSED_BYTES_SIZE,
+            ],
+        )?;
+        Self::try_from(bytes).map_err(ParseElementError::InvalidElement)
+    }
+}
+
+impl TryFrom<&str> for Gt {
+    type Error = ParseElementError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Hashing via the canonical uncompressed encoding
+///
+/// The uncompressed encoding is used since compression is considerably more
+/// expensive.
+impl Hash for Gt {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let bytes: [u8; UNCOMPRESSED_BYTES_SIZE] = self.into();
+        bytes.hash(state);
+    }
+}
+
+/// Constant-time equality
+///
+/// The comparison of relic used by [PartialEq] is not constant time. Hence, the
+/// canonical encodings of the elements are compared in constant time instead.
+impl ConstantTimeEq for Gt {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // Elements of `Gt` are compared via their uncompressed encoding since
+        // compression is considerably more expensive.
+        let lhs: [u8; UNCOMPRESSED_BYTES_SIZE] = self.into();
+        let rhs: [u8; UNCOMPRESSED_BYTES_SIZE] = other.into();
+        lhs.ct_eq(&rhs)
+    }
+}
+
+impl ConditionallySelectable for Gt {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut ret = new_wrapper();
+        unsafe {
+            wrapper_gt_conditional_select(&mut ret, &a.0, &b.0, choice.into());
+        }
+        Self(ret)
+    }
+}
+
 impl GroupEncoding for Gt {
     type Repr = GenericArray<u8, CompressedSize>;
 
@@ -479,13 +812,20 @@ impl Group for Gt {
         Self::default()
     }
 
+    /// Obtain the generator
+    ///
+    /// With the `std` feature, the generator is obtained from relic on the
+    /// first call and cached afterwards.
     #[inline]
     fn generator() -> Self {
-        let mut value = new_wrapper();
-        unsafe {
-            wrapper_gt_generator(&mut value);
+        #[cfg(feature = "std")]
+        {
+            static GENERATOR: std::sync::OnceLock<Gt> = std::sync::OnceLock::new();
+            *GENERATOR.get_or_init(new_generator)
         }
-        Self(value)
+
+        #[cfg(not(feature = "std"))]
+        new_generator()
     }
 
     #[inline]
@@ -505,15 +845,100 @@ impl Group for Gt {
 
 impl PrimeGroup for Gt {}
 
+/// Torus-based encoding of an element in Gt
+///
+/// Unlike [crate::G1Compressed] and [crate::G2Compressed], this type does not
+/// hold the compressed encoding returned by [GroupEncoding::to_bytes], but the
+/// smaller encoding of [Gt::to_compressed_torus].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GtTorus([u8; TORUS_BYTES_SIZE]);
+
+impl GtTorus {
+    /// Obtain the encoded bytes
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; TORUS_BYTES_SIZE] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for GtTorus {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; TORUS_BYTES_SIZE]> for GtTorus {
+    #[inline]
+    fn from(value: [u8; TORUS_BYTES_SIZE]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<GtTorus> for [u8; TORUS_BYTES_SIZE] {
+    #[inline]
+    fn from(value: GtTorus) -> Self {
+        value.0
+    }
+}
+
+impl From<&Gt> for GtTorus {
+    #[inline]
+    fn from(value: &Gt) -> Self {
+        value.to_compressed_torus()
+    }
+}
+
+impl From<Gt> for GtTorus {
+    #[inline]
+    fn from(value: Gt) -> Self {
+        value.to_compressed_torus()
+    }
+}
+
+impl TryFrom<&GtTorus> for Gt {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &GtTorus) -> Result<Self, Self::Error> {
+        Self::from_compressed_torus(value)
+    }
+}
+
+impl TryFrom<GtTorus> for Gt {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: GtTorus) -> Result<Self, Self::Error> {
+        Self::from_compressed_torus(&value)
+    }
+}
+
+/// Sampling with [rand::Rng::gen] via [Group::random]
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Gt> for rand::distributions::Standard {
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Gt {
+        Gt::random(rng)
+    }
+}
+
 #[cfg(feature = "zeroize")]
 impl zeroize::Zeroize for Gt {
     fn zeroize(&mut self) {
+        zeroize_wrappers(core::slice::from_mut(&mut self.0));
         unsafe {
             wrapper_gt_neutral(&mut self.0);
         }
     }
 }
 
+/// Serialization of the compressed encoding
+///
+/// Elements are serialized as the 384 bytes of [GroupEncoding::to_bytes], or
+/// as 768 hex digits for human-readable formats. Note that elements of `Gt`
+/// are considerably larger than elements of `G1` and `G2`. Deserialization
+/// also accepts the 288 bytes of [Gt::to_compressed_torus].
 #[cfg(feature = "serde")]
 impl serde::Serialize for Gt {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -545,6 +970,28 @@ mod test {
         let generator = Gt::generator();
         let identity = Gt::identity();
         assert_ne!(generator, identity);
+        assert_eq!(generator, new_generator());
+        assert_eq!(Gt::generator(), generator);
+        #[cfg(feature = "std")]
+        assert_eq!(std::thread::spawn(Gt::generator).join().unwrap(), generator);
+    }
+
+    #[test]
+    fn from_nums() {
+        let v = Gt::from_nums(b"dst");
+        assert_eq!(v, Gt::from_nums(b"dst"));
+        assert_ne!(v, Gt::from_nums(b"other dst"));
+        assert_ne!(v, Gt::identity());
+        assert_ne!(v, Gt::generator());
+    }
+
+    #[test]
+    fn hash_set() {
+        let g = Gt::generator();
+        let set: std::collections::HashSet<_> = [g.double(), g + g, g * Scalar::from(2)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 1);
     }
 
     #[test]
@@ -561,6 +1008,23 @@ mod test {
         assert_eq!(check, rv1 + v2);
     }
 
+    #[test]
+    fn sum_product() {
+        let mut rng = rand::thread_rng();
+        let values = [
+            Gt::random(&mut rng),
+            Gt::random(&mut rng),
+            Gt::random(&mut rng),
+        ];
+        let check = values.iter().fold(Gt::identity(), |acc, v| acc + v);
+
+        assert_eq!(values.iter().sum::<Gt>(), check);
+        assert_eq!(values.iter().copied().sum::<Gt>(), check);
+        assert_eq!(values.iter().product::<Gt>(), check);
+        assert_eq!(values.into_iter().product::<Gt>(), check);
+        assert_eq!(core::iter::empty::<Gt>().product::<Gt>(), Gt::identity());
+    }
+
     #[test]
     fn sub() {
         let mut rng = rand::thread_rng();
@@ -596,15 +1060,170 @@ mod test {
         assert_eq!(check, mv);
     }
 
+    #[test]
+    fn pow() {
+        let mut rng = rand::thread_rng();
+        let v = Gt::random(&mut rng);
+        let s = Scalar::random(&mut rng);
+
+        assert_eq!(v.pow(&s), v * s);
+        assert_eq!(v.pow(&Scalar::ZERO), Gt::identity());
+        assert_eq!(v.pow(&Scalar::ONE), v);
+        assert_eq!(v.square(), v + v);
+        assert_eq!(v.square(), v.pow(&Scalar::from(2)));
+    }
+
+    #[test]
+    fn constant_time() {
+        let mut rng = rand::thread_rng();
+        let v1 = Gt::random(&mut rng);
+        let v2 = Gt::random(&mut rng);
+
+        assert_eq!(v1.ct_eq(&v1).unwrap_u8(), 1);
+        assert_eq!(v1.ct_eq(&(v1 + v2 - v2)).unwrap_u8(), 1);
+        assert_eq!(v1.ct_eq(&v2).unwrap_u8(), 0);
+
+        assert_eq!(Gt::conditional_select(&v1, &v2, Choice::from(0)), v1);
+        assert_eq!(Gt::conditional_select(&v1, &v2, Choice::from(1)), v2);
+    }
+
+    #[test]
+    fn pow_u64() {
+        let mut rng = rand::thread_rng();
+        let g = Gt::random(&mut rng);
+        for exp in [0, 1, 2, 3, 5, 1 << 20, u64::MAX] {
+            assert_eq!(g.pow_u64(exp), g * Scalar::from(exp));
+        }
+        assert_eq!(Gt::identity().pow_u64(5), Gt::identity());
+    }
+
+    #[test]
+    fn mul_add() {
+        let mut rng = rand::thread_rng();
+        let g = Gt::random(&mut rng);
+        let h = Gt::random(&mut rng);
+        let s = Scalar::random(&mut rng);
+
+        assert_eq!(g.mul_add(&s, &h), g * s + h);
+        assert_eq!(g.mul_add(&Scalar::ZERO, &h), h);
+        assert_eq!(g.mul_add(&Scalar::ONE, &-g), Gt::identity());
+        assert_eq!(g.mul_add(&s, &Gt::identity()), g * s);
+        assert_eq!(g.mul_add(&s, &g), g * (s + Scalar::ONE));
+    }
+
     #[test]
     fn bytes() {
         let mut rng = rand::thread_rng();
         let v1 = Gt::random(&mut rng);
 
-        let v2 = Gt::from_bytes_unchecked(&v1.to_bytes()).unwrap();
+        let bytes = v1.to_bytes();
+        assert_eq!(bytes.len(), COMPRESSED_BYTES_SIZE);
+        assert_eq!(bytes.as_slice(), <[u8; COMPRESSED_BYTES_SIZE]>::from(v1));
+        let v2 = Gt::from_bytes_unchecked(&bytes).unwrap();
         assert_eq!(v1, v2);
-        let v2 = Gt::from_bytes(&v1.to_bytes()).unwrap();
+        let v2 = Gt::from_bytes(&bytes).unwrap();
         assert_eq!(v1, v2);
+
+        let identity = Gt::identity().to_bytes();
+        assert_eq!(Gt::from_bytes(&identity).unwrap(), Gt::identity());
+
+        assert_eq!(bytes.len(), 384);
+        assert_eq!(v1.to_uncompressed().len(), UNCOMPRESSED_BYTES_SIZE);
+        assert_eq!(v1.to_compressed_torus().as_bytes().len(), TORUS_BYTES_SIZE);
+    }
+
+    #[test]
+    fn cyclotomic() {
+        let mut rng = rand::thread_rng();
+        let v = Gt::random(&mut rng);
+
+        assert_eq!(v.cyclotomic_square(), v.double());
+        assert_eq!(v.cyclotomic_square(), v.square());
+        assert_eq!(Gt::identity().cyclotomic_square(), Gt::identity());
+
+        for s in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::from(5),
+            Scalar::random(&mut rng),
+        ] {
+            let mut expected = new_wrapper();
+            unsafe {
+                wrapper_gt_mul(&mut expected, &v.0, &s.0);
+            }
+            assert_eq!(v.pow(&s), Gt::from(expected));
+        }
+    }
+
+    #[test]
+    fn torus_compression() {
+        let mut rng = rand::thread_rng();
+        for v in [
+            Gt::identity(),
+            Gt::generator(),
+            -Gt::generator(),
+            Gt::random(&mut rng),
+            Gt::random(&mut rng),
+        ] {
+            let compressed = GtTorus::from(v);
+            assert_eq!(Gt::from_compressed_torus(&compressed).unwrap(), v);
+            assert_eq!(Gt::try_from(compressed).unwrap(), v);
+        }
+        assert_eq!(
+            Gt::identity().to_compressed_torus(),
+            GtTorus::from([0u8; TORUS_BYTES_SIZE])
+        );
+
+        // not a valid element of Fp6
+        assert!(Gt::try_from(GtTorus::from([0xff; TORUS_BYTES_SIZE])).is_err());
+        // valid element of the torus, but not in the prime-order subgroup
+        let mut bytes = [0u8; TORUS_BYTES_SIZE];
+        bytes[TORUS_BYTES_SIZE - 1] = 1;
+        assert!(matches!(
+            Gt::try_from(GtTorus::from(bytes)),
+            Err(Error::NotInSubgroup)
+        ));
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let mut rng = rand::thread_rng();
+        let v = Gt::random(&mut rng);
+        let compressed: [u8; COMPRESSED_BYTES_SIZE] = v.into();
+        let uncompressed: [u8; UNCOMPRESSED_BYTES_SIZE] = v.into();
+        assert_eq!(Gt::try_from(v.to_compressed_torus().as_ref()).unwrap(), v);
+        assert_eq!(Gt::try_from(&compressed[..]).unwrap(), v);
+        assert_eq!(Gt::try_from(&uncompressed[..]).unwrap(), v);
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(Gt::try_from(&compressed.to_vec()).unwrap(), v);
+            assert_eq!(Gt::try_from(uncompressed.to_vec()).unwrap(), v);
+        }
+
+        let bytes = [0u8; 600 + 1];
+        for len in 0..=bytes.len() {
+            if len == TORUS_BYTES_SIZE
+                || len == COMPRESSED_BYTES_SIZE
+                || len == UNCOMPRESSED_BYTES_SIZE
+            {
+                continue;
+            }
+            assert!(matches!(
+                Gt::try_from(&bytes[..len]),
+                Err(Error::InvalidBytesRepresentation)
+            ));
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut gt = Gt::random(rand::thread_rng());
+        gt.zeroize();
+        assert_eq!(gt, Gt::identity());
     }
 
     #[cfg(feature = "serde")]
@@ -616,5 +1235,74 @@ mod test {
         let bytes = bincode::serialize(&v1).unwrap();
         let v2: Gt = bincode::deserialize(&bytes).unwrap();
         assert_eq!(v1, v2);
+
+        let json = serde_json::to_string(&v1).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                "\"{}\"",
+                v1.to_bytes()
+                    .iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<String>()
+            )
+        );
+        let v3: Gt = serde_json::from_str(&json).unwrap();
+        assert_eq!(v2, v3);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_size() {
+        let mut rng = rand::thread_rng();
+        for v in [Gt::random(&mut rng), Gt::generator(), Gt::identity()] {
+            // bincode prefixes the bytes with their length as u64
+            let bytes = bincode::serialize(&v).unwrap();
+            assert_eq!(bytes.len(), 8 + COMPRESSED_BYTES_SIZE);
+            assert_eq!(&bytes[8..], v.to_bytes().as_slice());
+
+            let json = serde_json::to_string(&v).unwrap();
+            assert_eq!(json.len(), 2 + 2 * COMPRESSED_BYTES_SIZE);
+        }
+
+        // the torus-based encoding is accepted as well
+        let v = Gt::random(&mut rng);
+        let torus = v.to_compressed_torus();
+        let bytes = bincode::serialize(&torus.as_ref()).unwrap();
+        assert_eq!(bincode::deserialize::<Gt>(&bytes).unwrap(), v);
+    }
+
+    #[test]
+    fn parse() {
+        let g = Gt::generator();
+        let to_hex =
+            |bytes: &[u8]| -> String { bytes.iter().map(|b| format!("{b:02x}")).collect() };
+        let torus = to_hex(g.to_compressed_torus().as_bytes());
+        let uncompressed = to_hex(&<[u8; UNCOMPRESSED_BYTES_SIZE]>::from(g));
+
+        assert_eq!(g.to_string().parse::<Gt>().unwrap(), g);
+        assert_eq!(format!("{g:#X}").parse::<Gt>().unwrap(), g);
+        assert_eq!(torus.parse::<Gt>().unwrap(), g);
+        assert_eq!(
+            Gt::try_from(format!("0x{uncompressed}").as_str()).unwrap(),
+            g
+        );
+        assert_eq!(
+            Gt::identity().to_string().parse::<Gt>().unwrap(),
+            Gt::identity()
+        );
+
+        assert!(matches!(
+            torus[2..].parse::<Gt>(),
+            Err(ParseElementError::InvalidLength(len)) if len == torus.len() - 2
+        ));
+        assert!(matches!(
+            format!("{}x", &torus[1..]).parse::<Gt>(),
+            Err(ParseElementError::InvalidDigit('x'))
+        ));
+        assert!(matches!(
+            "ff".repeat(TORUS_BYTES_SIZE).parse::<Gt>(),
+            Err(ParseElementError::InvalidElement(_))
+        ));
     }
 }
@@ -0,0 +1,391 @@
+//! Conversions from and to the types of the `bls12_381` crate
+//!
+//! Points are converted via the compressed Zcash encoding and scalars via
+//! their canonical encoding. The conversions are fallible: points of either
+//! crate can be outside of the prime-order subgroup if they were created
+//! without checks, e.g., with `from_compressed_unchecked`, and scalars created
+//! from relic's representation can be unreduced. Such points are rejected with
+//! [Error::NotInSubgroup] and such scalars with
+//! [Error::InvalidBytesRepresentation].
+
+use crate::{Error, G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+
+impl TryFrom<&Scalar> for bls12_381::Scalar {
+    type Error = Error;
+
+    fn try_from(value: &Scalar) -> Result<Self, Self::Error> {
+        let mut bytes = value.to_bytes();
+        bytes.reverse();
+        Option::from(bls12_381::Scalar::from_bytes(&bytes)).ok_or(Error::InvalidBytesRepresentation)
+    }
+}
+
+impl TryFrom<Scalar> for bls12_381::Scalar {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: Scalar) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&bls12_381::Scalar> for Scalar {
+    type Error = Error;
+
+    fn try_from(value: &bls12_381::Scalar) -> Result<Self, Self::Error> {
+        let mut bytes = value.to_bytes();
+        bytes.reverse();
+        Option::from(Scalar::from_bytes(&bytes)).ok_or(Error::InvalidBytesRepresentation)
+    }
+}
+
+impl TryFrom<bls12_381::Scalar> for Scalar {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: bls12_381::Scalar) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&G1Projective> for bls12_381::G1Affine {
+    type Error = Error;
+
+    fn try_from(value: &G1Projective) -> Result<Self, Self::Error> {
+        Option::from(bls12_381::G1Affine::from_compressed(
+            &value.to_bytes_zcash(),
+        ))
+        .ok_or(Error::NotInSubgroup)
+    }
+}
+
+impl TryFrom<G1Projective> for bls12_381::G1Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G1Projective) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&G1Affine> for bls12_381::G1Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G1Affine) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<G1Affine> for bls12_381::G1Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G1Affine) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<&G1Projective> for bls12_381::G1Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G1Projective) -> Result<Self, Self::Error> {
+        bls12_381::G1Affine::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<G1Projective> for bls12_381::G1Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G1Projective) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&bls12_381::G1Affine> for G1Projective {
+    type Error = Error;
+
+    fn try_from(value: &bls12_381::G1Affine) -> Result<Self, Self::Error> {
+        Option::from(G1Projective::from_bytes_zcash(&value.to_compressed()))
+            .ok_or(Error::NotInSubgroup)
+    }
+}
+
+impl TryFrom<bls12_381::G1Affine> for G1Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: bls12_381::G1Affine) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&bls12_381::G1Affine> for G1Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &bls12_381::G1Affine) -> Result<Self, Self::Error> {
+        G1Projective::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<bls12_381::G1Affine> for G1Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: bls12_381::G1Affine) -> Result<Self, Self::Error> {
+        G1Projective::try_from(&value).map(Self::from)
+    }
+}
+
+impl TryFrom<&bls12_381::G1Projective> for G1Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &bls12_381::G1Projective) -> Result<Self, Self::Error> {
+        Self::try_from(bls12_381::G1Affine::from(value))
+    }
+}
+
+impl TryFrom<bls12_381::G1Projective> for G1Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: bls12_381::G1Projective) -> Result<Self, Self::Error> {
+        Self::try_from(bls12_381::G1Affine::from(value))
+    }
+}
+
+impl TryFrom<&G2Projective> for bls12_381::G2Affine {
+    type Error = Error;
+
+    fn try_from(value: &G2Projective) -> Result<Self, Self::Error> {
+        Option::from(bls12_381::G2Affine::from_compressed(
+            &value.to_bytes_zcash(),
+        ))
+        .ok_or(Error::NotInSubgroup)
+    }
+}
+
+impl TryFrom<G2Projective> for bls12_381::G2Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G2Projective) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&G2Affine> for bls12_381::G2Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G2Affine) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<G2Affine> for bls12_381::G2Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G2Affine) -> Result<Self, Self::Error> {
+        Self::try_from(&value.0)
+    }
+}
+
+impl TryFrom<&G2Projective> for bls12_381::G2Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &G2Projective) -> Result<Self, Self::Error> {
+        bls12_381::G2Affine::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<G2Projective> for bls12_381::G2Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: G2Projective) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&bls12_381::G2Affine> for G2Projective {
+    type Error = Error;
+
+    fn try_from(value: &bls12_381::G2Affine) -> Result<Self, Self::Error> {
+        Option::from(G2Projective::from_bytes_zcash(&value.to_compressed()))
+            .ok_or(Error::NotInSubgroup)
+    }
+}
+
+impl TryFrom<bls12_381::G2Affine> for G2Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: bls12_381::G2Affine) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&bls12_381::G2Affine> for G2Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &bls12_381::G2Affine) -> Result<Self, Self::Error> {
+        G2Projective::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<bls12_381::G2Affine> for G2Affine {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: bls12_381::G2Affine) -> Result<Self, Self::Error> {
+        G2Projective::try_from(&value).map(Self::from)
+    }
+}
+
+impl TryFrom<&bls12_381::G2Projective> for G2Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &bls12_381::G2Projective) -> Result<Self, Self::Error> {
+        Self::try_from(bls12_381::G2Affine::from(value))
+    }
+}
+
+impl TryFrom<bls12_381::G2Projective> for G2Projective {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: bls12_381::G2Projective) -> Result<Self, Self::Error> {
+        Self::try_from(bls12_381::G2Affine::from(value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pairing::group::{ff::Field, Group};
+
+    use super::*;
+
+    #[test]
+    fn scalar() {
+        let mut rng = rand::thread_rng();
+        for s in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::random(&mut rng),
+        ] {
+            let s_ref = bls12_381::Scalar::try_from(s).unwrap();
+            assert_eq!(Scalar::try_from(s_ref).unwrap(), s);
+        }
+        assert_eq!(
+            bls12_381::Scalar::try_from(Scalar::from(42u64)).unwrap(),
+            bls12_381::Scalar::from(42u64)
+        );
+
+        // unreduced scalars can only be created from relic's representation
+        let mut modulus = Scalar::ZERO;
+        modulus.0[0].dp[..4].copy_from_slice(&[
+            0xffffffff00000001,
+            0x53bda402fffe5bfe,
+            0x3339d80809a1d805,
+            0x73eda753299d7d48,
+        ]);
+        modulus.0[0].used = 4;
+        assert!(matches!(
+            bls12_381::Scalar::try_from(modulus),
+            Err(Error::InvalidBytesRepresentation)
+        ));
+        assert_eq!(
+            bls12_381::Scalar::try_from(modulus.reduced()).unwrap(),
+            bls12_381::Scalar::zero()
+        );
+    }
+
+    #[test]
+    fn g1() {
+        let mut rng = rand::thread_rng();
+        let s = Scalar::random(&mut rng);
+        for v in [
+            G1Projective::identity(),
+            G1Projective::generator(),
+            G1Projective::random(&mut rng),
+        ] {
+            let v_ref = bls12_381::G1Projective::try_from(v).unwrap();
+            assert_eq!(G1Projective::try_from(v_ref).unwrap(), v);
+            let a_ref = bls12_381::G1Affine::try_from(G1Affine::from(v)).unwrap();
+            assert_eq!(G1Affine::try_from(a_ref).unwrap(), G1Affine::from(v));
+
+            assert_eq!(
+                bls12_381::G1Projective::try_from(v * s).unwrap(),
+                v_ref * bls12_381::Scalar::try_from(s).unwrap()
+            );
+        }
+        assert_eq!(
+            bls12_381::G1Affine::try_from(G1Projective::generator()).unwrap(),
+            bls12_381::G1Affine::generator()
+        );
+    }
+
+    #[test]
+    fn g2() {
+        let mut rng = rand::thread_rng();
+        let s = Scalar::random(&mut rng);
+        for v in [
+            G2Projective::identity(),
+            G2Projective::generator(),
+            G2Projective::random(&mut rng),
+        ] {
+            let v_ref = bls12_381::G2Projective::try_from(v).unwrap();
+            assert_eq!(G2Projective::try_from(v_ref).unwrap(), v);
+            let a_ref = bls12_381::G2Affine::try_from(G2Affine::from(v)).unwrap();
+            assert_eq!(G2Affine::try_from(a_ref).unwrap(), G2Affine::from(v));
+
+            assert_eq!(
+                bls12_381::G2Projective::try_from(v * s).unwrap(),
+                v_ref * bls12_381::Scalar::try_from(s).unwrap()
+            );
+        }
+        assert_eq!(
+            bls12_381::G2Affine::try_from(G2Projective::generator()).unwrap(),
+            bls12_381::G2Affine::generator()
+        );
+    }
+
+    #[test]
+    fn not_in_subgroup() {
+        // (0, 2) is on the curve, but has order 3
+        let mut compressed = [0u8; 48];
+        compressed[0] = 0x80;
+        let p_ref = bls12_381::G1Affine::from_compressed_unchecked(&compressed).unwrap();
+        assert!(!bool::from(p_ref.is_torsion_free()));
+        assert!(matches!(
+            G1Projective::try_from(p_ref),
+            Err(Error::NotInSubgroup)
+        ));
+        assert!(matches!(
+            G1Affine::try_from(&p_ref),
+            Err(Error::NotInSubgroup)
+        ));
+
+        let mut y = [0u8; 48];
+        y[47] = 2;
+        let p = G1Projective::from_affine_coordinates_unchecked(&[0; 48], &y).unwrap();
+        assert!(matches!(
+            bls12_381::G1Affine::try_from(p),
+            Err(Error::NotInSubgroup)
+        ));
+        assert!(matches!(
+            bls12_381::G1Projective::try_from(&p),
+            Err(Error::NotInSubgroup)
+        ));
+    }
+}
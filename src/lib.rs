@@ -22,7 +22,16 @@
 //! * [G1Affine] and [G2Affine] are thin wrappers of their projective
 //!   counterparts since [relic] does not have separate types for affine
 //!   representations and associated functions.
-//! * There is no "prepared" variant of elements in `G2` for multi-miller-loops.
+//! * The "prepared" variant of elements in `G2` for multi-miller-loops,
+//!   [G2Prepared], stores the coefficients of the line functions of the
+//!   optimal ate pairing. Since [relic] evaluates them on the fly, they are
+//!   precomputed by the wrapper.
+//! * The encodings of group elements follow [relic]'s format, which prepends a
+//!   byte storing the format. Hence, compressed elements of `G1` and `G2`
+//!   require 49 and 97 bytes instead of 48 and 96 bytes. The format used by
+//!   [bls12_381] (and most other implementations) is available via
+//!   [G1Projective::to_bytes_zcash] and [G1Projective::from_bytes_zcash] (and
+//!   the corresponding functions of [G2Projective]).
 //!
 //! ## Additional features
 //!
@@ -48,7 +57,54 @@
 //! );
 //! ```
 //!
-//! This speed-up is only available if the `alloc` feature is enabled.
+//! This speed-up is only available if the `alloc` feature is enabled. For
+//! slices of points and scalars, [G1Projective::msm] and [G2Projective::msm]
+//! are the recommended interface and are always available.
+//!
+//! The `bls` feature enables the `bls` module implementing BLS signatures and
+//! the `schnorr` feature enables the `schnorr` module implementing Schnorr
+//! proofs of knowledge of discrete logarithms.
+//!
+//! The `xmd` feature enables hashing of messages given in multiple parts to
+//! the curves with [G1Projective::hash_to_curve_chunks] and
+//! [G2Projective::hash_to_curve_chunks].
+//!
+//! With the `digest` feature, all element types provide a `digest_update`
+//! function that feeds the encoding returned by `to_bytes` into a hasher, e.g.,
+//! for transcripts of Fiat-Shamir transformations. The encoding is written to
+//! a temporary buffer on the stack before it is absorbed.
+//!
+//! ## Thread safety
+//!
+//! All element types ([Scalar], [G1Projective], [G2Projective], [Gt] and the
+//! affine variants) are plain values without pointers into relic's state.
+//! They are [Send] and [Sync] and can be freely moved between threads.
+//!
+//! Relic itself keeps its context (including the curve parameters) in
+//! thread-local storage if it is built with multithreading support
+//! (`MULTI=PTHREAD` or `MULTI=OPENMP`). The main thread's context is set up
+//! when the library is loaded and the context of every other thread is set up
+//! on its first use of relic. It is cleaned up when the thread exits. No
+//! locking is involved. [ensure_initialized] can be called to set up the
+//! context eagerly, e.g., when a thread pool starts its workers. The vendored
+//! build enables multithreading support. If a system-provided relic is built
+//! without it, all threads share a single context and relic must not be used
+//! from multiple threads concurrently.
+//!
+//! ## Zeroization
+//!
+//! With the `zeroize` feature, all element types implement `Zeroize`. Zeroizing
+//! an element overwrites all of its memory with zeros and then resets it to
+//! zero or the identity, respectively, such that it remains a valid element.
+//! Since the element types are [Copy], they cannot implement `ZeroizeOnDrop`;
+//! use `Zeroizing` to zeroize them when they are dropped. `bls::SecretKey`
+//! implements `ZeroizeOnDrop`. Additionally, the temporary buffers that are
+//! filled with copies of the inputs for relic's simultaneous evaluation
+//! functions are overwritten before they are released. This covers the sums of
+//! products (including [G1Projective::multiexp_array] and
+//! [G2Projective::multiexp_array]), [pairing_sum], [pairing_sum_array] and the
+//! multi-Miller loops of [RelicEngine]. Note that growing a vector may leave
+//! copies in the released allocation if an iterator underestimates its length.
 //!
 //! ## Notation
 //!
@@ -68,26 +124,89 @@ extern crate alloc;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+use subtle::Choice;
+
 /// Re-exports of used crates
 pub use pairing;
 pub use pairing::group;
 pub use pairing::group::ff;
 pub use subtle;
 
+/// Implement `LowerHex`, `UpperHex`, `Display` and `Debug` (internal)
+///
+/// The hex formats and `Display` write the bytes returned by `$encode`. `Debug`
+/// writes the name of the type and the bytes returned by `$debug_encode`,
+/// which should be cheap to compute.
+macro_rules! impl_fmt {
+    ($type:ident, $doc:literal, $encode:expr, $debug_encode:expr) => {
+        #[doc = $doc]
+        impl core::fmt::LowerHex for $type {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let encode = $encode;
+                crate::fmt_hex(encode(self).as_ref(), f, false)
+            }
+        }
+
+        #[doc = $doc]
+        impl core::fmt::UpperHex for $type {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let encode = $encode;
+                crate::fmt_hex(encode(self).as_ref(), f, true)
+            }
+        }
+
+        #[doc = $doc]
+        impl core::fmt::Display for $type {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerHex::fmt(self, f)
+            }
+        }
+
+        impl core::fmt::Debug for $type {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let encode = $debug_encode;
+                f.write_str(concat!(stringify!($type), "(0x"))?;
+                encode(self)
+                    .as_ref()
+                    .iter()
+                    .try_for_each(|b| write!(f, "{b:02x}"))?;
+                f.write_str(")")
+            }
+        }
+    };
+}
+
 pub mod affine;
+#[cfg(feature = "bls")]
+pub mod bls;
 pub mod engine;
 pub mod g1;
 pub mod g2;
 pub mod gt;
+#[cfg(feature = "bls12_381-interop")]
+mod interop;
+pub mod params;
+#[cfg(feature = "alloc")]
+mod pippenger;
+#[cfg(feature = "alloc")]
+pub mod polynomial;
+#[cfg(feature = "raw")]
+pub mod raw;
 pub mod scalar;
+#[cfg(feature = "schnorr")]
+pub mod schnorr;
 #[cfg(feature = "serde")]
 mod serde_helpers;
+#[cfg(feature = "xmd")]
+mod xmd;
+mod zcash;
 
 pub(crate) use affine::Affine;
-pub use engine::RelicEngine;
-pub use g1::{G1Affine, G1Projective};
-pub use g2::{G2Affine, G2Projective};
-pub use gt::Gt;
+pub use engine::{G2Prepared, RelicEngine};
+pub use g1::{G1Affine, G1Compressed, G1Projective, G1Uncompressed};
+pub use g2::{G2Affine, G2Compressed, G2Projective, G2Uncompressed};
+pub use gt::{Gt, GtTorus};
 pub use scalar::Scalar;
 
 /// Error type
@@ -95,19 +214,108 @@ pub use scalar::Scalar;
 /// This enum covers all errors that are produced by this crate.
 #[derive(Debug)]
 #[cfg_attr(feature = "std", derive(thiserror::Error))]
+#[non_exhaustive]
 pub enum Error {
     /// Relic failure
     #[cfg_attr(feature = "std", error("Error from relic: {0}"))]
-    RelicError(i32),
+    RelicError(RelicError),
     /// Invalid byte representation of group elements or scalars
     #[cfg_attr(feature = "std", error("Invalid representation as bytes."))]
     InvalidBytesRepresentation,
+    /// The decoded element is well-formed, but not contained in the
+    /// prime-order subgroup
+    ///
+    /// Malformed encodings are reported as [Error::RelicError] or
+    /// [Error::InvalidBytesRepresentation] instead.
+    #[cfg_attr(feature = "std", error("Element is not in the prime-order subgroup."))]
+    NotInSubgroup,
+}
+
+/// Errors when parsing group elements from hex strings
+#[derive(Debug)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+#[non_exhaustive]
+pub enum ParseElementError {
+    /// The number of digits does not match the length of any encoding
+    #[cfg_attr(feature = "std", error("invalid number of digits {0}"))]
+    InvalidLength(usize),
+    /// The string contains a character that is not a hex digit
+    #[cfg_attr(feature = "std", error("invalid digit {0:?}"))]
+    InvalidDigit(char),
+    /// The decoded bytes do not encode a valid element
+    #[cfg_attr(feature = "std", error("invalid element: {0}"))]
+    InvalidElement(#[cfg_attr(feature = "std", source)] Error),
+}
+
+/// Errors reported by relic
+///
+/// The wrapper functions report failures of relic as error codes. This enum
+/// maps the known codes to variants. The raw code is available via
+/// [RelicError::code].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+#[non_exhaustive]
+pub enum RelicError {
+    /// Relic rejected the input (`RLC_ERR`)
+    ///
+    /// This is reported if relic fails to decode an element, e.g., since the
+    /// encoding is malformed or does not describe a point on the curve.
+    #[cfg_attr(
+        feature = "std",
+        error(
+            "the input was rejected, e.g., due to a malformed encoding (code {})",
+            librelic_sys::RLC_ERR
+        )
+    )]
+    Failure,
+    /// Unknown error code
+    #[cfg_attr(feature = "std", error("unknown error code {0}"))]
+    Unknown(i32),
+}
+
+impl RelicError {
+    /// Obtain relic's error code
+    pub fn code(&self) -> i32 {
+        match self {
+            Self::Failure => librelic_sys::RLC_ERR,
+            Self::Unknown(code) => *code,
+        }
+    }
+}
+
+impl From<i32> for RelicError {
+    fn from(code: i32) -> Self {
+        match code {
+            librelic_sys::RLC_ERR => Self::Failure,
+            code => Self::Unknown(code),
+        }
+    }
+}
+
+impl From<RelicError> for i32 {
+    #[inline]
+    fn from(error: RelicError) -> Self {
+        error.code()
+    }
+}
+
+/// Initialize relic's context for the current thread
+///
+/// Relic's context is set up automatically on the first use in every thread.
+/// This function can be called to do so eagerly, e.g., on thread startup, to
+/// avoid paying the initialization cost during the first operation. Calling it
+/// multiple times is cheap.
+#[inline]
+pub fn ensure_initialized() {
+    unsafe { librelic_sys::wrapper_init_thread() };
 }
 
 /// Compute pairing of a point in `G1` and one in `G2`
 ///
 /// `G1` can be elements from [G1Projective] or [G1Affine] (or references) and
 /// `G2` can be elements from [G2Projective] or [G2Affine] (or references).
+/// Pairings with the identity are short-circuited (see
+/// [RelicEngine::projective_pairing]).
 ///
 /// ```
 /// use bls12_381_relic::{G1Affine, G2Affine, G1Projective, G2Projective, pair};
@@ -129,6 +337,11 @@ where
 
 /// Compute sum of multiple pairings
 ///
+/// There is no limit on the number of pairings. Relic allocates temporary
+/// copies of all points of a simultaneous pairing on the stack, so the
+/// wrapper processes large inputs in chunks of 128 pairs, multiplies the
+/// outputs of their Miller loops and applies a single final exponentiation.
+///
 /// ```
 /// use bls12_381_relic::{G1Affine, G2Affine, G1Projective, G2Projective, pair, Scalar, pairing_sum};
 /// use bls12_381_relic::group::Group;
@@ -168,6 +381,8 @@ where
         unsafe {
             wrapper_pc_map_sim(&mut gt, g1s.as_ptr(), g2s.as_ptr(), g1s.len());
         }
+        zeroize_wrappers(&mut g1s);
+        zeroize_wrappers(&mut g2s);
         gt.into()
     }
 
@@ -180,14 +395,382 @@ where
     }
 }
 
+/// Compute sum of pairings from fallible terms
+///
+/// This function computes the same result as [pairing_sum] if all terms are
+/// `Ok`. Otherwise, it returns the first error without evaluating any of the
+/// pairings. With the `alloc` feature, the terms are collected before the
+/// pairings are evaluated simultaneously.
+///
+/// ```
+/// use bls12_381_relic::{G1Projective, G2Projective, pair, Scalar, try_pairing_sum};
+/// use bls12_381_relic::group::Group;
+///
+/// let g1 = G1Projective::generator();
+/// let g2 = G2Projective::generator();
+/// let terms = [Ok((g1, g2)), Ok((g1, g2 * Scalar::from(2)))];
+/// assert_eq!(try_pairing_sum::<_, _, _, ()>(terms), Ok(pair(g1, g2) * Scalar::from(3)));
+///
+/// let terms = [Ok((g1, g2)), Err("malformed"), Ok((g1, g2))];
+/// assert_eq!(try_pairing_sum(terms), Err("malformed"));
+/// ```
+pub fn try_pairing_sum<I, G1, G2, E>(iter: I) -> Result<Gt, E>
+where
+    I: IntoIterator<Item = Result<(G1, G2), E>>,
+    G1: AsRef<G1Projective>,
+    G2: AsRef<G2Projective>,
+{
+    #[cfg(feature = "alloc")]
+    {
+        let terms = iter.into_iter().collect::<Result<Vec<_>, E>>()?;
+        Ok(pairing_sum(terms))
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    {
+        use pairing::group::Group;
+
+        iter.into_iter().try_fold(Gt::identity(), |a, term| {
+            let (g1, g2) = term?;
+            Ok(a + pair(g1, g2))
+        })
+    }
+}
+
+/// Compute sum of a fixed number of pairings
+///
+/// This function computes the same result as [pairing_sum], but does not
+/// require the `alloc` feature to benefit from the faster simultaneous
+/// evaluation of the pairings. See [G1Projective::multiexp_array] and
+/// [G2Projective::multiexp_array] for the equivalent for sums of products of
+/// points and scalars.
+///
+/// ```
+/// use bls12_381_relic::{G1Projective, G2Projective, pair, Scalar, pairing_sum_array};
+/// use bls12_381_relic::group::Group;
+///
+/// let g1 = G1Projective::generator();
+/// let g2 = G2Projective::generator();
+///
+/// assert_eq!(
+///     pair(g1, g2) + pair(g1 * Scalar::from(2), g2 * Scalar::from(7)),
+///     pairing_sum_array([(g1, g2), (g1 * Scalar::from(2), g2 * Scalar::from(7))])
+/// );
+/// ```
+pub fn pairing_sum_array<G1, G2, const N: usize>(terms: [(G1, G2); N]) -> Gt
+where
+    G1: AsRef<G1Projective>,
+    G2: AsRef<G2Projective>,
+{
+    use gt::new_wrapper;
+    use librelic_sys::{wrapper_g1_t, wrapper_g2_t, wrapper_pc_map_sim};
+
+    let mut g1s: [wrapper_g1_t; N] = core::array::from_fn(|i| terms[i].0.as_ref().into());
+    let mut g2s: [wrapper_g2_t; N] = core::array::from_fn(|i| terms[i].1.as_ref().into());
+
+    let mut gt = new_wrapper();
+    unsafe {
+        wrapper_pc_map_sim(&mut gt, g1s.as_ptr(), g2s.as_ptr(), N);
+    }
+    zeroize_wrappers(&mut g1s);
+    zeroize_wrappers(&mut g2s);
+    gt.into()
+}
+
+/// Compute sum of multiple pairings with signs
+///
+/// Each term consists of a point in `G1`, a point in `G2`, and a flag
+/// indicating whether the pairing of the term is subtracted instead of added.
+/// The signs are applied to the points in `G1` before all pairings are
+/// evaluated together.
+///
+/// ```
+/// use bls12_381_relic::{G1Projective, G2Projective, pair, Scalar, pairing_sum_signed};
+/// use bls12_381_relic::group::Group;
+///
+/// let g1 = G1Projective::generator();
+/// let g2 = G2Projective::generator();
+///
+/// assert_eq!(
+///     pair(g1 * Scalar::from(2), g2) - pair(g1, g2),
+///     pairing_sum_signed([(g1 * Scalar::from(2), g2, false), (g1, g2, true)])
+/// );
+/// ```
+pub fn pairing_sum_signed<I, G1, G2>(iter: I) -> Gt
+where
+    I: IntoIterator<Item = (G1, G2, bool)>,
+    G1: AsRef<G1Projective>,
+    G2: AsRef<G2Projective>,
+{
+    pairing_sum(iter.into_iter().map(|(g1, g2, negate)| {
+        let g1 = g1.as_ref();
+        (if negate { -g1 } else { *g1 }, g2)
+    }))
+}
+
+/// Check whether a sum of pairings is the identity
+///
+/// This is the verification equation of most pairing-based schemes. The
+/// pairings are evaluated as in [pairing_sum] and the result is checked
+/// directly with relic without comparing it to [Gt::identity].
+///
+/// ```
+/// use bls12_381_relic::{G1Projective, G2Projective, Scalar, pairing_check};
+/// use bls12_381_relic::{group::Group, ff::Field};
+///
+/// let mut rng = rand::thread_rng();
+/// let sk = Scalar::random(&mut rng);
+/// let pk = G2Projective::generator() * sk;
+/// let h = G1Projective::hash_to_curve(b"message", b"dst");
+/// let sigma = h * sk;
+///
+/// // e(sigma, g2) == e(h, pk) <=> e(sigma, g2) - e(h, pk) == 0
+/// assert!(bool::from(pairing_check([(sigma, G2Projective::generator()), (-h, pk)])));
+/// ```
+pub fn pairing_check<I, G1, G2>(iter: I) -> Choice
+where
+    I: IntoIterator<Item = (G1, G2)>,
+    G1: AsRef<G1Projective>,
+    G2: AsRef<G2Projective>,
+{
+    use pairing::group::Group;
+
+    pairing_sum(iter).is_identity()
+}
+
+/// Relic's wrapper types that consist of plain data (internal)
+///
+/// The types are arrays of relic's structs, which only contain integers and
+/// arrays of integers since relic is built with `ALLOC == AUTO`. Hence, all-zero
+/// bytes are a valid value. The trait is not visible outside of the crate and
+/// only implemented for these types.
+pub(crate) trait PlainWrapper: Copy {}
+
+impl PlainWrapper for librelic_sys::wrapper_bn_t {}
+impl PlainWrapper for librelic_sys::wrapper_g1_t {}
+impl PlainWrapper for librelic_sys::wrapper_g2_t {}
+impl PlainWrapper for librelic_sys::wrapper_gt_t {}
+
+/// Overwrite the memory of relic values with zeros (internal)
+///
+/// The buffers used to pass points and scalars to relic's simultaneous
+/// multiplication and pairing functions may contain secret values. With the
+/// `zeroize` feature, they are overwritten before they are dropped. The
+/// `Zeroize` implementations of the element types also use this
+/// function. Otherwise, this function does nothing.
+#[inline(always)]
+pub(crate) fn zeroize_wrappers<T: PlainWrapper>(buffer: &mut [T]) {
+    #[cfg(feature = "zeroize")]
+    {
+        use zeroize::Zeroize;
+
+        // SAFETY: all-zero bytes are a valid value of relic's plain-data types
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                buffer.as_mut_ptr().cast::<u8>(),
+                core::mem::size_of_val(buffer),
+            )
+        }
+        .zeroize();
+    }
+    #[cfg(not(feature = "zeroize"))]
+    let _ = buffer;
+}
+
+/// Fill `buf` from `reader` unless it is at its end (internal)
+///
+/// Returns `Ok(false)` if the reader is at its end before the first byte is
+/// read. If the reader ends after some bytes were read, an error of kind
+/// [std::io::ErrorKind::UnexpectedEof] is returned.
+#[cfg(feature = "std")]
+pub(crate) fn read_exact_or_eof(
+    reader: &mut impl std::io::Read,
+    buf: &mut [u8],
+) -> std::io::Result<bool> {
+    loop {
+        match reader.read(&mut buf[..1]) {
+            Ok(0) => return Ok(false),
+            Ok(_) => break,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    reader.read_exact(&mut buf[1..]).map(|_| true)
+}
+
+/// Format bytes as hex (internal)
+///
+/// The alternate flag adds a `0x` prefix.
+pub(crate) fn fmt_hex(
+    bytes: &[u8],
+    f: &mut core::fmt::Formatter<'_>,
+    upper: bool,
+) -> core::fmt::Result {
+    if f.alternate() {
+        f.write_str("0x")?;
+    }
+    bytes.iter().try_for_each(|b| {
+        if upper {
+            write!(f, "{b:02X}")
+        } else {
+            write!(f, "{b:02x}")
+        }
+    })
+}
+
+/// Strip an optional `0x` or `0X` prefix from hex digits (internal)
+pub(crate) fn strip_hex_prefix(s: &str) -> &str {
+    s.strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s)
+}
+
+/// Decode hex digits into the end of `bytes` (internal)
+///
+/// An odd number of digits is decoded as if it was prefixed with `0`. Bytes
+/// that are not covered by the digits are set to zero. Returns the first
+/// character that is not a hex digit.
+///
+/// # Panics
+///
+/// Panics if there are more than `2 * bytes.len()` digits.
+pub(crate) fn decode_hex(digits: &str, bytes: &mut [u8]) -> Result<(), char> {
+    assert!(digits.len() <= 2 * bytes.len(), "too many digits");
+    if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(c);
+    }
+
+    bytes.fill(0);
+    let len = bytes.len();
+    // all digits are ASCII, so every byte is a digit
+    for (i, c) in digits.bytes().rev().enumerate() {
+        let nibble = match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => c - b'A' + 10,
+        };
+        bytes[len - 1 - i / 2] |= nibble << (4 * (i % 2));
+    }
+    Ok(())
+}
+
+/// Decode hex with an optional `0x` prefix (internal)
+///
+/// The number of decoded bytes has to be one of `lengths`. The bytes are
+/// written to the beginning of `buf`.
+pub(crate) fn parse_hex<'a>(
+    s: &str,
+    buf: &'a mut [u8],
+    lengths: &[usize],
+) -> Result<&'a [u8], ParseElementError> {
+    let digits = strip_hex_prefix(s);
+    if digits.len() % 2 != 0 || !lengths.contains(&(digits.len() / 2)) {
+        return Err(ParseElementError::InvalidLength(digits.len()));
+    }
+
+    let bytes = &mut buf[..digits.len() / 2];
+    decode_hex(digits, bytes).map_err(ParseElementError::InvalidDigit)?;
+    Ok(bytes)
+}
+
 pub(crate) const RANDOM_DOMAIN_SEPERATOR: &[u8; 32] = b"randrandrandrandrandrandrandrand";
 
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Scalar>();
+    assert_send_sync::<G1Projective>();
+    assert_send_sync::<G2Projective>();
+    assert_send_sync::<G1Affine>();
+    assert_send_sync::<G2Affine>();
+    assert_send_sync::<Gt>();
+};
+
 #[cfg(test)]
 mod test {
-    use pairing::group::Group;
+    use pairing::group::{ff::Field, Group};
 
     use super::*;
 
+    #[cfg(feature = "digest")]
+    #[test]
+    fn digest_update() {
+        use pairing::group::GroupEncoding;
+        use sha2::{Digest, Sha256};
+
+        fn check(update: impl FnOnce(&mut Sha256), bytes: &[u8]) {
+            let mut hasher = Sha256::new();
+            update(&mut hasher);
+            assert_eq!(hasher.finalize(), Sha256::digest(bytes));
+        }
+
+        let mut rng = rand::thread_rng();
+        let g1 = G1Projective::random(&mut rng);
+        check(|h| g1.digest_update(h), &g1.to_bytes());
+        check(|h| G1Affine::from(g1).digest_update(h), &g1.to_bytes());
+        let g2 = G2Projective::random(&mut rng);
+        check(|h| g2.digest_update(h), &g2.to_bytes());
+        check(|h| G2Affine::from(g2).digest_update(h), &g2.to_bytes());
+        let gt = Gt::random(&mut rng);
+        check(|h| gt.digest_update(h), &gt.to_bytes());
+        let s = Scalar::random(&mut rng);
+        check(|h| s.digest_update(h), &s.to_bytes());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample_standard() {
+        use rand::{
+            distributions::{Distribution, Standard},
+            rngs::StdRng,
+            Rng, SeedableRng,
+        };
+
+        // sampling from Standard yields the same elements as `random`
+        fn check<T>(random: impl Fn(&mut StdRng) -> T)
+        where
+            T: PartialEq + core::fmt::Debug,
+            Standard: Distribution<T>,
+        {
+            let mut rng = StdRng::seed_from_u64(0x5eed);
+            let mut check_rng = rng.clone();
+            let sampled: Vec<T> = (&mut rng).sample_iter(Standard).take(4).collect();
+            let expected: Vec<T> = (0..4).map(|_| random(&mut check_rng)).collect();
+            assert_eq!(sampled, expected);
+            assert_ne!(sampled[0], sampled[1]);
+        }
+
+        check(|rng| Scalar::random(rng));
+        check(|rng| G1Projective::random(rng));
+        check(|rng| G2Projective::random(rng));
+        check(|rng| Gt::random(rng));
+    }
+
+    #[test]
+    fn hex() {
+        let mut bytes = [0xffu8; 3];
+        assert_eq!(decode_hex("0aBc1", &mut bytes), Ok(()));
+        assert_eq!(bytes, [0x00, 0xab, 0xc1]);
+        assert_eq!(decode_hex("", &mut bytes), Ok(()));
+        assert_eq!(bytes, [0; 3]);
+        assert_eq!(decode_hex("12g4", &mut bytes), Err('g'));
+
+        let mut buf = [0u8; 4];
+        assert_eq!(parse_hex("0X0102", &mut buf, &[2, 4]).unwrap(), [1, 2]);
+        assert!(matches!(
+            parse_hex("010", &mut buf, &[2, 4]),
+            Err(ParseElementError::InvalidLength(3))
+        ));
+        assert!(matches!(
+            parse_hex("01", &mut buf, &[2, 4]),
+            Err(ParseElementError::InvalidLength(2))
+        ));
+        assert!(matches!(
+            parse_hex("01x2", &mut buf, &[2, 4]),
+            Err(ParseElementError::InvalidDigit('x'))
+        ));
+    }
+
     #[test]
     fn pair_generators() {
         let g1 = G1Projective::generator();
@@ -199,6 +782,217 @@ mod test {
         assert_eq!(pair(g1, g2 * s), Gt::generator() * s);
     }
 
+    #[test]
+    fn pair_identity() {
+        let mut rng = rand::thread_rng();
+        let g1 = G1Projective::random(&mut rng);
+        let g2 = G2Projective::random(&mut rng);
+
+        assert_eq!(pair(G1Projective::identity(), g2), Gt::identity());
+        assert_eq!(pair(g1, G2Projective::identity()), Gt::identity());
+        assert_eq!(
+            pair(G1Projective::identity(), G2Projective::identity()),
+            Gt::identity()
+        );
+        assert_eq!(
+            pairing_sum([(G1Projective::identity(), g2), (g1, g2)]),
+            pair(g1, g2)
+        );
+        assert_eq!(
+            pairing_sum([(g1, G2Projective::identity()), (g1, g2)]),
+            pair(g1, g2)
+        );
+    }
+
+    #[test]
+    fn send_to_thread() {
+        let mut rng = rand::thread_rng();
+        let g1 = G1Projective::random(&mut rng);
+        let g2 = G2Projective::random(&mut rng);
+        let s = Scalar::random(&mut rng);
+        let check = pair(g1 * s, g2);
+
+        let handles: [_; 4] =
+            core::array::from_fn(|_| std::thread::spawn(move || (g1 * s, pair(g1 * s, g2))));
+        for handle in handles {
+            let (g1s, gt) = handle.join().unwrap();
+            assert_eq!(g1s, g1 * s);
+            assert_eq!(gt, check);
+        }
+    }
+
+    #[test]
+    fn initialize_thread() {
+        let g1 = G1Projective::generator();
+        let g2 = G2Projective::generator();
+
+        let gt = std::thread::spawn(move || {
+            ensure_initialized();
+            ensure_initialized();
+            pair(g1, g2)
+        })
+        .join()
+        .unwrap();
+        assert_eq!(gt, Gt::generator());
+    }
+
+    #[test]
+    fn relic_error() {
+        assert_eq!(RelicError::from(librelic_sys::RLC_ERR), RelicError::Failure);
+        assert_eq!(RelicError::Failure.code(), librelic_sys::RLC_ERR);
+        assert_eq!(RelicError::from(42), RelicError::Unknown(42));
+        assert_eq!(i32::from(RelicError::Unknown(42)), 42);
+
+        assert!(matches!(
+            G1Projective::try_from(&[0xff; 49]),
+            Err(Error::RelicError(RelicError::Failure))
+        ));
+    }
+
+    #[test]
+    fn pair_array() {
+        let mut rng = rand::thread_rng();
+        let g1s: [G1Projective; 4] = core::array::from_fn(|_| G1Projective::random(&mut rng));
+        let g2s: [G2Projective; 4] = core::array::from_fn(|_| G2Projective::random(&mut rng));
+
+        let check = g1s
+            .iter()
+            .zip(g2s.iter())
+            .fold(Gt::identity(), |acc, (g1, g2)| acc + pair(g1, g2));
+        assert_eq!(
+            pairing_sum_array(core::array::from_fn::<_, 4, _>(|i| (g1s[i], g2s[i]))),
+            check
+        );
+        assert_eq!(
+            pairing_sum_array([(&g1s[0], &g2s[0]), (&G1Projective::identity(), &g2s[1])]),
+            pair(g1s[0], g2s[0])
+        );
+    }
+
+    #[test]
+    fn pairing_sum_edge_cases() {
+        let mut rng = rand::thread_rng();
+        let g1 = G1Projective::random(&mut rng);
+        let g2 = G2Projective::random(&mut rng);
+
+        assert_eq!(
+            pairing_sum(core::iter::empty::<(G1Projective, G2Projective)>()),
+            Gt::identity()
+        );
+        assert_eq!(
+            pairing_sum_array::<G1Projective, G2Projective, 0>([]),
+            Gt::identity()
+        );
+        assert_eq!(pairing_sum([(g1, g2)]), pair(g1, g2));
+        assert_eq!(pairing_sum_array([(g1, g2)]), pair(g1, g2));
+    }
+
+    #[test]
+    fn pairing_sum_many_terms() {
+        let mut rng = rand::thread_rng();
+        let terms: Vec<_> = (0..1000)
+            .map(|_| {
+                (
+                    G1Projective::random_fast(&mut rng),
+                    G2Projective::random_fast(&mut rng),
+                )
+            })
+            .collect();
+
+        let check = terms
+            .iter()
+            .fold(Gt::identity(), |acc, (g1, g2)| acc + pair(g1, g2));
+        assert_eq!(pairing_sum(terms.iter().map(|(g1, g2)| (g1, g2))), check);
+
+        let ref_terms: Vec<_> = terms.iter().map(|(g1, g2)| (g1, g2)).collect();
+        assert_eq!(RelicEngine::projective_multi_miller_loop(&ref_terms), check);
+
+        let (g1s, g2s): (Vec<_>, Vec<_>) = terms.into_iter().unzip();
+        assert!(RelicEngine::multi_miller_loop_slices(&g1s, &g2s).eq_after_final_exp(&check));
+    }
+
+    #[test]
+    fn try_pairing_sum_error() {
+        let mut rng = rand::thread_rng();
+        let terms: Vec<_> = (0..10)
+            .map(|_| {
+                (
+                    G1Projective::random_fast(&mut rng),
+                    G2Projective::random_fast(&mut rng),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            try_pairing_sum(terms.iter().map(|(g1, g2)| Ok::<_, usize>((g1, g2)))),
+            Ok(pairing_sum(terms.iter().map(|(g1, g2)| (g1, g2))))
+        );
+        assert_eq!(
+            try_pairing_sum(terms.iter().enumerate().map(|(i, (g1, g2))| {
+                if i == 5 || i == 7 {
+                    Err(i)
+                } else {
+                    Ok((g1, g2))
+                }
+            })),
+            Err(5)
+        );
+        assert_eq!(
+            try_pairing_sum(core::iter::empty::<Result<(G1Projective, G2Projective), ()>>()),
+            Ok(Gt::identity())
+        );
+    }
+
+    #[test]
+    fn pairing_check_bls() {
+        let mut rng = rand::thread_rng();
+        let sk = Scalar::random(&mut rng);
+        let pk = G2Projective::generator() * sk;
+        let h = G1Projective::hash_to_curve(b"message", b"dst");
+        let sigma = h * sk;
+
+        assert!(bool::from(pairing_check([
+            (sigma, G2Projective::generator()),
+            (-h, pk)
+        ])));
+        assert!(!bool::from(pairing_check([
+            (sigma + G1Projective::generator(), G2Projective::generator()),
+            (-h, pk)
+        ])));
+        assert!(!bool::from(pairing_check([
+            (sigma, G2Projective::generator()),
+            (h, pk)
+        ])));
+        assert!(bool::from(pairing_check(core::iter::empty::<(
+            G1Projective,
+            G2Projective
+        )>())));
+    }
+
+    #[test]
+    fn pair_signed() {
+        let mut rng = rand::thread_rng();
+        let g2 = G2Projective::generator();
+        let sk = Scalar::random(&mut rng);
+        let pk = g2 * sk;
+        let base = G1Projective::hash_to_curve(b"message", b"dst");
+        let sigma = base * sk;
+
+        // e(H(m), pk) == e(sigma, g2)
+        assert_eq!(
+            pairing_sum_signed([(base, pk, true), (sigma, g2, false)]),
+            Gt::identity()
+        );
+        assert_eq!(
+            pairing_sum_signed([(&base, &pk, false), (&sigma, &g2, true)]),
+            Gt::identity()
+        );
+        assert_ne!(
+            pairing_sum_signed([(base, pk, false), (sigma, g2, false)]),
+            Gt::identity()
+        );
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn multi_pair() {
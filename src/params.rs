@@ -0,0 +1,78 @@
+//! Parameters of the BLS12-381 curve
+//!
+//! This module collects the parameters of BLS12-381 as used by relic. All
+//! multi-precision integers are given as big-endian byte arrays.
+//!
+//! The curve is defined over the prime field `Fp` by `E: y^2 = x^3 + 4`. The
+//! twist used for `G2` is defined over `Fp2 = Fp[u] / (u^2 + 1)` by
+//! `E': y^2 = x^3 + 4 (u + 1)`.
+
+/// Absolute value of the curve parameter `x` (or `u`) of BLS12-381
+pub const X: u64 = 0xd201000000010000;
+
+/// Sign of the curve parameter `x`
+///
+/// The curve parameter is `-X`.
+pub const X_IS_NEGATIVE: bool = true;
+
+/// Embedding degree of the curve
+pub const EMBEDDING_DEGREE: usize = 12;
+
+/// Prime `p` of the base field `Fp` (big-endian)
+pub const FIELD_MODULUS: [u8; 48] = [
+    0x1a, 0x01, 0x11, 0xea, 0x39, 0x7f, 0xe6, 0x9a, 0x4b, 0x1b, 0xa7, 0xb6, 0x43, 0x4b, 0xac, 0xd7,
+    0x64, 0x77, 0x4b, 0x84, 0xf3, 0x85, 0x12, 0xbf, 0x67, 0x30, 0xd2, 0xa0, 0xf6, 0xb0, 0xf6, 0x24,
+    0x1e, 0xab, 0xff, 0xfe, 0xb1, 0x53, 0xff, 0xff, 0xb9, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xaa, 0xab,
+];
+
+/// Prime order `r` of the groups and the scalar field (big-endian)
+///
+/// This is the same value as `Scalar::MODULUS`.
+pub const SCALAR_MODULUS: [u8; 32] = [
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
+    0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+];
+
+/// Coefficient `b` of the curve `E: y^2 = x^3 + b` for `G1`
+pub const G1_B: u64 = 4;
+
+/// Coefficient `b` of the twist `E': y^2 = x^3 + b` for `G2`
+///
+/// The coefficient is given as `[c0, c1]` representing `c0 + c1 u`.
+pub const G2_B: [u64; 2] = [4, 4];
+
+/// Cofactor of `G1` in `E(Fp)` (big-endian)
+pub const G1_COFACTOR: [u8; 16] = [
+    0x39, 0x6c, 0x8c, 0x00, 0x55, 0x55, 0xe1, 0x56, 0x8c, 0x00, 0xaa, 0xab, 0x00, 0x00, 0xaa, 0xab,
+];
+
+/// Cofactor of `G2` in `E'(Fp2)` (big-endian)
+pub const G2_COFACTOR: [u8; 64] = [
+    0x05, 0xd5, 0x43, 0xa9, 0x54, 0x14, 0xe7, 0xf1, 0x09, 0x1d, 0x50, 0x79, 0x28, 0x76, 0xa2, 0x02,
+    0xcd, 0x91, 0xde, 0x45, 0x47, 0x08, 0x5a, 0xba, 0xa6, 0x8a, 0x20, 0x5b, 0x2e, 0x5a, 0x7d, 0xdf,
+    0xa6, 0x28, 0xf1, 0xcb, 0x4d, 0x9e, 0x82, 0xef, 0x21, 0x53, 0x7e, 0x29, 0x3a, 0x66, 0x91, 0xae,
+    0x16, 0x16, 0xec, 0x6e, 0x78, 0x6f, 0x0c, 0x70, 0xcf, 0x1c, 0x38, 0xe3, 0x1c, 0x72, 0x38, 0xe5,
+];
+
+#[cfg(test)]
+mod test {
+    use pairing::group::ff::PrimeField;
+
+    use super::*;
+    use crate::Scalar;
+
+    #[test]
+    fn scalar_modulus() {
+        let modulus = Scalar::MODULUS.trim_start_matches("0x");
+        let bytes: [u8; 32] =
+            core::array::from_fn(|i| u8::from_str_radix(&modulus[2 * i..2 * i + 2], 16).unwrap());
+        assert_eq!(bytes, SCALAR_MODULUS);
+    }
+
+    #[test]
+    fn g1_cofactor() {
+        // h = (x - 1)^2 / 3
+        let x_minus_one = X as u128 + 1;
+        assert_eq!((x_minus_one * x_minus_one / 3).to_be_bytes(), G1_COFACTOR);
+    }
+}
@@ -0,0 +1,180 @@
+//! Polynomials over the scalar field
+//!
+//! This module provides the building blocks of Shamir secret sharing and of
+//! threshold schemes and verifiable secret sharing schemes built on top of it:
+//! evaluation of polynomials and Lagrange coefficients for interpolation at
+//! zero.
+//!
+//! Polynomials are given by their coefficients in increasing order of the
+//! degree, i.e., `coeffs[0]` is the constant term. The coefficients can be
+//! scalars or, e.g., the commitments to the coefficients in `G1` used by
+//! Feldman's and Pedersen's verifiable secret sharing schemes.
+//!
+//! ```
+//! use bls12_381_relic::{polynomial, G1Projective, Scalar};
+//! use bls12_381_relic::{group::Group, ff::Field};
+//!
+//! let mut rng = rand::thread_rng();
+//! let secret = Scalar::random(&mut rng);
+//! // threshold of 3 shares
+//! let coeffs = [secret, Scalar::random(&mut rng), Scalar::random(&mut rng)];
+//! let xs: Vec<_> = (1..=5u64).map(Scalar::from).collect();
+//! let shares: Vec<_> = xs.iter().map(|x| polynomial::eval(&coeffs, x)).collect();
+//!
+//! // recover the secret from the shares 2, 4 and 5
+//! let lambdas = polynomial::lagrange_coefficients(&[xs[1], xs[3], xs[4]]);
+//! let recovered: Scalar = [shares[1], shares[3], shares[4]]
+//!     .iter()
+//!     .zip(lambdas.iter())
+//!     .map(|(share, lambda)| share * lambda)
+//!     .sum();
+//! assert_eq!(recovered, secret);
+//!
+//! // shares can be checked against commitments to the coefficients
+//! let commitments: Vec<_> = coeffs.iter().map(|c| G1Projective::generator() * c).collect();
+//! assert_eq!(
+//!     polynomial::eval(&commitments, &xs[0]),
+//!     G1Projective::generator() * shares[0]
+//! );
+//! ```
+
+use core::ops::{Add, Mul};
+
+use alloc::vec::Vec;
+use pairing::group::ff::Field;
+
+use crate::Scalar;
+
+/// Evaluate the polynomial with the given coefficients at `x`
+///
+/// The polynomial is evaluated using Horner's method. The coefficients can be
+/// scalars or group elements, e.g., commitments to the coefficients of a
+/// polynomial over the scalars. An empty list of coefficients denotes the
+/// zero polynomial.
+pub fn eval<T>(coeffs: &[T], x: &Scalar) -> T
+where
+    T: Default + for<'a> Add<&'a T, Output = T> + for<'a> Mul<&'a Scalar, Output = T>,
+{
+    coeffs
+        .iter()
+        .rev()
+        .fold(T::default(), |acc, coeff| acc * x + coeff)
+}
+
+/// Invert all scalars using Montgomery's trick
+///
+/// Panics if one of the scalars is zero.
+fn batch_invert(values: &mut [Scalar]) {
+    let mut prefixes = Vec::with_capacity(values.len());
+    let mut acc = Scalar::ONE;
+    for value in values.iter() {
+        prefixes.push(acc);
+        acc *= value;
+    }
+
+    let mut inv: Scalar = Option::from(acc.invert()).expect("evaluation points are distinct");
+    for (value, prefix) in values.iter_mut().zip(prefixes).rev() {
+        let next = inv * *value;
+        *value = inv * prefix;
+        inv = next;
+    }
+}
+
+/// Compute the Lagrange coefficients for interpolation at zero
+///
+/// For evaluation points `xs`, the `i`-th coefficient is the product of
+/// `x_j / (x_j - x_i)` over all `j != i`. Given the evaluations `y_i` of a
+/// polynomial of degree less than `xs.len()` at the points `x_i`, the sum of
+/// `y_i * lambda_i` is the constant term of the polynomial. The denominators
+/// are inverted using a single field inversion.
+///
+/// # Panics
+///
+/// Panics if the evaluation points are not distinct.
+pub fn lagrange_coefficients(xs: &[Scalar]) -> Vec<Scalar> {
+    let (numerators, mut denominators): (Vec<_>, Vec<_>) = xs
+        .iter()
+        .enumerate()
+        .map(|(i, xi)| {
+            xs.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold((Scalar::ONE, Scalar::ONE), |(num, den), (_, xj)| {
+                    (num * xj, den * (xj - xi))
+                })
+        })
+        .unzip();
+    batch_invert(&mut denominators);
+
+    numerators
+        .into_iter()
+        .zip(denominators)
+        .map(|(num, den)| num * den)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use pairing::group::Group;
+
+    use super::*;
+    use crate::{G1Projective, G2Projective, Gt};
+
+    #[test]
+    fn eval_known() {
+        // 3 + 2x + x^2
+        let coeffs = [Scalar::from(3u8), Scalar::from(2u8), Scalar::from(1u8)];
+        assert_eq!(eval(&coeffs, &Scalar::ZERO), Scalar::from(3u8));
+        assert_eq!(eval(&coeffs, &Scalar::ONE), Scalar::from(6u8));
+        assert_eq!(eval(&coeffs, &Scalar::from(2u8)), Scalar::from(11u8));
+        assert_eq!(eval(&coeffs, &-Scalar::ONE), Scalar::from(2u8));
+        assert_eq!(eval::<Scalar>(&[], &Scalar::from(2u8)), Scalar::ZERO);
+    }
+
+    #[test]
+    fn eval_in_exponent() {
+        let mut rng = rand::thread_rng();
+        let coeffs: Vec<_> = (0..4).map(|_| Scalar::random(&mut rng)).collect();
+        let x = Scalar::random(&mut rng);
+        let y = eval(&coeffs, &x);
+
+        let g1: Vec<_> = coeffs
+            .iter()
+            .map(|c| G1Projective::generator() * c)
+            .collect();
+        assert_eq!(eval(&g1, &x), G1Projective::generator() * y);
+        let g2: Vec<_> = coeffs
+            .iter()
+            .map(|c| G2Projective::generator() * c)
+            .collect();
+        assert_eq!(eval(&g2, &x), G2Projective::generator() * y);
+        let gt: Vec<_> = coeffs.iter().map(|c| Gt::generator() * c).collect();
+        assert_eq!(eval(&gt, &x), Gt::generator() * y);
+    }
+
+    #[test]
+    fn interpolation() {
+        let mut rng = rand::thread_rng();
+        let coeffs: Vec<_> = (0..5).map(|_| Scalar::random(&mut rng)).collect();
+        let xs: Vec<_> = (1..=5u64).map(|x| Scalar::from(x * 7)).collect();
+        let ys: Vec<_> = xs.iter().map(|x| eval(&coeffs, x)).collect();
+
+        let lambdas = lagrange_coefficients(&xs);
+        let constant: Scalar = ys.iter().zip(lambdas.iter()).map(|(y, l)| y * l).sum();
+        assert_eq!(constant, coeffs[0]);
+
+        // too few points do not recover the constant term
+        let lambdas = lagrange_coefficients(&xs[1..]);
+        let constant: Scalar = ys[1..].iter().zip(lambdas.iter()).map(|(y, l)| y * l).sum();
+        assert_ne!(constant, coeffs[0]);
+
+        assert_eq!(lagrange_coefficients(&[Scalar::from(5u8)]), [Scalar::ONE]);
+        assert!(lagrange_coefficients(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn duplicate_points() {
+        lagrange_coefficients(&[Scalar::ONE, Scalar::from(2u8), Scalar::ONE]);
+    }
+}
@@ -0,0 +1,135 @@
+//! Low-level access to relic's representations (unstable)
+//!
+//! This module provides an escape hatch for protocols that require operations
+//! of relic that are not wrapped by this crate. The element types can be
+//! converted to and from the types used by the wrapper functions of
+//! [librelic_sys], which is re-exported here.
+//!
+//! **Warning**: This module is not covered by the semver guarantees of the
+//! crate and may change with any release of relic or of this crate.
+//!
+//! # Invariants
+//!
+//! The functions of this crate assume that all elements are valid. Values
+//! passed to the `from_raw` functions must satisfy the following invariants:
+//!
+//! * Points in `G1` and `G2` are on the curve and in the prime-order subgroup
+//!   (or the identity). The coordinates are stored in relic's internal
+//!   representation, e.g., in Montgomery form.
+//! * Elements of `Gt` are in the prime-order subgroup of the cyclotomic
+//!   subgroup of `Fp12`.
+//! * Scalars are non-negative and reduced modulo the group order, and the
+//!   number of used limbs does not exceed the allocated limbs.
+//!
+//! Values obtained from relic's functions for the respective groups satisfy
+//! these invariants.
+//!
+//! ```
+//! use bls12_381_relic::{raw::librelic_sys, G1Projective};
+//! use bls12_381_relic::group::Group;
+//!
+//! let g = G1Projective::generator();
+//! let mut doubled = *g.as_raw();
+//! unsafe {
+//!     librelic_sys::wrapper_g1_double(&mut doubled, g.as_raw());
+//!     assert_eq!(G1Projective::from_raw(doubled), g.double());
+//! }
+//! ```
+
+pub use librelic_sys;
+pub use librelic_sys::{wrapper_bn_t, wrapper_g1_t, wrapper_g2_t, wrapper_gt_t};
+
+use crate::{G1Projective, G2Projective, Gt, Scalar};
+
+impl G1Projective {
+    /// Obtain relic's representation of the point
+    #[inline]
+    pub fn as_raw(&self) -> &wrapper_g1_t {
+        &self.0
+    }
+
+    /// Create a point from relic's representation
+    ///
+    /// # Safety
+    ///
+    /// The point must satisfy the [invariants](crate::raw#invariants).
+    #[inline]
+    pub unsafe fn from_raw(value: wrapper_g1_t) -> Self {
+        Self(value)
+    }
+}
+
+impl G2Projective {
+    /// Obtain relic's representation of the point
+    #[inline]
+    pub fn as_raw(&self) -> &wrapper_g2_t {
+        &self.0
+    }
+
+    /// Create a point from relic's representation
+    ///
+    /// # Safety
+    ///
+    /// The point must satisfy the [invariants](crate::raw#invariants).
+    #[inline]
+    pub unsafe fn from_raw(value: wrapper_g2_t) -> Self {
+        Self(value)
+    }
+}
+
+impl Gt {
+    /// Obtain relic's representation of the element
+    #[inline]
+    pub fn as_raw(&self) -> &wrapper_gt_t {
+        &self.0
+    }
+
+    /// Create an element from relic's representation
+    ///
+    /// # Safety
+    ///
+    /// The element must satisfy the [invariants](crate::raw#invariants).
+    #[inline]
+    pub unsafe fn from_raw(value: wrapper_gt_t) -> Self {
+        Self(value)
+    }
+}
+
+impl Scalar {
+    /// Obtain relic's representation of the scalar
+    #[inline]
+    pub fn as_raw(&self) -> &wrapper_bn_t {
+        &self.0
+    }
+
+    /// Create a scalar from relic's representation
+    ///
+    /// # Safety
+    ///
+    /// The scalar must satisfy the [invariants](crate::raw#invariants).
+    #[inline]
+    pub unsafe fn from_raw(value: wrapper_bn_t) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pairing::group::{ff::Field, Group};
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut rng = rand::thread_rng();
+
+        let g1 = G1Projective::random(&mut rng);
+        assert_eq!(unsafe { G1Projective::from_raw(*g1.as_raw()) }, g1);
+        let g2 = G2Projective::random(&mut rng);
+        assert_eq!(unsafe { G2Projective::from_raw(*g2.as_raw()) }, g2);
+        let gt = Gt::random(&mut rng);
+        assert_eq!(unsafe { Gt::from_raw(*gt.as_raw()) }, gt);
+        let s = Scalar::random(&mut rng);
+        assert_eq!(unsafe { Scalar::from_raw(*s.as_raw()) }, s);
+    }
+}
@@ -2,21 +2,34 @@
 //!
 //! This module provides the implementation of the scalar field.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, vec::Vec};
+
 use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
     iter::{Product, Sum},
-    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
 };
 
 use librelic_sys::{
-    bn_st, wrapper_bn_add, wrapper_bn_add_assign, wrapper_bn_double, wrapper_bn_inv,
-    wrapper_bn_is_even, wrapper_bn_is_odd, wrapper_bn_is_zero, wrapper_bn_mul,
-    wrapper_bn_mul_assign, wrapper_bn_neg, wrapper_bn_read_bin, wrapper_bn_sqr, wrapper_bn_sub,
-    wrapper_bn_sub_assign, wrapper_bn_t, wrapper_bn_write_bin, RLC_OK, RLC_POS,
+    bn_st, wrapper_bn_add, wrapper_bn_add_assign, wrapper_bn_double, wrapper_bn_hash_to_field,
+    wrapper_bn_inv, wrapper_bn_is_even, wrapper_bn_is_odd, wrapper_bn_is_zero, wrapper_bn_mul,
+    wrapper_bn_mul_assign, wrapper_bn_neg, wrapper_bn_read_bin, wrapper_bn_reduce, wrapper_bn_sqr,
+    wrapper_bn_sub, wrapper_bn_sub_assign, wrapper_bn_t, wrapper_bn_write_bin, RLC_OK, RLC_POS,
 };
 use pairing::group::ff::{Field, PrimeField};
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+    CtOption,
+};
 
-use crate::Error;
+#[cfg(feature = "zeroize")]
+use crate::zeroize_wrappers;
+use crate::{decode_hex, params::SCALAR_MODULUS, strip_hex_prefix, Error};
 use rand_core::RngCore;
 
 /// Reimplementation of `bn_make` to have a `const` version
@@ -44,12 +57,92 @@ const fn new_wrapper() -> wrapper_bn_t {
     }]
 }
 
+/// Order of the scalar field minus 2 as little-endian limbs
+const MODULUS_MINUS_TWO: [u64; 4] = [
+    0xfffffffeffffffff,
+    0x53bda402fffe5bfe,
+    0x3339d80809a1d805,
+    0x73eda753299d7d48,
+];
+
+/// Order of the scalar field minus 1 divided by 2 as little-endian limbs
+const MODULUS_MINUS_ONE_DIV_TWO: [u64; 4] = [
+    0x7fffffff80000000,
+    0xa9ded2017fff2dff,
+    0x199cec0404d0ec02,
+    0x39f6d3a994cebea4,
+];
+
 /// Scalar in the prime field induced by the order of the elliptic curve groups
-#[derive(Clone, Copy, Debug)]
+///
+/// Scalars are always kept reduced modulo the group order, i.e., all
+/// constructors and arithmetic operations produce the canonical representative
+/// in `[0, MODULUS)`. Conversions from bytes either reduce the value (`From`)
+/// or reject non-canonical encodings ([Scalar::from_bytes] and `TryFrom`). The
+/// only exception is the conversion from relic's `wrapper_bn_t`, where
+/// [Scalar::reduce] can be used to restore the invariant.
+#[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct Scalar(pub(crate) wrapper_bn_t);
 
+/// Errors when parsing scalars from strings
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+#[non_exhaustive]
+pub enum ParseScalarError {
+    /// The string contains no digits
+    #[cfg_attr(feature = "std", error("no digits"))]
+    Empty,
+    /// The string contains a character that is not a valid digit
+    #[cfg_attr(feature = "std", error("invalid digit {0:?}"))]
+    InvalidDigit(char),
+    /// The value is not smaller than the group order
+    #[cfg_attr(feature = "std", error("value is not smaller than the group order"))]
+    Overflow,
+}
+
 impl Scalar {
+    /// The additive identity
+    pub const ZERO: Self = Self::from_u8(0);
+
+    /// The multiplicative identity
+    pub const ONE: Self = Self::from_u8(1);
+
+    /// Generator of the multiplicative group
+    pub const MULTIPLICATIVE_GENERATOR: Self = Self::from_u8(7);
+
+    /// Inverse of 2
+    pub const TWO_INV: Self = Self::from_bytes_internal(
+        [0x39, 0xf6, 0xd3, 0xa9, 0x94, 0xce, 0xbe, 0xa4],
+        [0x19, 0x9c, 0xec, 0x04, 0x04, 0xd0, 0xec, 0x02],
+        [0xa9, 0xde, 0xd2, 0x01, 0x7f, 0xff, 0x2d, 0xff],
+        [0x7f, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00, 0x01],
+    );
+
+    /// Primitive `2^S`-th root of unity, see [PrimeField::ROOT_OF_UNITY]
+    pub const ROOT_OF_UNITY: Self = Self::from_bytes_internal(
+        [0x16, 0xa2, 0xa1, 0x9e, 0xdf, 0xe8, 0x1f, 0x20],
+        [0xd0, 0x9b, 0x68, 0x19, 0x22, 0xc8, 0x13, 0xb4],
+        [0xb6, 0x36, 0x83, 0x50, 0x8c, 0x22, 0x80, 0xb9],
+        [0x38, 0x29, 0x97, 0x1f, 0x43, 0x9f, 0x0d, 0x2b],
+    );
+
+    /// Inverse of [Scalar::ROOT_OF_UNITY]
+    pub const ROOT_OF_UNITY_INV: Self = Self::from_bytes_internal(
+        [0x05, 0x38, 0xa6, 0xf6, 0x6e, 0x19, 0xc6, 0x53],
+        [0xed, 0x4f, 0x2f, 0x74, 0xa3, 0x5d, 0x01, 0x68],
+        [0x6f, 0x67, 0xd4, 0xa2, 0xb5, 0x66, 0xf8, 0x33],
+        [0x0f, 0xb4, 0xd6, 0xe1, 0x3c, 0xf1, 0x9a, 0x78],
+    );
+
+    /// Generator to the power of `2^S`, see [PrimeField::DELTA]
+    pub const DELTA: Self = Self::from_bytes_internal(
+        [0x08, 0x63, 0x4d, 0x0a, 0xa0, 0x21, 0xaa, 0xf8],
+        [0x43, 0xca, 0xb3, 0x54, 0xfa, 0xbb, 0x00, 0x62],
+        [0xf6, 0x50, 0x24, 0x37, 0xc6, 0xa0, 0x9c, 0x00],
+        [0x6c, 0x08, 0x34, 0x79, 0x59, 0x01, 0x89, 0xd7],
+    );
+
     const fn from_u64(v: u64) -> Self {
         Self(new_wrapper_with_v(v))
     }
@@ -58,6 +151,34 @@ impl Scalar {
         Self(new_wrapper_with_v(v as u64))
     }
 
+    /// Create a scalar from a `u128`
+    pub const fn from_u128(v: u128) -> Self {
+        let mut bn = new_wrapper_with_v(v as u64);
+        let high = (v >> 64) as u64;
+        if high != 0 {
+            bn[0].dp[1] = high;
+            bn[0].used = 2;
+        }
+        Self(bn)
+    }
+
+    /// Create a scalar from an `i64` where negative values are mapped to
+    /// `MODULUS - |v|`
+    const fn from_i64(v: i64) -> Self {
+        if v >= 0 {
+            return Self::from_u64(v as u64);
+        }
+
+        // |v| <= 2^63 is smaller than the lowest limb of the modulus, hence
+        // there is no borrow
+        let mut bn = new_wrapper_with_v(MODULUS_MINUS_TWO[0] + 2 - v.unsigned_abs());
+        bn[0].dp[1] = MODULUS_MINUS_TWO[1];
+        bn[0].dp[2] = MODULUS_MINUS_TWO[2];
+        bn[0].dp[3] = MODULUS_MINUS_TWO[3];
+        bn[0].used = 4;
+        Self(bn)
+    }
+
     /// Obtain a representation of 1
     pub const fn one() -> Self {
         Self::ONE
@@ -77,6 +198,192 @@ impl Scalar {
         ret
     }
 
+    /// Obtain the canonical representation as little-endian `u64` limbs
+    ///
+    /// The limbs are those of the value reduced modulo the group order. The
+    /// conversion from `[u64; 4]` is the inverse of this function.
+    ///
+    /// ```
+    /// use bls12_381_relic::Scalar;
+    ///
+    /// let s = Scalar::from(u128::MAX);
+    /// assert_eq!(s.to_u64_limbs(), [u64::MAX, u64::MAX, 0, 0]);
+    /// assert_eq!(Scalar::from(s.to_u64_limbs()), s);
+    /// ```
+    pub fn to_u64_limbs(&self) -> [u64; 4] {
+        self.limbs()
+    }
+
+    /// Feed the big-endian encoding ([Self::to_bytes]) into a hasher
+    #[cfg(feature = "digest")]
+    pub fn digest_update(&self, hasher: &mut impl digest::Update) {
+        hasher.update(&self.to_bytes());
+    }
+
+    /// Reduce the scalar modulo the group order
+    ///
+    /// This is a no-op for all scalars obtained from the public API. It is
+    /// only required for scalars created from a `wrapper_bn_t` that was not
+    /// reduced.
+    #[inline]
+    pub fn reduce(&mut self) {
+        unsafe {
+            wrapper_bn_reduce(&mut self.0);
+        }
+    }
+
+    /// Obtain the scalar reduced modulo the group order
+    ///
+    /// See [Scalar::reduce].
+    #[inline]
+    pub fn reduced(&self) -> Self {
+        let mut ret = *self;
+        ret.reduce();
+        ret
+    }
+
+    /// Obtain the little-endian limbs of the scalar
+    ///
+    /// Relic only guarantees that the limbs up to `used` are meaningful, so
+    /// all other limbs are replaced by zeros in constant time. Since scalars
+    /// are kept reduced, four limbs cover all values.
+    fn limbs(&self) -> [u64; 4] {
+        let bn = &self.0[0];
+        let used = bn.used as u64;
+        core::array::from_fn(|i| u64::conditional_select(&0, &bn.dp[i], (i as u64).ct_lt(&used)))
+    }
+
+    /// Compare the canonical integer representations in variable time
+    ///
+    /// Scalars are compared as integers in `[0, MODULUS)`. Use the
+    /// [ConstantTimeGreater] and [ConstantTimeLess] implementations for
+    /// secret scalars.
+    ///
+    /// ```
+    /// use bls12_381_relic::Scalar;
+    /// use core::cmp::Ordering;
+    ///
+    /// assert_eq!(Scalar::from(2u8).cmp_vartime(&Scalar::from(3u8)), Ordering::Less);
+    /// assert_eq!((-Scalar::from(1u8)).cmp_vartime(&Scalar::from(3u8)), Ordering::Greater);
+    /// ```
+    pub fn cmp_vartime(&self, other: &Self) -> Ordering {
+        self.limbs().iter().rev().cmp(other.limbs().iter().rev())
+    }
+
+    /// Obtain the bits of the scalar in little-endian order
+    ///
+    /// The bits are taken from the canonical representation, i.e., the
+    /// integer in `[0, MODULUS)`. Since the modulus has 255 bits, the result
+    /// contains all non-zero bits.
+    ///
+    /// ```
+    /// use bls12_381_relic::Scalar;
+    ///
+    /// let bits = Scalar::from(0b1011u64).to_le_bits();
+    /// assert_eq!(bits[..5], [true, true, false, true, false]);
+    /// ```
+    pub fn to_le_bits(&self) -> [bool; 255] {
+        let bytes = self.to_bytes();
+        core::array::from_fn(|i| (bytes[31 - i / 8] >> (i % 8)) & 1 == 1)
+    }
+
+    /// Parse a scalar from its hexadecimal representation
+    ///
+    /// The digits may be prefixed with `0x` or `0X`. Both lower- and
+    /// upper-case digits are accepted. The value has to be smaller than the
+    /// group order.
+    ///
+    /// ```
+    /// use bls12_381_relic::Scalar;
+    ///
+    /// assert_eq!(Scalar::from_str_hex("0x2a").unwrap(), Scalar::from(42u8));
+    /// assert!(Scalar::from_str_hex("0x2g").is_err());
+    /// ```
+    pub fn from_str_hex(s: &str) -> Result<Self, ParseScalarError> {
+        let digits = strip_hex_prefix(s);
+        if digits.is_empty() {
+            return Err(ParseScalarError::Empty);
+        }
+
+        let mut bytes = [0u8; 32];
+        let digits = digits.trim_start_matches('0');
+        if digits.len() > 2 * bytes.len() {
+            return Err(digits
+                .chars()
+                .find(|c| !c.is_ascii_hexdigit())
+                .map_or(ParseScalarError::Overflow, ParseScalarError::InvalidDigit));
+        }
+        decode_hex(digits, &mut bytes).map_err(ParseScalarError::InvalidDigit)?;
+        Option::from(Self::from_bytes(&bytes)).ok_or(ParseScalarError::Overflow)
+    }
+
+    /// Parse a scalar from its decimal representation
+    ///
+    /// The value has to be smaller than the group order.
+    ///
+    /// ```
+    /// use bls12_381_relic::Scalar;
+    ///
+    /// assert_eq!(Scalar::from_str_decimal("42").unwrap(), Scalar::from(42u8));
+    /// assert!(Scalar::from_str_decimal("-42").is_err());
+    /// ```
+    pub fn from_str_decimal(s: &str) -> Result<Self, ParseScalarError> {
+        Self::from_str_radix(s, 10)
+    }
+
+    fn from_str_radix(digits: &str, radix: u32) -> Result<Self, ParseScalarError> {
+        if digits.is_empty() {
+            return Err(ParseScalarError::Empty);
+        }
+
+        let mut bytes = [0u8; 32];
+        for c in digits.chars() {
+            let digit = c.to_digit(radix).ok_or(ParseScalarError::InvalidDigit(c))?;
+            // bytes = bytes * radix + digit
+            let carry = bytes.iter_mut().rev().fold(digit, |carry, b| {
+                let v = *b as u32 * radix + carry;
+                *b = v as u8;
+                v >> 8
+            });
+            if carry != 0 {
+                return Err(ParseScalarError::Overflow);
+            }
+        }
+        Option::from(Self::from_bytes(&bytes)).ok_or(ParseScalarError::Overflow)
+    }
+
+    /// Obtain the hexadecimal representation prefixed with `0x`
+    ///
+    /// The representation consists of 64 lower-case digits and can be parsed
+    /// with [Self::from_str_hex].
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> String {
+        format!("{self:#x}")
+    }
+
+    /// Obtain the decimal representation
+    ///
+    /// The representation has no leading zeros and can be parsed with
+    /// [Self::from_str_decimal].
+    #[cfg(feature = "alloc")]
+    pub fn to_decimal(&self) -> String {
+        let mut bytes = self.to_bytes();
+        let mut digits = Vec::new();
+        loop {
+            // bytes = bytes / 10 with the remainder as next digit
+            let remainder = bytes.iter_mut().fold(0u32, |remainder, b| {
+                let v = (remainder << 8) | *b as u32;
+                *b = (v / 10) as u8;
+                v % 10
+            });
+            digits.push(char::from(b'0' + remainder as u8));
+            if bytes.iter().all(|b| *b == 0) {
+                break;
+            }
+        }
+        digits.iter().rev().collect()
+    }
+
     /// Decode scalar from bytes (internal)
     const fn from_bytes_internal(
         bytes0: [u8; 8],
@@ -92,9 +399,25 @@ impl Scalar {
         Self(bn)
     }
 
+    /// Check in constant time if bytes encode a value smaller than the modulus
+    fn is_canonical(bytes: &[u8; 32]) -> Choice {
+        // compute bytes - modulus and check if the subtraction borrows
+        let borrow = bytes
+            .iter()
+            .rev()
+            .zip(SCALAR_MODULUS.iter().rev())
+            .fold(0u16, |borrow, (b, m)| {
+                ((*b as u16).wrapping_sub(*m as u16).wrapping_sub(borrow) >> 15) & 1
+            });
+        Choice::from(borrow as u8)
+    }
+
     /// Decode scalar from bytes
+    ///
+    /// The result is none if the bytes do not encode a value smaller than the
+    /// modulus.
     pub fn from_bytes(bytes: &[u8; 32]) -> CtOption<Self> {
-        CtOption::new(Self::from(bytes), 1.into())
+        CtOption::new(Self::from(bytes), Self::is_canonical(bytes))
     }
 
     /// Decode scalar from bytes and reduce modulo the order
@@ -103,6 +426,145 @@ impl Scalar {
         unsafe { wrapper_bn_read_bin(&mut bn, bytes.as_ptr(), bytes.len(), true) };
         bn.into()
     }
+
+    /// Decode scalar from 48 bytes of output keying material
+    ///
+    /// The bytes are interpreted as big-endian integer and reduced modulo the
+    /// group order. This matches the reduction used by `hash_to_field` from
+    /// RFC 9380 and the key generation of BLS signatures.
+    pub fn from_okm(bytes: &[u8; 48]) -> Self {
+        let mut bn = new_wrapper();
+        unsafe { wrapper_bn_read_bin(&mut bn, bytes.as_ptr(), bytes.len(), true) };
+        bn.into()
+    }
+
+    /// Sample a uniformly random non-zero scalar
+    ///
+    /// Scalars are sampled as with [Field::random] until a non-zero scalar is
+    /// obtained. Hence, the result is guaranteed to be non-zero and is
+    /// uniformly distributed among the non-zero scalars. It is suitable for
+    /// nonces and blinding factors that must not be zero.
+    pub fn random_nonzero(mut rng: impl RngCore) -> Self {
+        loop {
+            let scalar = Self::random(&mut rng);
+            if !scalar.is_zero_vartime() {
+                return scalar;
+            }
+        }
+    }
+
+    /// Compute the inverse in variable time
+    ///
+    /// This function uses relic's inversion based on the extended Euclidean
+    /// algorithm. It is faster than [Field::invert], but its running time
+    /// depends on the input. It must therefore not be used with secret inputs.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        let mut value = new_wrapper();
+        let ret = unsafe { wrapper_bn_inv(&mut value, &self.0) };
+        CtOption::new(Self(value), ((ret == RLC_OK) as u8).into())
+    }
+
+    /// Compute the Legendre symbol
+    ///
+    /// Returns 1 if the scalar is a non-zero square, -1 if it is not a square,
+    /// and 0 if it is zero. The symbol is computed via Euler's criterion as
+    /// `self^((r - 1) / 2)`.
+    pub fn legendre_symbol(&self) -> i8 {
+        let euler = self.pow(MODULUS_MINUS_ONE_DIV_TWO);
+        let mut symbol = -1i8;
+        symbol.conditional_assign(&0, euler.ct_eq(&Self::ZERO));
+        symbol.conditional_assign(&1, euler.ct_eq(&Self::ONE));
+        symbol
+    }
+
+    /// Check whether the scalar is a quadratic residue
+    ///
+    /// This is the case if the scalar is a non-zero square, i.e., if its
+    /// [Legendre symbol](Scalar::legendre_symbol) is 1. The square root is not
+    /// computed.
+    pub fn is_quadratic_residue(&self) -> Choice {
+        self.pow(MODULUS_MINUS_ONE_DIV_TWO).ct_eq(&Self::ONE)
+    }
+
+    /// Compute the inverse if `choice` is set
+    ///
+    /// The result is none if `choice` is not set or the scalar is zero. The
+    /// inverse is always computed, so the running time is independent of
+    /// both `choice` and the scalar.
+    pub fn conditional_invert(&self, choice: Choice) -> CtOption<Self> {
+        self.invert()
+            .and_then(|inverse| CtOption::new(inverse, choice))
+    }
+
+    /// Divide by `rhs`
+    ///
+    /// The result is none if `rhs` is zero. In contrast to [Div], which panics
+    /// in this case, this function is suitable for untrusted inputs.
+    ///
+    /// ```
+    /// use bls12_381_relic::Scalar;
+    ///
+    /// let a = Scalar::from(6u64);
+    /// let b = Scalar::from(3u64);
+    /// assert_eq!(a.checked_div(&b).unwrap(), Scalar::from(2u64));
+    /// assert!(bool::from(a.checked_div(&Scalar::from(0u64)).is_none()));
+    /// ```
+    pub fn checked_div(&self, rhs: &Self) -> CtOption<Self> {
+        rhs.invert().map(|inverse| self * inverse)
+    }
+
+    /// Select an element from a table in constant time
+    ///
+    /// Returns `table[index]` or zero if `index` is out of range. The whole
+    /// table is scanned independently of `index`.
+    pub fn select_from_table(table: &[Self], index: u8) -> Self {
+        let index = index as u64;
+        table
+            .iter()
+            .enumerate()
+            .fold(Self::ZERO, |acc, (i, value)| {
+                Self::conditional_select(&acc, value, (i as u64).ct_eq(&index))
+            })
+    }
+
+    /// Hash a message to a scalar
+    ///
+    /// Implements `hash_to_field` from RFC 9380 for a single element of the
+    /// scalar field with `expand_message_xmd` and SHA-256. The 48 uniform
+    /// bytes are reduced modulo the group order as with [Scalar::from_okm],
+    /// hence the bias of the result is negligible. The domain separation tag
+    /// `dst` should be unique to the protocol and its use of the hash
+    /// function, e.g., for Fiat-Shamir challenges. Tags longer than 255 bytes
+    /// are hashed as specified by RFC 9380.
+    ///
+    /// ```
+    /// use bls12_381_relic::Scalar;
+    ///
+    /// let challenge = Scalar::hash_to_field(b"transcript", b"MY-PROTOCOL-V01-CHALLENGE");
+    /// assert_ne!(challenge, Scalar::hash_to_field(b"transcript", b"MY-PROTOCOL-V01-NONCE"));
+    /// ```
+    pub fn hash_to_field(msg: &[u8], dst: &[u8]) -> Self {
+        let mut bn = new_wrapper();
+        unsafe {
+            wrapper_bn_hash_to_field(&mut bn, msg.as_ptr(), msg.len(), dst.as_ptr(), dst.len());
+        }
+        bn.into()
+    }
+}
+
+/// Parsing of decimal or `0x`-prefixed hexadecimal representations
+///
+/// See [Scalar::from_str_decimal] and [Scalar::from_str_hex].
+impl FromStr for Scalar {
+    type Err = ParseScalarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("0x") || s.starts_with("0X") {
+            Self::from_str_hex(s)
+        } else {
+            Self::from_str_decimal(s)
+        }
+    }
 }
 
 impl AsRef<Scalar> for Scalar {
@@ -131,6 +593,10 @@ impl From<&wrapper_bn_t> for Scalar {
     }
 }
 
+/// Decode scalar from big-endian bytes and reduce modulo the order
+///
+/// Use [Scalar::from_bytes] to reject values that are not smaller than the
+/// modulus.
 impl From<[u8; 32]> for Scalar {
     #[inline(always)]
     fn from(value: [u8; 32]) -> Self {
@@ -138,11 +604,15 @@ impl From<[u8; 32]> for Scalar {
     }
 }
 
+/// Decode scalar from big-endian bytes and reduce modulo the order
+///
+/// Use [Scalar::from_bytes] to reject values that are not smaller than the
+/// modulus.
 impl From<&[u8; 32]> for Scalar {
     #[inline(always)]
     fn from(value: &[u8; 32]) -> Self {
         let mut bn = new_wrapper();
-        unsafe { wrapper_bn_read_bin(&mut bn, value.as_ptr(), value.len(), false) };
+        unsafe { wrapper_bn_read_bin(&mut bn, value.as_ptr(), value.len(), true) };
         bn.into()
     }
 }
@@ -178,17 +648,105 @@ impl From<u64> for Scalar {
     }
 }
 
+impl From<bool> for Scalar {
+    #[inline(always)]
+    fn from(value: bool) -> Self {
+        Self::from_u64(value as u64)
+    }
+}
+
+impl From<u8> for Scalar {
+    #[inline(always)]
+    fn from(value: u8) -> Self {
+        Self::from_u64(value as u64)
+    }
+}
+
+impl From<u16> for Scalar {
+    #[inline(always)]
+    fn from(value: u16) -> Self {
+        Self::from_u64(value as u64)
+    }
+}
+
+impl From<u32> for Scalar {
+    #[inline(always)]
+    fn from(value: u32) -> Self {
+        Self::from_u64(value as u64)
+    }
+}
+
+/// Conversion from little-endian `u64` limbs
+///
+/// Values that are not smaller than the group order are reduced.
+impl From<[u64; 4]> for Scalar {
+    fn from(value: [u64; 4]) -> Self {
+        let mut bn = new_wrapper();
+        bn[0].dp[..4].copy_from_slice(&value);
+        // relic expects no leading zero limbs
+        bn[0].used = value
+            .iter()
+            .rposition(|limb| *limb != 0)
+            .map_or(1, |idx| idx + 1) as _;
+        let mut ret = Self(bn);
+        ret.reduce();
+        ret
+    }
+}
+
+impl From<u128> for Scalar {
+    #[inline(always)]
+    fn from(value: u128) -> Self {
+        Self::from_u128(value)
+    }
+}
+
+impl From<i32> for Scalar {
+    #[inline(always)]
+    fn from(value: i32) -> Self {
+        Self::from_i64(value as i64)
+    }
+}
+
+impl From<i64> for Scalar {
+    #[inline(always)]
+    fn from(value: i64) -> Self {
+        Self::from_i64(value)
+    }
+}
+
+/// Decode scalar from 32 big-endian bytes
+///
+/// Inputs of any other length and values that are not smaller than the
+/// modulus are rejected with [Error::InvalidBytesRepresentation].
 impl TryFrom<&[u8]> for Scalar {
     type Error = Error;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        let mut bn = new_wrapper();
-        let ret = unsafe { wrapper_bn_read_bin(&mut bn, value.as_ptr(), value.len(), false) };
-        if ret == RLC_OK {
-            Ok(Self(bn))
-        } else {
-            Err(Error::RelicError(ret))
-        }
+        let bytes: &[u8; 32] = value
+            .try_into()
+            .map_err(|_| Error::InvalidBytesRepresentation)?;
+        Option::from(Self::from_bytes(bytes)).ok_or(Error::InvalidBytesRepresentation)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<Vec<u8>> for Scalar {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&Vec<u8>> for Scalar {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -328,6 +886,41 @@ impl Mul<Scalar> for &Scalar {
     }
 }
 
+/// Division in the scalar field
+///
+/// # Panics
+///
+/// Panics if the divisor is zero. Use [Scalar::checked_div] to handle this
+/// case without panicking.
+impl<S> Div<S> for Scalar
+where
+    S: AsRef<Self>,
+{
+    type Output = Scalar;
+
+    #[inline]
+    fn div(self, rhs: S) -> Self::Output {
+        (&self).div(rhs)
+    }
+}
+
+/// Division in the scalar field
+///
+/// # Panics
+///
+/// Panics if the divisor is zero. Use [Scalar::checked_div] to handle this
+/// case without panicking.
+impl<S> Div<S> for &Scalar
+where
+    S: AsRef<Scalar>,
+{
+    type Output = Scalar;
+
+    fn div(self, rhs: S) -> Self::Output {
+        Option::from(self.checked_div(rhs.as_ref())).expect("attempt to divide by zero")
+    }
+}
+
 impl<S> MulAssign<S> for Scalar
 where
     S: AsRef<Self>,
@@ -370,36 +963,85 @@ where
     }
 }
 
+/// Comparison of the limbs
+///
+/// Since scalars are kept reduced, equal scalars have equal limbs and the
+/// limbs can be compared directly without encoding the scalars first.
 impl ConstantTimeEq for Scalar {
+    #[inline]
     fn ct_eq(&self, other: &Self) -> Choice {
-        let lhs: [u8; 32] = self.into();
-        let rhs: [u8; 32] = other.into();
-        lhs.ct_eq(&rhs)
+        self.limbs().ct_eq(&other.limbs())
     }
 }
 
+/// Comparison of the canonical integer representations in `[0, MODULUS)`
+impl ConstantTimeGreater for Scalar {
+    fn ct_gt(&self, other: &Self) -> Choice {
+        let lhs = self.limbs();
+        let rhs = other.limbs();
+        // the most significant differing limb decides
+        let (gt, _) = lhs
+            .iter()
+            .zip(rhs.iter())
+            .rev()
+            .fold((Choice::from(0), Choice::from(1)), |(gt, eq), (l, r)| {
+                (gt | (eq & l.ct_gt(r)), eq & l.ct_eq(r))
+            });
+        gt
+    }
+}
+
+/// Comparison of the canonical integer representations in `[0, MODULUS)`
+impl ConstantTimeLess for Scalar {}
+
+/// Selection of the limbs
 impl ConditionallySelectable for Scalar {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
-        let lhs: [u8; 32] = a.into();
-        let rhs: [u8; 32] = b.into();
-        Self::from(<[u8; 32]>::conditional_select(&lhs, &rhs, choice))
+        let lhs = a.limbs();
+        let rhs = b.limbs();
+        let mut ret = new_wrapper();
+        // both inputs have no leading zero limbs and so does the result
+        ret[0].used =
+            u64::conditional_select(&(a.0[0].used as u64), &(b.0[0].used as u64), choice) as _;
+        for i in 0..lhs.len() {
+            ret[0].dp[i] = u64::conditional_select(&lhs[i], &rhs[i], choice);
+        }
+        Self(ret)
     }
 }
 
 impl PartialEq for Scalar {
+    #[inline]
     fn eq(&self, other: &Self) -> bool {
-        let lhs: [u8; 32] = self.into();
-        let rhs: [u8; 32] = other.into();
-        lhs.eq(&rhs)
+        self.ct_eq(other).into()
     }
 }
 
 impl Eq for Scalar {}
 
+impl_fmt!(
+    Scalar,
+    "Formatting as hex of the big-endian encoding",
+    Scalar::to_bytes,
+    Scalar::to_bytes
+);
+
+/// Hashing of the limbs
+///
+/// Equal scalars have equal limbs (see [ConstantTimeEq]), so hashing the limbs
+/// is consistent with [PartialEq].
+impl Hash for Scalar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.limbs().hash(state);
+    }
+}
+
 impl Field for Scalar {
-    const ZERO: Self = Self::from_u8(0);
+    // inherent constants take precedence over the trait constants, so these
+    // refer to the inherent ones
+    const ZERO: Self = Scalar::ZERO;
 
-    const ONE: Self = Self::from_u8(1);
+    const ONE: Self = Scalar::ONE;
 
     fn random(mut rng: impl RngCore) -> Self {
         // oversample by 64 bits
@@ -428,10 +1070,15 @@ impl Field for Scalar {
         Self(ret)
     }
 
+    /// Compute the inverse
+    ///
+    /// The inverse is computed via Fermat's little theorem as `self^(r - 2)`
+    /// using a fixed sequence of squarings, multiplications and constant-time
+    /// selections that does not depend on the value of `self`. Note that relic's
+    /// underlying multi-precision arithmetic is not guaranteed to be constant
+    /// time. See [Scalar::invert_vartime] for a faster variable-time version.
     fn invert(&self) -> CtOption<Self> {
-        let mut value = new_wrapper();
-        let ret = unsafe { wrapper_bn_inv(&mut value, &self.0) };
-        CtOption::new(Self(value), ((ret == RLC_OK) as u8).into())
+        CtOption::new(self.pow(MODULUS_MINUS_TWO), !self.is_zero())
     }
 
     fn sqrt_ratio(_num: &Self, _div: &Self) -> (Choice, Self) {
@@ -439,6 +1086,17 @@ impl Field for Scalar {
         unimplemented!("The wrapper has no use for this function.")
     }
 
+    /// Check whether the scalar is zero
+    ///
+    /// The check ORs the limbs of the reduced scalar and runs in constant
+    /// time. See [Field::is_zero_vartime] for a faster variable-time version.
+    fn is_zero(&self) -> Choice {
+        self.limbs()
+            .iter()
+            .fold(0, |acc, limb| acc | limb)
+            .ct_eq(&0)
+    }
+
     fn is_zero_vartime(&self) -> bool {
         unsafe { wrapper_bn_is_zero(&self.0) }
     }
@@ -448,7 +1106,7 @@ impl PrimeField for Scalar {
     type Repr = [u8; 32];
 
     fn from_repr(repr: Self::Repr) -> CtOption<Self> {
-        CtOption::new(Self::from(repr), 1.into())
+        Self::from_bytes(&repr)
     }
 
     fn to_repr(&self) -> Self::Repr {
@@ -470,45 +1128,34 @@ impl PrimeField for Scalar {
 
     const CAPACITY: u32 = 254;
 
-    const TWO_INV: Self = Self::from_bytes_internal(
-        [0x39, 0xf6, 0xd3, 0xa9, 0x94, 0xce, 0xbe, 0xa4],
-        [0x19, 0x9c, 0xec, 0x04, 0x04, 0xd0, 0xec, 0x02],
-        [0xa9, 0xde, 0xd2, 0x01, 0x7f, 0xff, 0x2d, 0xff],
-        [0x7f, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00, 0x01],
-    );
+    const TWO_INV: Self = Scalar::TWO_INV;
 
-    const MULTIPLICATIVE_GENERATOR: Self = Self::from_u8(7);
+    const MULTIPLICATIVE_GENERATOR: Self = Scalar::MULTIPLICATIVE_GENERATOR;
 
     const S: u32 = 32;
 
-    const ROOT_OF_UNITY: Self = Self::from_bytes_internal(
-        [0x16, 0xa2, 0xa1, 0x9e, 0xdf, 0xe8, 0x1f, 0x20],
-        [0xd0, 0x9b, 0x68, 0x19, 0x22, 0xc8, 0x13, 0xb4],
-        [0xb6, 0x36, 0x83, 0x50, 0x8c, 0x22, 0x80, 0xb9],
-        [0x38, 0x29, 0x97, 0x1f, 0x43, 0x9f, 0x0d, 0x2b],
-    );
+    const ROOT_OF_UNITY: Self = Scalar::ROOT_OF_UNITY;
 
-    const ROOT_OF_UNITY_INV: Self = Self::from_bytes_internal(
-        [0x05, 0x38, 0xa6, 0xf6, 0x6e, 0x19, 0xc6, 0x53],
-        [0xed, 0x4f, 0x2f, 0x74, 0xa3, 0x5d, 0x01, 0x68],
-        [0x6f, 0x67, 0xd4, 0xa2, 0xb5, 0x66, 0xf8, 0x33],
-        [0x0f, 0xb4, 0xd6, 0xe1, 0x3c, 0xf1, 0x9a, 0x78],
-    );
+    const ROOT_OF_UNITY_INV: Self = Scalar::ROOT_OF_UNITY_INV;
 
-    const DELTA: Self = Self::from_bytes_internal(
-        [0x08, 0x63, 0x4d, 0x0a, 0xa0, 0x21, 0xaa, 0xf8],
-        [0x43, 0xca, 0xb3, 0x54, 0xfa, 0xbb, 0x00, 0x62],
-        [0xf6, 0x50, 0x24, 0x37, 0xc6, 0xa0, 0x9c, 0x00],
-        [0x6c, 0x08, 0x34, 0x79, 0x59, 0x01, 0x89, 0xd7],
-    );
+    const DELTA: Self = Scalar::DELTA;
+}
+
+/// Sampling with [rand::Rng::gen] via [Field::random]
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Scalar> for rand::distributions::Standard {
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Scalar {
+        Scalar::random(rng)
+    }
 }
 
 #[cfg(feature = "zeroize")]
 impl zeroize::Zeroize for Scalar {
     fn zeroize(&mut self) {
-        unsafe {
-            librelic_sys::wrapper_bn_zero(&mut self.0);
-        }
+        zeroize_wrappers(core::slice::from_mut(&mut self.0));
+        // restore the size and sign of the limbs
+        self.0 = new_wrapper();
     }
 }
 
@@ -518,7 +1165,7 @@ impl serde::Serialize for Scalar {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_bytes(&self.to_bytes())
+        crate::serde_helpers::serialize_bytes(&self.to_bytes(), serializer)
     }
 }
 
@@ -528,43 +1175,69 @@ impl<'de> serde::Deserialize<'de> for Scalar {
     where
         D: serde::Deserializer<'de>,
     {
-        use serde::de;
-
-        struct BytesVisitor;
+        use serde::de::{Error, Unexpected};
 
-        impl de::Visitor<'_> for BytesVisitor {
-            type Value = Scalar;
-
-            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> alloc::fmt::Result {
-                write!(formatter, "a byte-encoded Scalar")
-            }
-
-            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                Scalar::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Bytes(v), &self))
-            }
-        }
-
-        deserializer.deserialize_bytes(BytesVisitor)
+        let bytes: [u8; 32] = crate::serde_helpers::deserialize(deserializer)?;
+        Option::from(Self::from_bytes(&bytes))
+            .ok_or_else(|| D::Error::invalid_value(Unexpected::Bytes(&bytes), &"a valid Scalar"))
     }
 }
 
 #[cfg(test)]
 mod test {
-    use librelic_sys::{wrapper_bn_one, wrapper_bn_zero};
+    use core::cmp::Ordering;
+    use librelic_sys::{
+        wrapper_bn_one, wrapper_bn_order, wrapper_bn_read_bin, wrapper_bn_zero, RLC_OK,
+    };
     use pairing::group::ff::{Field, PrimeField};
+    use subtle::{
+        Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+    };
 
-    use crate::scalar::new_wrapper;
+    use crate::{params::SCALAR_MODULUS, scalar::new_wrapper, Error};
 
-    use super::Scalar;
+    use super::{wrapper_bn_write_bin, ParseScalarError, Scalar, MODULUS_MINUS_TWO};
 
     #[test]
     fn from_u64() {
         assert_eq!(Scalar::from_u64(128), Scalar::from_u8(128));
     }
 
+    #[test]
+    fn from_small_integers() {
+        assert_eq!(Scalar::from(128u8), Scalar::from_u64(128));
+        assert_eq!(Scalar::from(u16::MAX), Scalar::from_u64(u16::MAX as u64));
+        assert_eq!(Scalar::from(u32::MAX), Scalar::from_u64(u32::MAX as u64));
+        assert_eq!(Scalar::from(7i32), Scalar::from_u64(7));
+        assert_eq!(Scalar::from(0i64), Scalar::ZERO);
+    }
+
+    #[test]
+    fn from_u128() {
+        assert_eq!(Scalar::from(42u128), Scalar::from_u64(42));
+        assert_eq!(Scalar::from(u64::MAX as u128), Scalar::from_u64(u64::MAX));
+
+        let two_64 = Scalar::from_u64(u64::MAX) + Scalar::ONE;
+        assert_eq!(Scalar::from(1u128 << 64), two_64);
+        assert_eq!(
+            Scalar::from(u128::MAX),
+            Scalar::from_u64(u64::MAX) * two_64 + Scalar::from_u64(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn from_signed() {
+        assert_eq!(Scalar::from(-1i64), -Scalar::ONE);
+        assert_eq!(Scalar::from(-1i32), -Scalar::ONE);
+        assert_eq!(Scalar::from(-42i64), -Scalar::from_u64(42));
+        assert_eq!(
+            Scalar::from(i64::MIN),
+            -Scalar::from_u64(i64::MIN.unsigned_abs())
+        );
+        assert_eq!(Scalar::from(i64::MAX), Scalar::from_u64(i64::MAX as u64));
+        assert_eq!(Scalar::from(-1i64) + Scalar::ONE, Scalar::ZERO);
+    }
+
     #[test]
     fn zero() {
         let mut zero_relic = new_wrapper();
@@ -583,6 +1256,131 @@ mod test {
         assert_eq!(scalar + scalar, Scalar::ZERO);
     }
 
+    #[test]
+    fn inherent_constants() {
+        const CONSTANTS: [Scalar; 7] = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            Scalar::MULTIPLICATIVE_GENERATOR,
+            Scalar::TWO_INV,
+            Scalar::ROOT_OF_UNITY,
+            Scalar::ROOT_OF_UNITY_INV,
+            Scalar::DELTA,
+        ];
+        assert_eq!(
+            CONSTANTS,
+            [
+                <Scalar as Field>::ZERO,
+                <Scalar as Field>::ONE,
+                <Scalar as PrimeField>::MULTIPLICATIVE_GENERATOR,
+                <Scalar as PrimeField>::TWO_INV,
+                <Scalar as PrimeField>::ROOT_OF_UNITY,
+                <Scalar as PrimeField>::ROOT_OF_UNITY_INV,
+                <Scalar as PrimeField>::DELTA,
+            ]
+        );
+        assert_eq!(Scalar::TWO_INV.double(), Scalar::ONE);
+        assert_eq!(
+            Scalar::ROOT_OF_UNITY * Scalar::ROOT_OF_UNITY_INV,
+            Scalar::ONE
+        );
+    }
+
+    #[test]
+    fn u64_limbs() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let s = Scalar::random(&mut rng);
+            assert_eq!(Scalar::from(s.to_u64_limbs()), s);
+        }
+
+        assert_eq!(Scalar::ZERO.to_u64_limbs(), [0; 4]);
+        assert_eq!(Scalar::from(42u64).to_u64_limbs(), [42, 0, 0, 0]);
+        assert_eq!((-Scalar::ONE).to_u64_limbs()[0], MODULUS_MINUS_TWO[0] + 1);
+        let two_128 = Scalar::from(u128::MAX) + Scalar::ONE;
+        assert_eq!(Scalar::from([0, 0, 1, 0]), two_128);
+        // non-reduced values
+        let mut modulus = MODULUS_MINUS_TWO;
+        modulus[0] += 2;
+        assert_eq!(Scalar::from(modulus), Scalar::ZERO);
+        assert_eq!(Scalar::from([u64::MAX; 4]), two_128.square() - Scalar::ONE);
+    }
+
+    #[test]
+    fn is_zero() {
+        assert!(bool::from(Scalar::ZERO.is_zero()));
+        assert!(bool::from((Scalar::ONE - Scalar::ONE).is_zero()));
+        for value in [
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::from(1u64 << 63),
+            Scalar::from(u64::MAX) * Scalar::from(u64::MAX),
+        ] {
+            assert!(!bool::from(value.is_zero()));
+            assert!(!value.is_zero_vartime());
+        }
+    }
+
+    #[test]
+    fn random_nonzero() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            assert!(!Scalar::random_nonzero(&mut rng).is_zero_vartime());
+        }
+
+        /// RNG that returns zeros for the first `zeros` calls
+        struct ZeroFirst {
+            zeros: usize,
+        }
+
+        impl rand_core::RngCore for ZeroFirst {
+            fn next_u32(&mut self) -> u32 {
+                self.next_u64() as u32
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                let mut bytes = [0u8; 8];
+                self.fill_bytes(&mut bytes);
+                u64::from_le_bytes(bytes)
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                if self.zeros > 0 {
+                    self.zeros -= 1;
+                    dest.fill(0);
+                } else {
+                    dest.fill(0x42);
+                }
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+
+        assert!(Scalar::random(ZeroFirst { zeros: 1 }).is_zero_vartime());
+        let mut rng = ZeroFirst { zeros: 2 };
+        assert_eq!(
+            Scalar::random_nonzero(&mut rng),
+            Scalar::random(ZeroFirst { zeros: 0 })
+        );
+        assert_eq!(rng.zeros, 0);
+    }
+
+    #[test]
+    fn modulus_from_relic() {
+        let mut order = new_wrapper();
+        let mut bytes = [0u8; 32];
+        unsafe {
+            wrapper_bn_order(&mut order);
+            wrapper_bn_write_bin(bytes.as_mut_ptr(), bytes.len(), &order);
+        }
+        assert_eq!(bytes, SCALAR_MODULUS);
+        let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+        assert_eq!(Scalar::MODULUS, format!("0x{hex}"));
+    }
+
     #[test]
     fn one() {
         let mut one_relic = new_wrapper();
@@ -611,6 +1409,464 @@ mod test {
         assert_eq!(two_inverse * two, Scalar::ONE);
     }
 
+    #[test]
+    fn quadratic_residue() {
+        let four = Scalar::from(4u64);
+        assert!(bool::from(four.is_quadratic_residue()));
+        assert_eq!(four.legendre_symbol(), 1);
+        // r = 1 mod 4, hence -1 is a square
+        assert!(bool::from((-Scalar::ONE).is_quadratic_residue()));
+
+        let generator = Scalar::MULTIPLICATIVE_GENERATOR;
+        assert!(!bool::from(generator.is_quadratic_residue()));
+        assert_eq!(generator.legendre_symbol(), -1);
+
+        assert!(!bool::from(Scalar::ZERO.is_quadratic_residue()));
+        assert_eq!(Scalar::ZERO.legendre_symbol(), 0);
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..8 {
+            let s = Scalar::random(&mut rng);
+            assert_eq!(s.square().legendre_symbol(), 1);
+            assert_eq!((s.square() * generator).legendre_symbol(), -1);
+        }
+    }
+
+    #[test]
+    fn invert() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..8 {
+            let s = Scalar::random(&mut rng);
+            let inverse = s.invert().unwrap();
+            assert_eq!(inverse, s.invert_vartime().unwrap());
+            assert_eq!(inverse * s, Scalar::ONE);
+        }
+
+        assert_eq!(Scalar::ZERO.invert().is_none().unwrap_u8(), 1);
+        assert_eq!(Scalar::ZERO.invert_vartime().is_none().unwrap_u8(), 1);
+    }
+
+    #[test]
+    fn reduce() {
+        let mut bytes = SCALAR_MODULUS;
+        bytes[31] += 5;
+        let mut bn = new_wrapper();
+        assert_eq!(
+            unsafe { wrapper_bn_read_bin(&mut bn, bytes.as_ptr(), bytes.len(), false) },
+            RLC_OK
+        );
+
+        let mut s = Scalar::from(bn);
+        assert_eq!(s.reduced(), Scalar::from_u64(5));
+        s.reduce();
+        assert_eq!(s, Scalar::from_u64(5));
+
+        let mut rng = rand::thread_rng();
+        let s = Scalar::random(&mut rng);
+        assert_eq!(s.reduced(), s);
+    }
+
+    #[test]
+    fn eq() {
+        let mut rng = rand::thread_rng();
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+        assert_eq!(a * b, b * a);
+        assert_eq!(a - a, Scalar::ZERO);
+        assert_eq!(a + b - b, a);
+        assert_ne!(a + Scalar::ONE, a);
+        assert!(bool::from((a * b).ct_eq(&(b * a))));
+        assert!(!bool::from(a.ct_eq(&(a + Scalar::ONE))));
+
+        // limbs beyond the used ones are ignored
+        let mut bn = new_wrapper();
+        bn[0].dp[0] = 42;
+        bn[0].dp[1] = 0xdeadbeef;
+        assert_eq!(Scalar::from(bn), Scalar::from_u64(42));
+    }
+
+    #[test]
+    fn compare() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            let ordering = a.to_bytes().cmp(&b.to_bytes());
+            assert_eq!(a.cmp_vartime(&b), ordering);
+            assert_eq!(bool::from(a.ct_gt(&b)), ordering == Ordering::Greater);
+            assert_eq!(bool::from(a.ct_lt(&b)), ordering == Ordering::Less);
+        }
+
+        let a = Scalar::random(&mut rng);
+        assert_eq!(a.cmp_vartime(&a), Ordering::Equal);
+        assert!(!bool::from(a.ct_gt(&a)));
+        assert!(!bool::from(a.ct_lt(&a)));
+
+        // differing only in the least significant limb
+        let b = Scalar::from(1u128 << 64);
+        assert_eq!((b + Scalar::ONE).cmp_vartime(&b), Ordering::Greater);
+        assert!(bool::from(b.ct_lt(&(b + Scalar::ONE))));
+        assert!(bool::from((-Scalar::ONE).ct_gt(&Scalar::ZERO)));
+    }
+
+    #[test]
+    fn to_le_bits() {
+        let bits = Scalar::from_u64(0b1011).to_le_bits();
+        assert_eq!(bits[..4], [true, true, false, true]);
+        assert!(bits[4..].iter().all(|bit| !bit));
+
+        assert!(Scalar::ZERO.to_le_bits().iter().all(|bit| !bit));
+
+        let mut rng = rand::thread_rng();
+        for s in [-Scalar::ONE, Scalar::random(&mut rng)] {
+            let bits = s.to_le_bits();
+            let v = bits.iter().rev().fold(Scalar::ZERO, |acc, bit| {
+                acc.double() + if *bit { Scalar::ONE } else { Scalar::ZERO }
+            });
+            assert_eq!(v, s);
+        }
+
+        // MODULUS - 1 is even and has its top bit set
+        let bits = (-Scalar::ONE).to_le_bits();
+        assert!(!bits[0]);
+        assert!(bits[254]);
+    }
+
+    #[test]
+    fn div() {
+        let mut rng = rand::thread_rng();
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+
+        let c = a / b;
+        assert_eq!(c * b, a);
+        let ra = &a;
+        let rb = &b;
+        assert_eq!(a / rb, c);
+        assert_eq!(ra / b, c);
+        assert_eq!(ra / rb, c);
+        assert_eq!(a.checked_div(&b).unwrap(), c);
+        assert_eq!(Scalar::from(6u64) / Scalar::from(3u64), Scalar::from(2u64));
+        assert_eq!(Scalar::ZERO / b, Scalar::ZERO);
+
+        assert_eq!(a.checked_div(&Scalar::ZERO).is_none().unwrap_u8(), 1);
+        assert_eq!(
+            Scalar::ZERO
+                .checked_div(&Scalar::ZERO)
+                .is_none()
+                .unwrap_u8(),
+            1
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_by_zero() {
+        let _ = Scalar::ONE / Scalar::ZERO;
+    }
+
+    #[test]
+    fn conditional_invert() {
+        let mut rng = rand::thread_rng();
+        let s = Scalar::random(&mut rng);
+
+        assert_eq!(
+            s.conditional_invert(Choice::from(1)).unwrap(),
+            s.invert().unwrap()
+        );
+        assert_eq!(
+            s.conditional_invert(Choice::from(0)).is_none().unwrap_u8(),
+            1
+        );
+        assert_eq!(
+            Scalar::ZERO
+                .conditional_invert(Choice::from(1))
+                .is_none()
+                .unwrap_u8(),
+            1
+        );
+        assert_eq!(
+            Scalar::ZERO
+                .conditional_invert(Choice::from(0))
+                .is_none()
+                .unwrap_u8(),
+            1
+        );
+    }
+
+    #[test]
+    fn from_bytes() {
+        let mut modulus_minus_one = SCALAR_MODULUS;
+        modulus_minus_one[31] -= 1;
+        assert_eq!(
+            Scalar::from_bytes(&modulus_minus_one).unwrap(),
+            -Scalar::ONE
+        );
+        assert_eq!(Scalar::from_repr(modulus_minus_one).unwrap(), -Scalar::ONE);
+
+        assert!(bool::from(Scalar::from_bytes(&SCALAR_MODULUS).is_none()));
+        assert!(bool::from(Scalar::from_repr(SCALAR_MODULUS).is_none()));
+        assert!(bool::from(Scalar::from_bytes(&[0xff; 32]).is_none()));
+        assert!(bool::from(Scalar::from_repr([0xff; 32]).is_none()));
+
+        assert_eq!(Scalar::from_bytes(&[0; 32]).unwrap(), Scalar::ZERO);
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let s = Scalar::from(0x1234u64);
+        let bytes = s.to_bytes();
+        assert_eq!(Scalar::try_from(&bytes[..]).unwrap(), s);
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(Scalar::try_from(&bytes.to_vec()).unwrap(), s);
+            assert_eq!(Scalar::try_from(bytes.to_vec()).unwrap(), s);
+        }
+
+        for bytes in [&[0xff; 32][..], &SCALAR_MODULUS, &[0; 31], &[0; 33], &[]] {
+            assert!(matches!(
+                Scalar::try_from(bytes),
+                Err(Error::InvalidBytesRepresentation)
+            ));
+        }
+    }
+
+    #[test]
+    fn from_unreduced_bytes() {
+        // 2^256 - 1
+        let max = Scalar::from([0xff; 32]);
+        assert_eq!(max, Scalar::from([u64::MAX; 4]));
+        assert_eq!(
+            max.to_u64_limbs(),
+            Scalar::from([u64::MAX; 4]).to_u64_limbs()
+        );
+        assert_eq!(max + Scalar::ONE, Scalar::from(2u8).pow_vartime([256]));
+        assert!(bool::from(max.ct_eq(&Scalar::from([u64::MAX; 4]))));
+        assert!(bool::from(max.ct_lt(&-Scalar::ONE)));
+
+        let modulus = Scalar::from(SCALAR_MODULUS);
+        assert_eq!(modulus, Scalar::ZERO);
+        assert!(bool::from(modulus.is_zero()));
+        assert_eq!(modulus.to_u64_limbs(), [0; 4]);
+        assert!(modulus.to_le_bits().iter().all(|bit| !bit));
+        assert_eq!(Scalar::from(&SCALAR_MODULUS).to_bytes(), [0; 32]);
+    }
+
+    #[test]
+    fn format() {
+        let one = "0000000000000000000000000000000000000000000000000000000000000001";
+        assert_eq!(format!("{}", Scalar::ONE), one);
+        assert_eq!(format!("{:x}", Scalar::ONE), one);
+        assert_eq!(
+            format!("{:#X}", Scalar::from_u64(0xab)),
+            format!("0x{:0>64}", "AB")
+        );
+        assert_eq!(format!("{:?}", Scalar::ONE), format!("Scalar(0x{one})"));
+    }
+
+    #[test]
+    fn hash_to_field() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+        assert_eq!(
+            Scalar::hash_to_field(b"", DST),
+            Scalar::from_str_hex(
+                "0x2f56a64b865d6feb71a064ce5af39c4e1e99d62bbe3ad67415075c862d43cd6e"
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Scalar::hash_to_field(b"abc", DST),
+            Scalar::from_str_hex(
+                "0x25de2d06c63a80fbddfa3d574a394db9b5367ea15dbeec23dd4b580826da6270"
+            )
+            .unwrap()
+        );
+
+        #[cfg(feature = "xmd")]
+        {
+            let mut okm = [0u8; 48];
+            crate::xmd::expand_message_xmd(&[b"message"], b"other dst", &mut okm);
+            assert_eq!(
+                Scalar::hash_to_field(b"message", b"other dst"),
+                Scalar::from_okm(&okm)
+            );
+        }
+        assert_ne!(
+            Scalar::hash_to_field(b"message", b"other dst"),
+            Scalar::hash_to_field(b"message", DST)
+        );
+
+        // DSTs longer than 255 bytes are hashed first
+        let long_dst = [b'a'; 300];
+        assert_eq!(
+            Scalar::hash_to_field(b"abc", &long_dst),
+            Scalar::from_str_hex(
+                "0x368ed8f73db4ef04e4b1ad922bdc1722c25017db79a1c098cbbf933d1420b1e5"
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Scalar::hash_to_field(b"abc", &long_dst[..255]),
+            Scalar::from_str_hex(
+                "0x314d803811229f471a1a9d51b38b98976fbe0a36b79409ceb1f42b7e55c6081a"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn hash_set() {
+        let set: std::collections::HashSet<_> = [
+            Scalar::from_u64(2),
+            Scalar::ONE.double(),
+            Scalar::ONE + Scalar::ONE,
+            Scalar::from_bytes(&Scalar::from_u64(2).to_bytes()).unwrap(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn from_bool() {
+        assert_eq!(Scalar::from(true), Scalar::ONE);
+        assert_eq!(Scalar::from(false), Scalar::ZERO);
+    }
+
+    #[test]
+    fn conditional_assign() {
+        let mut rng = rand::thread_rng();
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+        let small = Scalar::from(42u8);
+
+        let mut c = a;
+        c.conditional_assign(&b, Choice::from(0));
+        assert_eq!(c, a);
+        c.conditional_assign(&b, Choice::from(1));
+        assert_eq!(c, b);
+        c.conditional_assign(&small, Choice::from(1));
+        assert_eq!(c, small);
+        assert_eq!(c + Scalar::ONE, Scalar::from(43u8));
+        c.conditional_assign(&a, Choice::from(1));
+        assert_eq!(c, a);
+        assert_eq!(c.to_bytes(), a.to_bytes());
+    }
+
+    #[test]
+    fn select_from_table() {
+        let mut rng = rand::thread_rng();
+        let table: [Scalar; 8] = core::array::from_fn(|_| Scalar::random(&mut rng));
+
+        for (index, value) in table.iter().enumerate() {
+            assert_eq!(Scalar::select_from_table(&table, index as u8), *value);
+        }
+        assert_eq!(Scalar::select_from_table(&table, 8), Scalar::ZERO);
+        assert_eq!(Scalar::select_from_table(&[], 0), Scalar::ZERO);
+    }
+
+    #[test]
+    fn from_str() {
+        const ORDER_MINUS_ONE: &str =
+            "52435875175126190479447740508185965837690552500527637822603658699938581184512";
+        const ORDER: &str =
+            "52435875175126190479447740508185965837690552500527637822603658699938581184513";
+
+        let minus_one = -Scalar::ONE;
+        assert_eq!(Scalar::from_str_decimal(ORDER_MINUS_ONE), Ok(minus_one));
+        assert_eq!(ORDER_MINUS_ONE.parse(), Ok(minus_one));
+        assert_eq!(
+            Scalar::from_str_hex(Scalar::MODULUS),
+            Err(ParseScalarError::Overflow)
+        );
+        assert_eq!(
+            Scalar::from_str_decimal(ORDER),
+            Err(ParseScalarError::Overflow)
+        );
+        assert_eq!(
+            Scalar::from_str_decimal(&["1"; 80].concat()),
+            Err(ParseScalarError::Overflow)
+        );
+
+        assert_eq!("0".parse(), Ok(Scalar::ZERO));
+        assert_eq!("000042".parse(), Ok(Scalar::from(42u8)));
+        assert_eq!("0x2A".parse(), Ok(Scalar::from(42u8)));
+        assert_eq!(Scalar::from_str_hex("2a"), Ok(Scalar::from(42u8)));
+
+        assert_eq!("".parse::<Scalar>(), Err(ParseScalarError::Empty));
+        assert_eq!("0x".parse::<Scalar>(), Err(ParseScalarError::Empty));
+        assert_eq!(
+            "12a".parse::<Scalar>(),
+            Err(ParseScalarError::InvalidDigit('a'))
+        );
+        assert_eq!(
+            "0x+1".parse::<Scalar>(),
+            Err(ParseScalarError::InvalidDigit('+'))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_str() {
+        let s = Scalar::random(rand::thread_rng());
+        assert_eq!(Scalar::from_str_decimal(&s.to_decimal()), Ok(s));
+        assert_eq!(Scalar::from_str_hex(&s.to_hex()), Ok(s));
+
+        assert_eq!(Scalar::ZERO.to_decimal(), "0");
+        assert_eq!(Scalar::from(1234567890u64).to_decimal(), "1234567890");
+        assert_eq!(
+            (-Scalar::ONE).to_decimal(),
+            "52435875175126190479447740508185965837690552500527637822603658699938581184512"
+        );
+        assert_eq!(Scalar::from(42u8).to_hex(), format!("0x{:0>64}", "2a"));
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut s = Scalar::random(rand::thread_rng());
+        s.zeroize();
+        assert!(s.0[0].dp.iter().all(|d| *d == 0));
+        assert_eq!(s, Scalar::ZERO);
+        assert!(bool::from(s.is_zero()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_serialization() {
+        let s1 = Scalar::random(rand::thread_rng());
+
+        let bytes = bincode::serialize(&s1).unwrap();
+        let s2: Scalar = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(s1, s2);
+
+        let json = serde_json::to_string(&s1).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                "\"{}\"",
+                s1.to_bytes()
+                    .iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<String>()
+            )
+        );
+        let s3: Scalar = serde_json::from_str(&json).unwrap();
+        assert_eq!(s2, s3);
+
+        assert_eq!(
+            serde_json::to_string(&Scalar::from_u64(0x0102)).unwrap(),
+            "\"0000000000000000000000000000000000000000000000000000000000000102\""
+        );
+        assert!(serde_json::from_str::<Scalar>("\"0102\"").is_err());
+        assert!(
+            bincode::deserialize::<Scalar>(&bincode::serialize(&[0u8; 31][..]).unwrap()).is_err()
+        );
+    }
+
     #[test]
     fn root_of_unity() {
         assert_eq!(
@@ -0,0 +1,89 @@
+//! Schnorr proofs of knowledge of discrete logarithms in `G1`
+//!
+//! This module implements non-interactive proofs of knowledge of a discrete
+//! logarithm `sk` of `pk = base * sk` with respect to some `base` in `G1`. The
+//! proofs are obtained by applying the Fiat-Shamir transform to Schnorr's
+//! protocol, i.e., the challenge is derived by hashing the base, the public
+//! key, and the commitment to a scalar.
+//!
+//! ```
+//! use bls12_381_relic::{schnorr, G1Projective, Scalar};
+//! use bls12_381_relic::{group::Group, ff::Field};
+//!
+//! let mut rng = rand::thread_rng();
+//! let base = G1Projective::generator();
+//! let sk = Scalar::random(&mut rng);
+//! let pk = base * sk;
+//!
+//! let proof = schnorr::prove(&sk, &base, &mut rng);
+//! assert!(schnorr::verify(&pk, &base, &proof));
+//! ```
+
+use pairing::group::{ff::Field, GroupEncoding};
+use rand_core::RngCore;
+
+use crate::{g1::COMPRESSED_BYTES_SIZE, G1Projective, Scalar};
+
+const CHALLENGE_DOMAIN_SEPERATOR: &[u8] = b"BLS12381G1_SCHNORR_POK_CHALLENGE";
+
+/// Proof consisting of the commitment and the response
+pub type Proof = (G1Projective, Scalar);
+
+fn challenge(pk: &G1Projective, base: &G1Projective, commitment: &G1Projective) -> Scalar {
+    let mut buf = [0u8; 3 * COMPRESSED_BYTES_SIZE];
+    buf[..COMPRESSED_BYTES_SIZE].copy_from_slice(&base.to_bytes());
+    buf[COMPRESSED_BYTES_SIZE..2 * COMPRESSED_BYTES_SIZE].copy_from_slice(&pk.to_bytes());
+    buf[2 * COMPRESSED_BYTES_SIZE..].copy_from_slice(&commitment.to_bytes());
+    Scalar::hash_to_field(&buf, CHALLENGE_DOMAIN_SEPERATOR)
+}
+
+/// Prove knowledge of `sk` such that `pk = base * sk`
+pub fn prove(sk: &Scalar, base: &G1Projective, rng: impl RngCore) -> Proof {
+    let r = Scalar::random(rng);
+    let commitment = base * r;
+    let c = challenge(&(base * sk), base, &commitment);
+    (commitment, r + c * sk)
+}
+
+/// Verify a proof of knowledge of the discrete logarithm of `pk` with respect
+/// to `base`
+pub fn verify(pk: &G1Projective, base: &G1Projective, proof: &Proof) -> bool {
+    let (commitment, response) = proof;
+    let c = challenge(pk, base, commitment);
+    base * response == commitment + pk * c
+}
+
+#[cfg(test)]
+mod test {
+    use pairing::group::Group;
+
+    use super::*;
+
+    #[test]
+    fn valid_proof() {
+        let mut rng = rand::thread_rng();
+        let base = G1Projective::random(&mut rng);
+        let sk = Scalar::random(&mut rng);
+        let pk = base * sk;
+
+        let proof = prove(&sk, &base, &mut rng);
+        assert!(verify(&pk, &base, &proof));
+    }
+
+    #[test]
+    fn forged_proof() {
+        let mut rng = rand::thread_rng();
+        let base = G1Projective::random(&mut rng);
+        let sk = Scalar::random(&mut rng);
+        let pk = base * sk;
+
+        let proof = prove(&sk, &base, &mut rng);
+        assert!(!verify(&(pk + base), &base, &proof));
+        assert!(!verify(&pk, &G1Projective::generator(), &proof));
+        assert!(!verify(&pk, &base, &(proof.0 + base, proof.1)));
+        assert!(!verify(&pk, &base, &(proof.0, proof.1 + Scalar::ONE)));
+
+        let proof = prove(&(sk + Scalar::ONE), &base, &mut rng);
+        assert!(!verify(&pk, &base, &proof));
+    }
+}
@@ -1,11 +1,17 @@
-use core::marker::PhantomData;
+use core::{fmt, marker::PhantomData};
 
 use pairing::group::GroupEncoding;
 use serde::{
     de::{self, Visitor},
-    Deserializer, Serializer,
+    ser, Deserializer, Serializer,
 };
 
+/// Maximal size of the encoded elements (compressed elements of `Gt`)
+///
+/// Deserialization of `Gt` also accepts the 288 bytes of the torus-based
+/// encoding, which fits as well.
+const MAX_BYTES_SIZE: usize = 384;
+
 struct BytesVisitor<T>(PhantomData<T>);
 
 impl<T> Visitor<'_> for BytesVisitor<T>
@@ -14,8 +20,8 @@ where
 {
     type Value = T;
 
-    fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
-        write!(formatter, "a byte-encoded Scalar")
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a byte-encoded or hex-encoded element")
     }
 
     fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
@@ -24,6 +30,22 @@ where
     {
         T::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Bytes(v), &self))
     }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.len() > 2 * MAX_BYTES_SIZE {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        let mut bytes = [0u8; MAX_BYTES_SIZE];
+        let bytes = &mut bytes[..v.len() / 2];
+        if v.len() % 2 != 0 || crate::decode_hex(v, bytes).is_err() {
+            return Err(E::invalid_value(de::Unexpected::Str(v), &self));
+        }
+        T::try_from(bytes).map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
 }
 
 pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
@@ -32,7 +54,29 @@ where
     <T as GroupEncoding>::Repr: AsRef<[u8]>,
     S: Serializer,
 {
-    serializer.serialize_bytes(value.to_bytes().as_ref())
+    serialize_bytes(value.to_bytes().as_ref(), serializer)
+}
+
+pub fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if !serializer.is_human_readable() {
+        return serializer.serialize_bytes(bytes);
+    }
+
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut buffer = [0u8; 2 * MAX_BYTES_SIZE];
+    let buffer = &mut buffer[..2 * bytes.len()];
+    buffer
+        .chunks_exact_mut(2)
+        .zip(bytes)
+        .for_each(|(digits, b)| {
+            digits[0] = DIGITS[usize::from(b >> 4)];
+            digits[1] = DIGITS[usize::from(b & 0xf)];
+        });
+    serializer.serialize_str(core::str::from_utf8(buffer).map_err(ser::Error::custom)?)
 }
 
 pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
@@ -40,5 +84,9 @@ where
     D: Deserializer<'de>,
     T: for<'a> TryFrom<&'a [u8]>,
 {
-    deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+    }
 }
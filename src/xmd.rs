@@ -0,0 +1,110 @@
+//! `expand_message_xmd` from RFC 9380 with SHA-256
+//!
+//! Relic only provides this function for contiguous messages. This
+//! implementation accepts messages split into multiple parts.
+
+use sha2::{Digest, Sha256};
+
+/// Number of bytes hashed per field element (`L` in RFC 9380)
+///
+/// `L = ceil((ceil(log2(p)) + k) / 8)` with `k = 128`. This matches the
+/// length used by relic's hash to curve.
+pub(crate) const FIELD_ELEMENT_BYTES: usize = (381 + 128 + 7) / 8;
+
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Expand the message given in parts into `out.len()` uniform bytes
+///
+/// The output is the same as for the concatenation of all parts.
+///
+/// # Panics
+///
+/// Panics if more than 8160 bytes are requested.
+pub(crate) fn expand_message_xmd(parts: &[&[u8]], dst: &[u8], out: &mut [u8]) {
+    const B_IN_BYTES: usize = 32;
+    const S_IN_BYTES: usize = 64;
+
+    let ell = (out.len() + B_IN_BYTES - 1) / B_IN_BYTES;
+    assert!(ell <= 255, "requested too many bytes");
+
+    let hashed_dst;
+    let dst = if dst.len() > 255 {
+        hashed_dst = Sha256::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize();
+        &hashed_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let mut hasher = Sha256::new().chain_update([0u8; S_IN_BYTES]);
+    for part in parts {
+        hasher.update(part);
+    }
+    let b_0 = hasher
+        .chain_update((out.len() as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    let mut b_i = [0u8; B_IN_BYTES];
+    for (i, chunk) in out.chunks_mut(B_IN_BYTES).enumerate() {
+        b_i.iter_mut().zip(b_0.iter()).for_each(|(b, b0)| *b ^= b0);
+        b_i = Sha256::new()
+            .chain_update(b_i)
+            .chain_update([i as u8 + 1])
+            .chain_update(dst)
+            .chain_update(dst_len)
+            .finalize()
+            .into();
+        chunk.copy_from_slice(&b_i[..chunk.len()]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        core::array::from_fn(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap())
+    }
+
+    #[test]
+    fn test_vectors() {
+        let mut out = [0u8; 0x20];
+        expand_message_xmd(&[b""], DST, &mut out);
+        assert_eq!(
+            out,
+            from_hex("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235")
+        );
+
+        expand_message_xmd(&[b"abc"], DST, &mut out);
+        assert_eq!(
+            out,
+            from_hex("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615")
+        );
+
+        let mut out = [0u8; 0x80];
+        expand_message_xmd(&[], DST, &mut out);
+        assert_eq!(
+            out,
+            from_hex(
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced"
+            )
+        );
+    }
+
+    #[test]
+    fn chunks() {
+        let mut out1 = [0u8; 2 * FIELD_ELEMENT_BYTES];
+        let mut out2 = [0u8; 2 * FIELD_ELEMENT_BYTES];
+        expand_message_xmd(&[b"abcdef"], DST, &mut out1);
+        expand_message_xmd(&[b"ab", b"", b"cde", b"f"], DST, &mut out2);
+        assert_eq!(out1, out2);
+    }
+}
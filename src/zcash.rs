@@ -0,0 +1,94 @@
+//! Helpers for the Zcash serialization format
+//!
+//! The format is used by the `bls12_381` crate and most other implementations
+//! of BLS12-381. In contrast to relic's format, it does not prepend a byte
+//! storing the format, but stores three flags in the most significant bits of
+//! the x-coordinate:
+//!
+//! * the compression flag,
+//! * the infinity flag, and
+//! * the sort flag which is set if the y-coordinate is the lexicographically
+//!   largest of `y` and `-y`.
+//!
+//! Elements of `Fp2` are encoded as `c1 || c0`.
+
+use crate::params::FIELD_MODULUS;
+
+/// Size of an encoded element of `Fp`
+pub(crate) const FP_BYTES_SIZE: usize = 48;
+
+pub(crate) const FLAG_COMPRESSED: u8 = 0x80;
+pub(crate) const FLAG_INFINITY: u8 = 0x40;
+pub(crate) const FLAG_SORT: u8 = 0x20;
+pub(crate) const FLAGS_MASK: u8 = FLAG_COMPRESSED | FLAG_INFINITY | FLAG_SORT;
+
+/// `(p - 1) / 2` (big-endian)
+const HALF_FIELD_MODULUS: [u8; FP_BYTES_SIZE] = {
+    let mut half = [0u8; FP_BYTES_SIZE];
+    let mut carry = 0;
+    let mut i = 0;
+    while i < FP_BYTES_SIZE {
+        half[i] = (FIELD_MODULUS[i] >> 1) | carry;
+        carry = (FIELD_MODULUS[i] & 1) << 7;
+        i += 1;
+    }
+    half
+};
+
+/// Check if an element of `Fp` is lexicographically largest, i.e., larger than
+/// `(p - 1) / 2`
+pub(crate) fn fp_is_lexicographically_largest(y: &[u8]) -> bool {
+    debug_assert_eq!(y.len(), FP_BYTES_SIZE);
+    y > &HALF_FIELD_MODULUS[..]
+}
+
+/// Check if an element `c0 + c1 u` of `Fp2` is lexicographically largest
+pub(crate) fn fp2_is_lexicographically_largest(c0: &[u8], c1: &[u8]) -> bool {
+    if c1.iter().any(|b| *b != 0) {
+        fp_is_lexicographically_largest(c1)
+    } else {
+        fp_is_lexicographically_largest(c0)
+    }
+}
+
+/// Check if the encoding of the point at infinity is well-formed
+pub(crate) fn is_valid_infinity(bytes: &[u8]) -> bool {
+    bytes[0] == FLAG_COMPRESSED | FLAG_INFINITY && bytes[1..].iter().all(|b| *b == 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn half_field_modulus() {
+        // 2 * half + 1 == p
+        let mut carry = 1u16;
+        let mut doubled = [0u8; FP_BYTES_SIZE];
+        for i in (0..FP_BYTES_SIZE).rev() {
+            let v = ((HALF_FIELD_MODULUS[i] as u16) << 1) + carry;
+            doubled[i] = v as u8;
+            carry = v >> 8;
+        }
+        assert_eq!(carry, 0);
+        assert_eq!(doubled, FIELD_MODULUS);
+    }
+
+    #[test]
+    fn lexicographically_largest() {
+        let zero = [0u8; FP_BYTES_SIZE];
+        let mut one = [0u8; FP_BYTES_SIZE];
+        one[FP_BYTES_SIZE - 1] = 1;
+        let mut half_plus_one = HALF_FIELD_MODULUS;
+        half_plus_one[FP_BYTES_SIZE - 1] += 1;
+
+        assert!(!fp_is_lexicographically_largest(&zero));
+        assert!(!fp_is_lexicographically_largest(&one));
+        assert!(!fp_is_lexicographically_largest(&HALF_FIELD_MODULUS));
+        assert!(fp_is_lexicographically_largest(&half_plus_one));
+
+        assert!(fp2_is_lexicographically_largest(&zero, &half_plus_one));
+        assert!(!fp2_is_lexicographically_largest(&half_plus_one, &one));
+        assert!(fp2_is_lexicographically_largest(&half_plus_one, &zero));
+    }
+}